@@ -24,15 +24,11 @@ use std::fs::create_dir_all;
 use std::io::BufReader;
 use std::path::Path;
 
-use chrono;
 use clap::Parser;
 
-use gencad::interpreter::InterpretedGencadFile;
+use gencad::interpreter::{InterpretedGencadFile, PadOrPadstack};
 use gencad::parser::ParsedGencadFile;
 use gencad::parser::sections::pads::{Pad, PadShape};
-use gencad::parser::sections::shapes::SubShape;
-use gencad::types::{CircleRef, PadType, XYRef};
-use gencad::types::{Dimension, Number};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -41,38 +37,6 @@ struct Args {
     file: String,
 }
 
-fn units_to_mm(value_units: Number, units: &Dimension) -> Number {
-    match units {
-        Dimension::Inch => value_units * 25.4,
-        Dimension::Thou => value_units * 0.0254,
-        Dimension::Mm => value_units,
-        Dimension::Mm100 => value_units * 0.01,
-        Dimension::User(units_per_inch) => {
-            (25.4 * value_units) / <u16 as Into<Number>>::into(*units_per_inch)
-        }
-        Dimension::UserM(units_per_cm) => {
-            (10.0 * value_units) / <u16 as Into<Number>>::into(*units_per_cm)
-        }
-        Dimension::UserMm(units_per_mm) => value_units / <u16 as Into<Number>>::into(*units_per_mm),
-    }
-}
-
-fn mm_to_units(value_mm: Number, units: &Dimension) -> Number {
-    match units {
-        Dimension::Inch => value_mm / 25.4,
-        Dimension::Thou => value_mm / 0.0254,
-        Dimension::Mm => value_mm,
-        Dimension::Mm100 => value_mm * 100.0,
-        Dimension::User(units_per_inch) => {
-            (value_mm * <u16 as Into<Number>>::into(*units_per_inch)) / 25.4
-        }
-        Dimension::UserM(units_per_cm) => {
-            (value_mm * <u16 as Into<Number>>::into(*units_per_cm)) / 10.0
-        }
-        Dimension::UserMm(units_per_mm) => value_mm * <u16 as Into<Number>>::into(*units_per_mm),
-    }
-}
-
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
@@ -94,26 +58,25 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     create_dir_all(&output_dir)?;
 
     // Generate .kicad_mod files for each footprint
-    for (name, component) in &interpreted.components {
+    for component in interpreted.component_refs() {
+        let name = &component.name;
         let mut content = String::new();
 
-        let shape = interpreted
-            .shapes
-            .get(&component.shape.name)
-            .ok_or(format!(
-                "Shape not found for component {}: {}",
-                name, component.shape.name
-            ))?;
+        let shape = component.shape()?;
 
-        let device = interpreted.devices.get(&component.device);
+        let device = match component.device() {
+            Ok(device) => Some(device),
+            Err(err) => {
+                eprintln!("Component {}: {}", name, err);
+                None
+            }
+        };
 
         let part = device
-            .as_ref()
             .and_then(|d| d.part.as_ref().cloned())
             .unwrap_or_default();
 
         let description = device
-            .as_ref()
             .and_then(|d| d.desc.as_ref().cloned())
             .unwrap_or_default();
 
@@ -142,55 +105,53 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         content.push_str("    (effects (font (size 1 1) (thickness 0.15)))\n");
         content.push_str("  )\n");
 
-        let default_pad = Pad {
-            name: "default".to_string(),
-            ptype: PadType::Round,
-            drill_size: 0.0,
-            shapes: vec![PadShape::Circle(CircleRef {
-                center: XYRef { x: 0.0, y: 0.0 },
-                radius: mm_to_units(0.5, &interpreted.header.units),
-            })],
-            attributes: Vec::new(),
-        };
-
         // Add pads for each pin
-        for subshape in &shape.subshapes {
-            if let SubShape::Pin(pin) = subshape {
-                let pad = interpreted.pads.get(&pin.pad_name).unwrap_or(&default_pad);
-
-                // Convert to KiCad coordinates
-                let x = pin.xy.x;
-                let y = -pin.xy.y;
-
-                // Convert to KiCad units
-                let x_mm = units_to_mm(x, &interpreted.header.units);
-                let y_mm = units_to_mm(y, &interpreted.header.units);
-
-                // Get the radius from the pad's shape if it is a circle
-                let radius = pad
-                    .shapes
+        for pin in shape.pins() {
+            let pads: Vec<&Pad> = match pin.pad_or_padstack() {
+                Ok(PadOrPadstack::Pad(pad)) => vec![pad],
+                Ok(PadOrPadstack::Padstack(padstack)) => padstack
+                    .pads
                     .iter()
-                    .find_map(|shape| {
-                        if let PadShape::Circle(circle) = shape {
-                            Some(circle.radius)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(mm_to_units(0.5, &interpreted.header.units)); // Default radius of 0.5mm if no circle found
-
-                let radius_mm = units_to_mm(radius, &interpreted.header.units);
-
-                content.push_str(&format!(
-                    "  (pad \"{}\" smd circle (at {:.6} {:.6}) (size {:.6} {:.6}) (layers F.Cu F.Paste F.Mask)\n",
-                    pin.name,
-                    x_mm,
-                    y_mm,
-                    radius_mm,
-                    radius_mm
-                ));
-                content.push_str("  )\n");
-            }
+                    .filter_map(|pad| interpreted.pads.get(&pad.name))
+                    .collect(),
+                Err(err) => {
+                    eprintln!("Component {}, pin {}: {}", name, pin.name, err);
+                    Vec::new()
+                }
+            };
+
+            // Convert to KiCad coordinates
+            let x = pin.xy.x;
+            let y = -pin.xy.y;
+
+            // Convert to KiCad units
+            let x_mm = interpreted.header.units.to_mm(x);
+            let y_mm = interpreted.header.units.to_mm(y);
+
+            // Get the radius from the pad's shape if it is a circle
+            let radius = pads
+                .iter()
+                .flat_map(|pad| &pad.shapes)
+                .find_map(|shape| {
+                    if let PadShape::Circle(circle) = shape {
+                        Some(circle.radius)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(interpreted.header.units.from_mm(0.5)); // Default radius of 0.5mm if no circle found
+
+            let radius_mm = interpreted.header.units.to_mm(radius);
+
+            content.push_str(&format!(
+                "  (pad \"{}\" smd circle (at {:.6} {:.6}) (size {:.6} {:.6}) (layers F.Cu F.Paste F.Mask)\n",
+                pin.name,
+                x_mm,
+                y_mm,
+                radius_mm,
+                radius_mm
+            ));
+            content.push_str("  )\n");
         }
 
         // Close the footprint
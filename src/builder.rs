@@ -0,0 +1,568 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Builder for GenCAD files.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+/*!
+ * # `builder` Module
+ *
+ * This module provides [GencadBuilder], for constructing GenCAD files
+ * programmatically rather than parsing them.
+ *
+ * Each method adds a record with the fields that are required or almost
+ * always set, and returns it so that the rest of its fields can be changed.
+ * [GencadBuilder::build] checks that every name the records refer to is
+ * defined, and that no two records of the same kind share a name.
+ *
+ * ## Usage Example
+ *
+ * ```no_run
+ * use std::fs::File;
+ *
+ * use gencad::builder::GencadBuilder;
+ * use gencad::parser::sections::pads::PadShape;
+ * use gencad::types::{CircleRef, Dimension, Layer, PadType, XYRef};
+ *
+ * fn main() -> Result<(), Box<dyn std::error::Error>> {
+ *     let mut builder = GencadBuilder::new(Dimension::Mm);
+ *     builder.header("me", "test board", "1");
+ *
+ *     let circle = CircleRef {
+ *         center: XYRef { x: 0.0, y: 0.0 },
+ *         radius: 0.5,
+ *     };
+ *     builder.pad("P1", PadType::Round, 0.0, [PadShape::Circle(circle)]);
+ *     builder.shape("R0603", [], [
+ *         ("1", "P1", XYRef { x: -0.8, y: 0.0 }, Layer::Top),
+ *         ("2", "P1", XYRef { x: 0.8, y: 0.0 }, Layer::Top),
+ *     ]);
+ *     builder.device("RES_10K").value = Some("10k".to_string());
+ *     builder.component("R1", "RES_10K", "R0603", XYRef { x: 10.0, y: 5.0 }, Layer::Top);
+ *     builder.signal("GND", [("R1", "1")]);
+ *
+ *     // Check the references and write the file
+ *     let file = builder.build()?;
+ *     file.write(File::create("test.cad")?)?;
+ *
+ *     Ok(())
+ * }
+ * ```
+ */
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::Error;
+use crate::parser::sections::components::{self, Component};
+use crate::parser::sections::devices::Device;
+use crate::parser::sections::header::Header;
+use crate::parser::sections::pads::{Pad, PadShape};
+use crate::parser::sections::padstacks::{self, Padstack, Padstacks};
+use crate::parser::sections::shapes::{Pin, Shape, ShapeElement, SubShape};
+use crate::parser::sections::signals::{Node, Signal, Signals};
+use crate::parser::{NumberText, ParsedGencadFile, ParsedSection};
+use crate::types::{Dimension, Layer, Mirror, Number, PadType, XYRef};
+
+/// Builds a [ParsedGencadFile] from pads, padstacks, shapes, devices, components and signals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GencadBuilder {
+    header: Header,
+    pads: Vec<Pad>,
+    padstacks: Vec<Padstack>,
+    shapes: Vec<Shape>,
+    devices: Vec<Device>,
+    components: Vec<Component>,
+    signals: Vec<Signal>,
+}
+
+impl GencadBuilder {
+    /// Creates a builder for a GenCAD 1.4 file whose coordinates are in `units`.
+    ///
+    /// The origin and intertrack spacing are zero, and the user, drawing and revision are empty
+    /// until set with [GencadBuilder::header].
+    pub fn new(units: Dimension) -> Self {
+        let header = Header {
+            gencad_version: 1.4,
+            user: String::new(),
+            drawing: String::new(),
+            revision: String::new(),
+            units,
+            origin: XYRef { x: 0.0, y: 0.0 },
+            intertrack: 0.0,
+            attributes: Vec::new(),
+        };
+        Self {
+            header,
+            pads: Vec::new(),
+            padstacks: Vec::new(),
+            shapes: Vec::new(),
+            devices: Vec::new(),
+            components: Vec::new(),
+            signals: Vec::new(),
+        }
+    }
+
+    /// Sets the `USER`, `DRAWING` and `REVISION` of the header, and returns the header.
+    pub fn header(&mut self, user: &str, drawing: &str, revision: &str) -> &mut Header {
+        self.header.user = user.to_string();
+        self.header.drawing = drawing.to_string();
+        self.header.revision = revision.to_string();
+        &mut self.header
+    }
+
+    /// Adds a pad made up of `shapes`, and returns it.
+    pub fn pad(
+        &mut self,
+        name: &str,
+        ptype: PadType,
+        drill_size: Number,
+        shapes: impl IntoIterator<Item = PadShape>,
+    ) -> &mut Pad {
+        self.pads.push(Pad {
+            name: name.to_string(),
+            ptype,
+            drill_size,
+            shapes: shapes.into_iter().collect(),
+            attributes: Vec::new(),
+            span: None,
+        });
+        self.pads.last_mut().unwrap()
+    }
+
+    /// Adds a padstack made up of the named pads, each on its layer, and returns it.
+    ///
+    /// The pads aren't rotated or mirrored.
+    pub fn padstack<'n>(
+        &mut self,
+        name: &str,
+        drill_size: Number,
+        pads: impl IntoIterator<Item = (&'n str, Layer)>,
+    ) -> &mut Padstack {
+        let pads = pads
+            .into_iter()
+            .map(|(name, layer)| padstacks::Pad {
+                name: name.to_string(),
+                layer,
+                rotation: 0.0,
+                mirror: Mirror::Not,
+            })
+            .collect();
+        self.padstacks.push(Padstack {
+            name: name.to_string(),
+            drill_size,
+            pads,
+            span: None,
+        });
+        self.padstacks.last_mut().unwrap()
+    }
+
+    /// Adds a shape with an outline made up of `elements`, and returns it.
+    ///
+    /// Each pin is given by its name, the name of its pad or padstack, its position relative to
+    /// the shape's origin, and its layer. The pins aren't rotated or mirrored.
+    pub fn shape<'n>(
+        &mut self,
+        name: &str,
+        elements: impl IntoIterator<Item = ShapeElement>,
+        pins: impl IntoIterator<Item = (&'n str, &'n str, XYRef, Layer)>,
+    ) -> &mut Shape {
+        let subshapes = pins
+            .into_iter()
+            .map(|(name, pad_name, xy, layer)| {
+                SubShape::Pin(Pin {
+                    name: name.to_string(),
+                    pad_name: pad_name.to_string(),
+                    xy,
+                    layer,
+                    rotation: 0.0,
+                    mirror: Mirror::Not,
+                    attributes: Vec::new(),
+                    span: None,
+                })
+            })
+            .collect();
+        self.shapes.push(Shape {
+            name: name.to_string(),
+            elements: elements.into_iter().collect(),
+            insert: None,
+            height: None,
+            subshapes,
+            attributes: Vec::new(),
+            span: None,
+        });
+        self.shapes.last_mut().unwrap()
+    }
+
+    /// Adds a device with none of its optional fields set, and returns it.
+    pub fn device(&mut self, name: &str) -> &mut Device {
+        self.devices.push(Device {
+            name: name.to_string(),
+            part: None,
+            dtype: None,
+            style: None,
+            package: None,
+            pin_descriptions: Vec::new(),
+            pin_functions: Vec::new(),
+            pincount: None,
+            value: None,
+            tol: None,
+            ntol: None,
+            ptol: None,
+            volts: None,
+            desc: None,
+            attributes: Vec::new(),
+            span: None,
+        });
+        self.devices.last_mut().unwrap()
+    }
+
+    /// Adds a component that places the shape at `place` on `layer`, and returns it.
+    ///
+    /// The component isn't rotated, and its shape isn't mirrored or flipped.
+    pub fn component(
+        &mut self,
+        name: &str,
+        device: &str,
+        shape: &str,
+        place: XYRef,
+        layer: Layer,
+    ) -> &mut Component {
+        self.components.push(Component {
+            name: name.to_string(),
+            device: device.to_string(),
+            place,
+            layer,
+            rotation: 0.0,
+            shape: components::Shape {
+                name: shape.to_string(),
+                mirror: Mirror::Not,
+                flip: false,
+            },
+            subcomponents: Vec::new(),
+            texts: Vec::new(),
+            sheet: None,
+            attributes: Vec::new(),
+            span: None,
+        });
+        self.components.last_mut().unwrap()
+    }
+
+    /// Adds a signal connecting the pins given by their component and pin names, and returns it.
+    pub fn signal<'n>(
+        &mut self,
+        name: &str,
+        nodes: impl IntoIterator<Item = (&'n str, &'n str)>,
+    ) -> &mut Signal {
+        let nodes = nodes
+            .into_iter()
+            .map(|(component_name, pin_name)| Node {
+                component_name: component_name.to_string(),
+                pin_name: pin_name.to_string(),
+                span: None,
+            })
+            .collect();
+        self.signals.push(Signal {
+            name: name.to_string(),
+            nodes,
+            nail_locations: Vec::new(),
+            span: None,
+        });
+        self.signals.last_mut().unwrap()
+    }
+
+    /// Checks the references between the records, and returns the file made up of them.
+    ///
+    /// Sections without any records are left out, except for the header.
+    ///
+    /// # Returns
+    ///
+    /// The file, or an [Error::UndefinedReference] or [Error::DuplicateName] for the first
+    /// problem found.
+    pub fn build(self) -> Result<ParsedGencadFile, Error> {
+        self.validate()?;
+
+        let mut sections = vec![ParsedSection::Header(self.header)];
+        if !self.pads.is_empty() {
+            sections.push(ParsedSection::Pads(self.pads));
+        }
+        if !self.padstacks.is_empty() {
+            sections.push(ParsedSection::Padstacks(Padstacks {
+                padstacks: self.padstacks,
+                attributes: Vec::new(),
+            }));
+        }
+        if !self.shapes.is_empty() {
+            sections.push(ParsedSection::Shapes(self.shapes));
+        }
+        if !self.components.is_empty() {
+            sections.push(ParsedSection::Components(self.components));
+        }
+        if !self.devices.is_empty() {
+            sections.push(ParsedSection::Devices(self.devices));
+        }
+        if !self.signals.is_empty() {
+            sections.push(ParsedSection::Signals(Signals {
+                signals: self.signals,
+                attributes: Vec::new(),
+            }));
+        }
+
+        Ok(ParsedGencadFile {
+            sections,
+            diagnostics: Vec::new(),
+            number_text: NumberText::default(),
+        })
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        // Pads and padstacks are referred to the same way, so share a namespace.
+        let mut pads = HashSet::new();
+        for name in self.pads.iter().map(|pad| &pad.name) {
+            insert_unique(&mut pads, name, "PADS", "PAD")?;
+        }
+        for padstack in &self.padstacks {
+            for pad in &padstack.pads {
+                if !self.pads.iter().any(|p| p.name == pad.name) {
+                    return Err(undefined("PADSTACKS", "PAD", &pad.name));
+                }
+            }
+            insert_unique(&mut pads, &padstack.name, "PADSTACKS", "PADSTACK")?;
+        }
+
+        let mut shapes = HashMap::new();
+        for shape in &self.shapes {
+            let mut pins = HashSet::new();
+            // A pin may have a pad on each side of the board under the same name.
+            let mut pin_layers = HashSet::new();
+            for subshape in &shape.subshapes {
+                if let SubShape::Pin(pin) = subshape {
+                    if !pads.contains(pin.pad_name.as_str()) {
+                        return Err(undefined("SHAPES", "PAD", &pin.pad_name));
+                    }
+                    if !pin_layers.insert((pin.name.as_str(), pin.layer)) {
+                        return Err(duplicate("SHAPES", "PIN", &pin.name));
+                    }
+                    pins.insert(pin.name.as_str());
+                }
+            }
+            if shapes.insert(shape.name.as_str(), pins).is_some() {
+                return Err(duplicate("SHAPES", "SHAPE", &shape.name));
+            }
+        }
+
+        let mut devices = HashSet::new();
+        for name in self.devices.iter().map(|device| &device.name) {
+            insert_unique(&mut devices, name, "DEVICES", "DEVICE")?;
+        }
+
+        let mut components = HashMap::new();
+        for component in &self.components {
+            if !devices.contains(component.device.as_str()) {
+                return Err(undefined("COMPONENTS", "DEVICE", &component.device));
+            }
+            let Some(pins) = shapes.get(component.shape.name.as_str()) else {
+                return Err(undefined("COMPONENTS", "SHAPE", &component.shape.name));
+            };
+            if components.insert(component.name.as_str(), pins).is_some() {
+                return Err(duplicate("COMPONENTS", "COMPONENT", &component.name));
+            }
+        }
+
+        let mut signals = HashSet::new();
+        for signal in &self.signals {
+            for node in &signal.nodes {
+                let Some(pins) = components.get(node.component_name.as_str()) else {
+                    return Err(undefined("SIGNALS", "COMPONENT", &node.component_name));
+                };
+                if !pins.contains(node.pin_name.as_str()) {
+                    let name = format!("{} {}", node.component_name, node.pin_name);
+                    return Err(undefined("SIGNALS", "PIN", &name));
+                }
+            }
+            insert_unique(&mut signals, &signal.name, "SIGNALS", "SIGNAL")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn undefined(section: &str, keyword: &str, name: &str) -> Error {
+    Error::UndefinedReference {
+        section: section.to_string(),
+        keyword: keyword.to_string(),
+        name: name.to_string(),
+    }
+}
+
+fn duplicate(section: &str, keyword: &str, name: &str) -> Error {
+    Error::DuplicateName {
+        section: section.to_string(),
+        keyword: keyword.to_string(),
+        name: name.to_string(),
+    }
+}
+
+fn insert_unique<'b>(
+    names: &mut HashSet<&'b str>,
+    name: &'b str,
+    section: &str,
+    keyword: &str,
+) -> Result<(), Error> {
+    if names.insert(name) {
+        Ok(())
+    } else {
+        Err(duplicate(section, keyword, name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CircleRef;
+
+    fn board() -> GencadBuilder {
+        let mut builder = GencadBuilder::new(Dimension::Mm);
+        builder.header("test", "board with spaces", "A");
+        let circle = CircleRef {
+            center: XYRef { x: 0.0, y: 0.0 },
+            radius: 0.5,
+        };
+        builder.pad("ROUND1", PadType::Round, 0.3, [PadShape::Circle(circle)]);
+        builder.padstack(
+            "VIA1",
+            0.3,
+            [("ROUND1", Layer::Top), ("ROUND1", Layer::Bottom)],
+        );
+        builder.shape(
+            "R0603",
+            [],
+            [
+                ("1", "ROUND1", XYRef { x: -0.8, y: 0.0 }, Layer::Top),
+                ("2", "VIA1", XYRef { x: 0.8, y: 0.0 }, Layer::Top),
+            ],
+        );
+        builder.device("RES").value = Some("10k".to_string());
+        builder
+            .component("R1", "RES", "R0603", XYRef { x: 1.0, y: 2.0 }, Layer::Top)
+            .rotation = 90.0;
+        builder.component(
+            "R2",
+            "RES",
+            "R0603",
+            XYRef { x: 3.0, y: 2.0 },
+            Layer::Bottom,
+        );
+        builder.signal("GND", [("R1", "1"), ("R2", "2")]);
+        builder
+    }
+
+    #[test]
+    fn test_build() {
+        let built = board().build().unwrap();
+        assert_eq!(built.sections.len(), 7);
+
+        // The built file can be written and read back.
+        let mut text = Vec::new();
+        built.write(&mut text).unwrap();
+        let parsed = ParsedGencadFile::new(text.as_slice()).unwrap();
+        assert!(parsed.diagnostics.is_empty());
+        let mut rewritten = Vec::new();
+        parsed.write(&mut rewritten).unwrap();
+        assert_eq!(rewritten, text);
+
+        let ParsedSection::Components(components) = &parsed.sections[4] else {
+            panic!("expected COMPONENTS, got {:?}", parsed.sections[4]);
+        };
+        assert_eq!(components[0].rotation, 90.0);
+        assert_eq!(components[1].layer, Layer::Bottom);
+    }
+
+    #[test]
+    fn test_references() {
+        let mut builder = board();
+        builder.signal("VCC", [("R3", "1")]);
+        let err = builder.build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "undefined COMPONENT R3 referenced in $SIGNALS"
+        );
+
+        let mut builder = board();
+        builder.signal("VCC", [("R1", "3")]);
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.to_string(), "undefined PIN R1 3 referenced in $SIGNALS");
+
+        let mut builder = board();
+        builder.component("R3", "CAP", "R0603", XYRef { x: 0.0, y: 0.0 }, Layer::Top);
+        let err = builder.build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "undefined DEVICE CAP referenced in $COMPONENTS"
+        );
+
+        let mut builder = board();
+        builder.shape(
+            "SOT23",
+            [],
+            [("1", "SQUARE1", XYRef { x: 0.0, y: 0.0 }, Layer::Top)],
+        );
+        let err = builder.build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "undefined PAD SQUARE1 referenced in $SHAPES"
+        );
+
+        let mut builder = board();
+        builder.padstack("ROUND1", 0.0, []);
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.to_string(), "duplicate PADSTACK ROUND1 in $PADSTACKS");
+
+        let mut builder = board();
+        builder.component("R1", "RES", "R0603", XYRef { x: 0.0, y: 0.0 }, Layer::Top);
+        let err = builder.build().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::DuplicateName { section, keyword, name }
+                if section == "COMPONENTS" && keyword == "COMPONENT" && name == "R1"
+        ));
+
+        // The same pin name may be used once on each layer.
+        let mut builder = board();
+        let xy = XYRef { x: 0.0, y: 0.0 };
+        builder.shape(
+            "EDGE",
+            [],
+            [
+                ("1", "ROUND1", xy, Layer::Top),
+                ("1", "ROUND1", xy, Layer::Bottom),
+            ],
+        );
+        assert!(builder.build().is_ok());
+
+        let mut builder = board();
+        builder.shape(
+            "EDGE",
+            [],
+            [
+                ("1", "ROUND1", xy, Layer::Top),
+                ("1", "ROUND1", xy, Layer::Top),
+            ],
+        );
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.to_string(), "duplicate PIN 1 in $SHAPES");
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Diagnostics reported while parsing GenCAD files.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+use crate::error::Error;
+
+/// How serious a [Diagnostic] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Data was ignored or overridden, but the file was otherwise understood.
+    Warning,
+    /// A statement or section could not be parsed and was skipped.
+    Error,
+}
+
+/// A problem found in a GenCAD file that did not stop it from being parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// The 1-based line the problem was found on.
+    pub line: usize,
+    /// The 1-based column the problem was found at, if it is known.
+    pub column: Option<usize>,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl From<Error> for Diagnostic {
+    fn from(err: Error) -> Self {
+        Self {
+            severity: Severity::Error,
+            line: err.line().unwrap_or_default(),
+            column: err.column(),
+            message: err.message(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        match self.column {
+            Some(column) => write!(
+                f,
+                "{}: line {}, column {}: {}",
+                severity, self.line, column, self.message
+            ),
+            None => write!(f, "{}: line {}: {}", severity, self.line, self.message),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Error type for GenCAD parsing.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+
+/// An error encountered while parsing, building or writing a GenCAD file.
+///
+/// All line and column numbers are 1-based.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// A line outside of any section could not be parsed.
+    Syntax { line: usize, column: usize },
+    /// A section was opened but never closed with its `$END` line.
+    UnterminatedSection { section: String, line: usize },
+    /// A section was closed by the `$END` line of a different section.
+    MismatchedEnd {
+        section: String,
+        end: String,
+        line: usize,
+    },
+    /// A required section was not present in the file.
+    MissingSection { section: String },
+    /// A required statement was not present.
+    ///
+    /// The line is that of the record missing the statement, or that of the section's `$END`
+    /// line if the statement belongs to the section itself.
+    MissingKeyword {
+        section: String,
+        keyword: String,
+        line: usize,
+    },
+    /// A statement appeared somewhere it is not allowed.
+    UnexpectedKeyword {
+        section: String,
+        keyword: String,
+        line: usize,
+        column: usize,
+    },
+    /// A statement's parameter could not be parsed. The column points at the offending field.
+    BadField {
+        section: String,
+        keyword: String,
+        line: usize,
+        column: usize,
+    },
+    /// A record refers to a name that isn't defined, e.g., a pseudo-component whose member
+    /// component isn't in the `COMPONENTS` section.
+    UndefinedReference {
+        section: String,
+        keyword: String,
+        name: String,
+    },
+    /// A value can't be written as GenCAD text, e.g., a string with non-ASCII characters or a
+    /// number that isn't finite.
+    Unwritable { section: String, keyword: String },
+    /// Two records of the same kind have the same name.
+    DuplicateName {
+        section: String,
+        keyword: String,
+        name: String,
+    },
+}
+
+impl Error {
+    /// Returns the line the error was found on, if it has one.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Self::Io(_)
+            | Self::MissingSection { .. }
+            | Self::UndefinedReference { .. }
+            | Self::Unwritable { .. }
+            | Self::DuplicateName { .. } => None,
+            Self::Syntax { line, .. }
+            | Self::UnterminatedSection { line, .. }
+            | Self::MismatchedEnd { line, .. }
+            | Self::MissingKeyword { line, .. }
+            | Self::UnexpectedKeyword { line, .. }
+            | Self::BadField { line, .. } => Some(*line),
+        }
+    }
+
+    /// Returns the column the error was found at, if it has one.
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            Self::Syntax { column, .. }
+            | Self::UnexpectedKeyword { column, .. }
+            | Self::BadField { column, .. } => Some(*column),
+            _ => None,
+        }
+    }
+
+    /// Describes the error without its location.
+    pub(crate) fn message(&self) -> String {
+        match self {
+            Self::Io(err) => format!("I/O error: {}", err),
+            Self::Syntax { .. } => "syntax error".to_string(),
+            Self::UnterminatedSection { section, .. } => {
+                format!("section ${} is never closed", section)
+            }
+            Self::MismatchedEnd { section, end, .. } => {
+                format!("section ${} closed by $END{}", section, end)
+            }
+            Self::MissingSection { section } => format!("missing ${} section", section),
+            Self::MissingKeyword {
+                section, keyword, ..
+            } => format!("missing {} in ${}", keyword, section),
+            Self::UnexpectedKeyword {
+                section, keyword, ..
+            } => format!("unexpected {} in ${}", keyword, section),
+            Self::BadField {
+                section, keyword, ..
+            } => format!("invalid {} parameter in ${}", keyword, section),
+            Self::UndefinedReference {
+                section,
+                keyword,
+                name,
+            } => format!("undefined {} {} referenced in ${}", keyword, name, section),
+            Self::Unwritable { section, keyword } => {
+                format!(
+                    "{} in ${} can't be written as GenCAD text",
+                    keyword, section
+                )
+            }
+            Self::DuplicateName {
+                section,
+                keyword,
+                name,
+            } => format!("duplicate {} {} in ${}", keyword, name, section),
+        }
+    }
+
+    /// Creates a [Error::MissingKeyword] for a statement missing from the record starting on `line`.
+    pub(crate) fn missing(section: &str, keyword: &str, line: usize) -> Self {
+        Self::MissingKeyword {
+            section: section.to_string(),
+            keyword: keyword.to_string(),
+            line,
+        }
+    }
+
+    /// Creates a [Error::MissingKeyword] for a statement belonging to a whole section.
+    ///
+    /// The line is filled in by [Error::at_section_end] once the section's `$END` line is known.
+    pub(crate) fn missing_in_section(section: &str, keyword: &str) -> Self {
+        Self::missing(section, keyword, 0)
+    }
+
+    /// Sets the line of a section-level [Error::MissingKeyword] to the section's `$END` line.
+    pub(crate) fn at_section_end(self, end_line: usize) -> Self {
+        match self {
+            Self::MissingKeyword {
+                section,
+                keyword,
+                line: 0,
+            } => Self::MissingKeyword {
+                section,
+                keyword,
+                line: end_line,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line(), self.column()) {
+            (Some(line), Some(column)) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message())
+            }
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message()),
+            _ => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// The position of a parse failure within a statement's parameter.
+///
+/// Parameter parsers only consume input from the front, so the failure is located by the length
+/// of the input that was left when it occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FieldError {
+    /// The number of bytes of the parameter remaining at the point of failure.
+    pub remaining: usize,
+}
+
+impl FieldError {
+    /// Creates an error pointing at the start of `rest`, a suffix of the parameter.
+    pub(crate) fn at(rest: &str) -> Self {
+        Self {
+            remaining: rest.len(),
+        }
+    }
+}
+
+impl From<nom::Err<nom::error::Error<&str>>> for FieldError {
+    fn from(err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(e.input),
+            nom::Err::Incomplete(_) => Self { remaining: 0 },
+        }
+    }
+}
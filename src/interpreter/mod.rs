@@ -23,6 +23,14 @@
  *
  * This module provides functionality to interpret parsed GenCAD files.
  *
+ * Records refer to each other by name. [InterpretedGencadFile::component]
+ * returns a [ComponentRef], which looks up the component's shape and device,
+ * and the shape's pins look up their pad or padstack. A name that isn't defined
+ * is reported as an [Error::UndefinedReference].
+ *
+ * [InterpretedGencadFile::netlist] indexes the signals, to find the net a pin
+ * is on and every other pin on that net.
+ *
  * ## Usage Example
  *
  * ```no_run
@@ -51,50 +59,119 @@
  * ```
  */
 
+mod netlist;
+mod references;
+
+pub use netlist::Netlist;
+use references::undefined;
+pub use references::{ComponentRef, PadOrPadstack, PinRef, ShapeRef};
+
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 
+use crate::error::Error;
+use crate::parser::sections::artworks::Artwork;
+use crate::parser::sections::board::Board;
+use crate::parser::sections::changes::Change;
 use crate::parser::sections::components::Component;
 use crate::parser::sections::devices::Device;
 use crate::parser::sections::header::Header;
+use crate::parser::sections::layers::{LayerDef, Layerset};
+use crate::parser::sections::mech::Mechanical;
 use crate::parser::sections::pads::Pad;
 use crate::parser::sections::padstacks::Padstack;
+use crate::parser::sections::pseudos::Pseudo;
+use crate::parser::sections::routes::Route;
 use crate::parser::sections::shapes::Shape;
+use crate::parser::sections::signals::Signal;
+use crate::parser::sections::testpins::{TestPin, TestPins};
+use crate::parser::sections::unknown::Unknown;
 use crate::parser::{ParsedGencadFile, ParsedSection};
+use crate::types::{Attribute, Layer, Number};
 
 /// A fully interpreted GenCAD file.
 #[derive(Debug, Clone, PartialEq)]
 pub struct InterpretedGencadFile {
     pub header: Header,
+    /// The board outline, cutouts, masks and artworks, if the file has a `BOARD` section.
+    pub board: Option<Board>,
     pub pads: HashMap<String, Pad>,
     pub padstacks: HashMap<String, Padstack>,
     pub shapes: HashMap<String, Shape>,
     pub components: HashMap<String, Component>,
     pub devices: HashMap<String, Device>,
+    /// The signals, by name. `SIGNAL` records with the same name are merged into one signal.
+    pub signals: HashMap<String, Signal>,
+    /// The names of the signals, in file order.
+    pub signal_names: Vec<String>,
+    /// The attributes of the `SIGNALS` section itself.
+    pub signal_attributes: Vec<Attribute>,
+    /// The routed copper of each signal, by signal name. `ROUTE` records with the same name are
+    /// merged into one route.
+    pub routes: HashMap<String, Route>,
+    pub tracks: HashMap<String, Number>,
+    pub layers: HashMap<Layer, LayerDef>,
+    pub layersets: HashMap<Layer, Layerset>,
+    pub artworks: HashMap<String, Artwork>,
+    pub mechanicals: HashMap<String, Mechanical>,
+    pub testpins: HashMap<String, Vec<TestPin>>,
+    pub powerpins: HashMap<String, Vec<TestPin>>,
+    pub pseudos: HashMap<String, Pseudo>,
+    pub changes: Vec<Change>,
+    /// Sections with names that aren't part of GenCAD 1.4, in file order.
+    pub unknown_sections: Vec<Unknown>,
 }
 
 impl InterpretedGencadFile {
-    pub fn new(parsed: ParsedGencadFile) -> Result<Self, Box<dyn std::error::Error>> {
+    /// Indexes the sections of a parsed file.
+    ///
+    /// Fails with an [Error::MissingSection] if the file has no `HEADER` section.
+    pub fn new(parsed: ParsedGencadFile) -> Result<Self, Error> {
         let mut header_section = None;
+        let mut board_section = None;
         let mut pads_section = None;
         let mut padstacks_section = None;
         let mut shapes_section = None;
         let mut components_section = None;
         let mut devices_section = None;
+        let mut signals_section = None;
+        let mut routes_section = None;
+        let mut tracks_section = None;
+        let mut layers_section = None;
+        let mut artworks_section = None;
+        let mut mech_section = None;
+        let mut testpins_section = None;
+        let mut powerpins_section = None;
+        let mut pseudos_section = None;
+        let mut changes_section = None;
+        let mut unknown_sections = Vec::new();
 
         for section in parsed.sections {
             match section {
                 ParsedSection::Header(s) => header_section = Some(s),
+                ParsedSection::Board(s) => board_section = Some(s),
                 ParsedSection::Pads(s) => pads_section = Some(s),
                 ParsedSection::Padstacks(s) => padstacks_section = Some(s),
                 ParsedSection::Shapes(s) => shapes_section = Some(s),
                 ParsedSection::Components(s) => components_section = Some(s),
                 ParsedSection::Devices(s) => devices_section = Some(s),
-                _ => (),
+                ParsedSection::Signals(s) => signals_section = Some(s),
+                ParsedSection::Routes(s) => routes_section = Some(s),
+                ParsedSection::Tracks(s) => tracks_section = Some(s),
+                ParsedSection::Layers(s) => layers_section = Some(s),
+                ParsedSection::Artworks(s) => artworks_section = Some(s),
+                ParsedSection::Mech(s) => mech_section = Some(s),
+                ParsedSection::Testpins(s) => testpins_section = Some(s),
+                ParsedSection::Powerpins(s) => powerpins_section = Some(s),
+                ParsedSection::Pseudos(s) => pseudos_section = Some(s),
+                ParsedSection::Changes(s) => changes_section = Some(s),
+                ParsedSection::Unknown(s) => unknown_sections.push(s),
             }
         }
 
-        let header =
-            header_section.ok_or_else(|| "Missing header section in GenCAD file".to_owned())?;
+        let header = header_section.ok_or_else(|| Error::MissingSection {
+            section: "HEADER".to_string(),
+        })?;
 
         let mut pads = HashMap::new();
         if let Some(pads_vec) = pads_section {
@@ -131,13 +208,221 @@ impl InterpretedGencadFile {
             }
         }
 
+        let mut signals: HashMap<String, Signal> = HashMap::new();
+        let mut signal_names = Vec::new();
+        let mut signal_attributes = Vec::new();
+        if let Some(s) = signals_section {
+            for signal in s.signals {
+                match signals.entry(signal.name.clone()) {
+                    Entry::Occupied(mut entry) => {
+                        let merged = entry.get_mut();
+                        merged.nodes.extend(signal.nodes);
+                        merged.nail_locations.extend(signal.nail_locations);
+                    }
+                    Entry::Vacant(entry) => {
+                        signal_names.push(entry.key().clone());
+                        entry.insert(signal);
+                    }
+                }
+            }
+            signal_attributes = s.attributes;
+        }
+
+        let mut routes: HashMap<String, Route> = HashMap::new();
+        if let Some(routes_vec) = routes_section {
+            for route in routes_vec {
+                match routes.entry(route.name.clone()) {
+                    Entry::Occupied(mut entry) => {
+                        let merged = entry.get_mut();
+                        merged.elements.extend(route.elements);
+                        merged.attributes.extend(route.attributes);
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(route);
+                    }
+                }
+            }
+        }
+
+        let mut tracks = HashMap::new();
+        if let Some(t) = tracks_section {
+            for track in t.tracks {
+                tracks.insert(track.name, track.width);
+            }
+        }
+
+        let mut layers = HashMap::new();
+        let mut layersets = HashMap::new();
+        if let Some(l) = layers_section {
+            for layer in l.layers {
+                layers.insert(layer.layer, layer);
+            }
+            for layerset in l.layersets {
+                layersets.insert(layerset.layerset, layerset);
+            }
+        }
+
+        let mut artworks = HashMap::new();
+        if let Some(artworks_vec) = artworks_section {
+            for artwork in artworks_vec {
+                artworks.insert(artwork.name.clone(), artwork);
+            }
+        }
+
+        let mut mechanicals = HashMap::new();
+        if let Some(mech_vec) = mech_section {
+            for mechanical in mech_vec {
+                mechanicals.insert(mechanical.name.clone(), mechanical);
+            }
+        }
+
+        let testpins = testpins_section.map(group_by_signal).unwrap_or_default();
+        let powerpins = powerpins_section.map(group_by_signal).unwrap_or_default();
+
+        let mut pseudos = HashMap::new();
+        if let Some(pseudos_vec) = pseudos_section {
+            for pseudo in pseudos_vec {
+                pseudos.insert(pseudo.name.clone(), pseudo);
+            }
+        }
+
+        let changes = changes_section.map(|s| s.changes).unwrap_or_default();
+
         Ok(Self {
             header,
+            board: board_section,
             pads,
             padstacks,
             shapes,
             components,
             devices,
+            signals,
+            signal_names,
+            signal_attributes,
+            routes,
+            tracks,
+            layers,
+            layersets,
+            artworks,
+            mechanicals,
+            testpins,
+            powerpins,
+            pseudos,
+            changes,
+            unknown_sections,
         })
     }
+
+    /// Returns the component with the given name, with access to the records it refers to.
+    pub fn component(&self, name: &str) -> Option<ComponentRef<'_>> {
+        let component = self.components.get(name)?;
+        Some(ComponentRef::new(self, component))
+    }
+
+    /// Returns all the components, with access to the records they refer to, in no particular
+    /// order.
+    pub fn component_refs(&self) -> impl Iterator<Item = ComponentRef<'_>> {
+        self.components
+            .values()
+            .map(|component| ComponentRef::new(self, component))
+    }
+
+    /// Returns the shape with the given name, with access to the pads of its pins.
+    pub fn shape(&self, name: &str) -> Option<ShapeRef<'_>> {
+        let shape = self.shapes.get(name)?;
+        Some(ShapeRef::new(self, shape))
+    }
+
+    /// Returns the pad or padstack with the given name.
+    ///
+    /// Pad and padstack names must not conflict, but if they do, the pad is returned.
+    pub fn pad_or_padstack(&self, name: &str) -> Option<PadOrPadstack<'_>> {
+        if let Some(pad) = self.pads.get(name) {
+            return Some(PadOrPadstack::Pad(pad));
+        }
+        self.padstacks.get(name).map(PadOrPadstack::Padstack)
+    }
+
+    /// Returns the signal that a pin of a component is connected to, if any.
+    ///
+    /// If the pin is listed in more than one signal, the first in file order is returned. This
+    /// searches every signal, so use a [Netlist] to look up many pins.
+    pub fn pin_signal(&self, component_name: &str, pin_name: &str) -> Option<&Signal> {
+        self.signals_in_order().find(|signal| {
+            signal
+                .nodes
+                .iter()
+                .any(|node| node.component_name == component_name && node.pin_name == pin_name)
+        })
+    }
+
+    /// Indexes the signals by pin and by net.
+    pub fn netlist(&self) -> Netlist<'_> {
+        Netlist::new(self.signals_in_order())
+    }
+
+    /// Returns the signals in file order.
+    fn signals_in_order(&self) -> impl Iterator<Item = &Signal> {
+        self.signal_names.iter().map(|name| &self.signals[name])
+    }
+
+    /// Returns the components aggregated by a pseudo-component, in membership order.
+    ///
+    /// Fails with an [Error::UndefinedReference] if the pseudo-component or any of its member
+    /// components is not defined.
+    pub fn pseudo_components(&self, pseudo_name: &str) -> Result<Vec<&Component>, Error> {
+        let pseudo = self
+            .pseudos
+            .get(pseudo_name)
+            .ok_or_else(|| undefined("PSEUDOS", "PSEUDO", pseudo_name))?;
+
+        pseudo
+            .members
+            .iter()
+            .map(|member| {
+                self.components
+                    .get(&member.component_name)
+                    .ok_or_else(|| undefined("PSEUDOS", "COMPONENT", &member.component_name))
+            })
+            .collect()
+    }
+
+    /// Expands a layer into the concrete layers it refers to.
+    ///
+    /// A [Layer::LayersetX] defined in the `LAYERS` section is replaced by its member layers,
+    /// recursively. Any other layer, including an undefined layerset, is returned as-is.
+    pub fn expand_layer(&self, layer: Layer) -> Vec<Layer> {
+        let mut expanded = Vec::new();
+        let mut visited = Vec::new();
+        self.expand_layer_into(layer, &mut expanded, &mut visited);
+        expanded
+    }
+
+    fn expand_layer_into(&self, layer: Layer, expanded: &mut Vec<Layer>, visited: &mut Vec<Layer>) {
+        match self.layersets.get(&layer) {
+            Some(layerset) => {
+                if visited.contains(&layer) {
+                    return;
+                }
+                visited.push(layer);
+                for member in &layerset.layers {
+                    self.expand_layer_into(*member, expanded, visited);
+                }
+            }
+            None => {
+                if !expanded.contains(&layer) {
+                    expanded.push(layer);
+                }
+            }
+        }
+    }
+}
+
+/// Groups the pins of a `TESTPINS` or `POWERPINS` section by signal name.
+fn group_by_signal(section: TestPins) -> HashMap<String, Vec<TestPin>> {
+    let mut grouped: HashMap<String, Vec<TestPin>> = HashMap::new();
+    for signal in section.signals {
+        grouped.entry(signal.name).or_default().extend(signal.pins);
+    }
+    grouped
 }
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Netlist indexes over GenCAD signals.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::{HashMap, HashSet};
+
+use super::InterpretedGencadFile;
+use crate::error::Error;
+use crate::parser::sections::signals::{Node, Signal};
+
+/// Indexes the connections between pins and nets, so that they can be looked up either way.
+///
+/// A net is a signal from the `SIGNALS` section. If a pin is listed in more than one signal, it
+/// belongs to the first one, in the order the signals are given to [Netlist::new].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Netlist<'f> {
+    /// The net of each `(component, pin)`.
+    pin_nets: HashMap<(&'f str, &'f str), &'f str>,
+    /// The nodes of each net.
+    net_nodes: HashMap<&'f str, &'f [Node]>,
+}
+
+impl<'f> Netlist<'f> {
+    /// Indexes the nodes of the signals.
+    pub fn new(signals: impl IntoIterator<Item = &'f Signal>) -> Self {
+        let mut pin_nets = HashMap::new();
+        let mut net_nodes = HashMap::new();
+        for signal in signals {
+            for node in &signal.nodes {
+                pin_nets
+                    .entry((node.component_name.as_str(), node.pin_name.as_str()))
+                    .or_insert(signal.name.as_str());
+            }
+            net_nodes.insert(signal.name.as_str(), signal.nodes.as_slice());
+        }
+        Self {
+            pin_nets,
+            net_nodes,
+        }
+    }
+
+    /// Returns the net a pin of a component is on, if any.
+    pub fn net_of(&self, component_name: &str, pin_name: &str) -> Option<&'f str> {
+        self.pin_nets.get(&(component_name, pin_name)).copied()
+    }
+
+    /// Returns the nodes of a net, in file order, or `None` if there is no such net.
+    pub fn nodes(&self, net: &str) -> Option<&'f [Node]> {
+        self.net_nodes.get(net).copied()
+    }
+
+    /// Returns every node on the same net as a pin of a component, including the pin itself.
+    ///
+    /// The result is empty if the pin is on no net.
+    pub fn connected(&self, component_name: &str, pin_name: &str) -> &'f [Node] {
+        self.net_of(component_name, pin_name)
+            .and_then(|net| self.nodes(net))
+            .unwrap_or_default()
+    }
+
+    /// Returns the names of the components with a pin on a net, in the order they first appear.
+    pub fn components(&self, net: &str) -> Vec<&'f str> {
+        let mut seen = HashSet::new();
+        self.nodes(net)
+            .unwrap_or_default()
+            .iter()
+            .map(|node| node.component_name.as_str())
+            .filter(|name| seen.insert(*name))
+            .collect()
+    }
+
+    /// Returns the names of all the nets, sorted.
+    pub fn nets(&self) -> Vec<&'f str> {
+        let mut nets: Vec<_> = self.net_nodes.keys().copied().collect();
+        nets.sort_unstable();
+        nets
+    }
+
+    /// Returns the `(component, pin)` pairs of the components' pins that are on no net, sorted.
+    ///
+    /// # Returns
+    ///
+    /// The pins, or an [Error::UndefinedReference] if the shape of a component isn't defined.
+    pub fn unconnected_pins<'g>(
+        &self,
+        file: &'g InterpretedGencadFile,
+    ) -> Result<Vec<(&'g str, &'g str)>, Error> {
+        let mut unconnected = Vec::new();
+        for component in file.component_refs() {
+            for pin in component.shape()?.pins() {
+                let component_name = component.component().name.as_str();
+                let pin_name = pin.pin().name.as_str();
+                if self.net_of(component_name, pin_name).is_none() {
+                    unconnected.push((component_name, pin_name));
+                }
+            }
+        }
+        // Shapes may have a pin on each side of the board with the same name.
+        unconnected.sort_unstable();
+        unconnected.dedup();
+        Ok(unconnected)
+    }
+}
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Resolved references between interpreted GenCAD records.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::ops::Deref;
+
+use super::InterpretedGencadFile;
+use crate::error::Error;
+use crate::parser::sections::components::Component;
+use crate::parser::sections::devices::Device;
+use crate::parser::sections::pads::Pad;
+use crate::parser::sections::padstacks::Padstack;
+use crate::parser::sections::shapes::{Pin, Shape, SubShape};
+use crate::transform::{PlacedPin, placed_pins};
+
+/// The pad or padstack a pin's `pad_name` refers to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadOrPadstack<'f> {
+    /// A pad defined in the `PADS` section.
+    Pad(&'f Pad),
+    /// A padstack defined in the `PADSTACKS` section.
+    Padstack(&'f Padstack),
+}
+
+/// A component, along with the file it's in so that the records it refers to can be looked up.
+///
+/// Dereferences to the [Component] itself.
+#[derive(Debug, Clone, Copy)]
+pub struct ComponentRef<'f> {
+    file: &'f InterpretedGencadFile,
+    component: &'f Component,
+}
+
+impl<'f> ComponentRef<'f> {
+    pub(super) fn new(file: &'f InterpretedGencadFile, component: &'f Component) -> Self {
+        Self { file, component }
+    }
+
+    /// Returns the component itself.
+    pub fn component(&self) -> &'f Component {
+        self.component
+    }
+
+    /// Returns the shape the component places, or an [Error::UndefinedReference] if it isn't
+    /// defined in the `SHAPES` section.
+    pub fn shape(&self) -> Result<ShapeRef<'f>, Error> {
+        let name = &self.component.shape.name;
+        self.file
+            .shape(name)
+            .ok_or_else(|| undefined("COMPONENTS", "SHAPE", name))
+    }
+
+    /// Returns the device of the component, or an [Error::UndefinedReference] if it isn't defined
+    /// in the `DEVICES` section.
+    pub fn device(&self) -> Result<&'f Device, Error> {
+        let name = &self.component.device;
+        self.file
+            .devices
+            .get(name)
+            .ok_or_else(|| undefined("COMPONENTS", "DEVICE", name))
+    }
+
+    /// Returns the pins of the component's shape, placed on the board, in file order.
+    ///
+    /// Fails like [ComponentRef::shape] if the shape isn't defined.
+    pub fn placed_pins(&self) -> Result<Vec<PlacedPin<'f>>, Error> {
+        Ok(placed_pins(self.component, self.shape()?.shape()))
+    }
+}
+
+impl Deref for ComponentRef<'_> {
+    type Target = Component;
+
+    fn deref(&self) -> &Component {
+        self.component
+    }
+}
+
+/// A shape, along with the file it's in so that the pads of its pins can be looked up.
+///
+/// Dereferences to the [Shape] itself.
+#[derive(Debug, Clone, Copy)]
+pub struct ShapeRef<'f> {
+    file: &'f InterpretedGencadFile,
+    shape: &'f Shape,
+}
+
+impl<'f> ShapeRef<'f> {
+    pub(super) fn new(file: &'f InterpretedGencadFile, shape: &'f Shape) -> Self {
+        Self { file, shape }
+    }
+
+    /// Returns the shape itself.
+    pub fn shape(&self) -> &'f Shape {
+        self.shape
+    }
+
+    /// Returns the pins of the shape, in file order.
+    pub fn pins(&self) -> impl Iterator<Item = PinRef<'f>> + use<'f> {
+        let file = self.file;
+        self.shape
+            .subshapes
+            .iter()
+            .filter_map(move |subshape| match subshape {
+                SubShape::Pin(pin) => Some(PinRef { file, pin }),
+                _ => None,
+            })
+    }
+
+    /// Returns the first pin with the given name.
+    ///
+    /// Shapes with pins on both sides of the board may have two pins with the same name.
+    pub fn pin(&self, name: &str) -> Option<PinRef<'f>> {
+        self.pins().find(|pin| pin.name == name)
+    }
+}
+
+impl Deref for ShapeRef<'_> {
+    type Target = Shape;
+
+    fn deref(&self) -> &Shape {
+        self.shape
+    }
+}
+
+/// A pin of a shape, along with the file it's in so that its pad can be looked up.
+///
+/// Dereferences to the [Pin] itself.
+#[derive(Debug, Clone, Copy)]
+pub struct PinRef<'f> {
+    file: &'f InterpretedGencadFile,
+    pin: &'f Pin,
+}
+
+impl<'f> PinRef<'f> {
+    /// Returns the pin itself.
+    pub fn pin(&self) -> &'f Pin {
+        self.pin
+    }
+
+    /// Returns the pad or padstack of the pin, or an [Error::UndefinedReference] if its
+    /// `pad_name` is in neither the `PADS` nor the `PADSTACKS` section.
+    pub fn pad_or_padstack(&self) -> Result<PadOrPadstack<'f>, Error> {
+        let name = &self.pin.pad_name;
+        self.file
+            .pad_or_padstack(name)
+            .ok_or_else(|| undefined("SHAPES", "PAD", name))
+    }
+}
+
+impl Deref for PinRef<'_> {
+    type Target = Pin;
+
+    fn deref(&self) -> &Pin {
+        self.pin
+    }
+}
+
+pub(super) fn undefined(section: &str, keyword: &str, name: &str) -> Error {
+    Error::UndefinedReference {
+        section: section.to_string(),
+        keyword: keyword.to_string(),
+        name: name.to_string(),
+    }
+}
@@ -28,6 +28,12 @@
  * 1. [parser]: Converts the file bytes into structured data.
  * 2. [interpreter]: Interprets the parsed file to create a higher-level
  *    representation of the objects in the file.
+ * 3. [writer]: Writes the parsed file back out as GenCAD text.
+ *
+ * Files can also be constructed programmatically with [builder::GencadBuilder],
+ * [transform] places the features of components on the board, and
+ * [tessellate] turns arcs and circles into lists of points. Both parsed and
+ * interpreted files can be converted to other units with `to_units`.
  *
  * ## Usage Example
  *
@@ -57,6 +63,16 @@
  * ```
  */
 
+pub mod builder;
+mod diagnostic;
+mod error;
 pub mod interpreter;
 pub mod parser;
+pub mod tessellate;
+pub mod transform;
 pub mod types;
+mod units;
+pub mod writer;
+
+pub use diagnostic::{Diagnostic, Severity};
+pub use error::Error;
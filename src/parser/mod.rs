@@ -24,6 +24,19 @@
  * This module provides functionality to parse GenCAD files into structured
  * data.
  *
+ * [ParsedGencadFile] reads the whole file at once. For files too large to keep
+ * in memory, [SectionReader] and [RecordReader] read from a
+ * [BufRead](std::io::BufRead) and yield one section or one record at a time.
+ *
+ * The parsed records are generic over the type of their strings, which
+ * defaults to [String]. [BorrowedGencadFile] uses `Cow<'a, str>` instead, so
+ * that strings borrow from the file data rather than being copied.
+ *
+ * With the `rayon` feature enabled, [ParsedGencadFile] parses sections in
+ * parallel, and splits large `COMPONENTS`, `SHAPES` and `SIGNALS` sections
+ * into chunks of records that are parsed in parallel too. The result is the
+ * same as parsing the sections one after another.
+ *
  * ## Usage Example
  *
  * ```no_run
@@ -50,23 +63,42 @@
  * ```
  */
 
+pub(crate) mod number_text;
+#[cfg(feature = "rayon")]
+mod parallel;
 pub mod sections;
-mod types;
+mod stream;
+pub(crate) mod types;
+
+pub use number_text::NumberText;
+pub use stream::{Record, RecordReader, SectionReader};
+
+use std::borrow::Cow;
 
 use nom::bytes::complete::{is_a, tag, take_till, take_while, take_while1};
-use nom::combinator::{fail, map_res};
-use nom::multi::{many0, many1};
+use nom::combinator::map_res;
 use nom::sequence::delimited;
 use nom::{AsChar, IResult, Parser};
 
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::error::{Error, FieldError};
+
+use sections::artworks::{Artwork, parse_artworks};
 use sections::board::Board;
+use sections::changes::Changes;
 use sections::components::{Component, parse_components};
 use sections::devices::{Device, parse_devices};
 use sections::header::Header;
+use sections::layers::Layers;
+use sections::mech::{Mechanical, parse_mech};
 use sections::pads::{Pad, parse_pads};
 use sections::padstacks::Padstacks;
+use sections::pseudos::{Pseudo, parse_pseudos};
+use sections::routes::{Route, parse_routes};
 use sections::shapes::{Shape, parse_shapes};
 use sections::signals::Signals;
+use sections::testpins::TestPins;
+use sections::tracks::Tracks;
 use sections::unknown::Unknown;
 
 fn take_newlines(input: &[u8]) -> IResult<&[u8], &[u8]> {
@@ -102,6 +134,23 @@ fn section_end(input: &[u8]) -> IResult<&[u8], &str> {
     .parse(input)
 }
 
+/// The string types that parsed records can be built with: [String], or [Cow] to borrow from the
+/// input.
+pub trait InputStr<'a>: From<Cow<'a, str>> + AsRef<str> + Clone + Send {}
+
+impl<'a, S: From<Cow<'a, str>> + AsRef<str> + Clone + Send> InputStr<'a> for S {}
+
+/// The location of a statement in the source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The byte offset of the start of the statement.
+    pub offset: usize,
+    /// The length of the statement in bytes, not including the line ending.
+    pub len: usize,
+    /// The 1-based line number of the statement.
+    pub line: usize,
+}
+
 /// A keyword/parameter pair.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct KeywordParam<'a> {
@@ -109,6 +158,10 @@ pub(crate) struct KeywordParam<'a> {
     pub keyword: &'a str,
     /// The parameter associated with the keyword.
     pub parameter: &'a str,
+    /// The 1-based line number of the statement.
+    pub line: usize,
+    /// The byte offset of the start of the statement.
+    pub offset: usize,
 }
 
 impl<'a> KeywordParam<'a> {
@@ -120,9 +173,110 @@ impl<'a> KeywordParam<'a> {
             take_newlines,
         )
             .parse(input)?;
-        let kp = Self { keyword, parameter };
+        let kp = Self {
+            keyword,
+            parameter,
+            line: 0,
+            offset: 0,
+        };
         Ok((remaining, kp))
     }
+
+    /// Returns the location of the statement in the source file.
+    pub(crate) fn span(&self) -> Span {
+        Span {
+            offset: self.offset,
+            // The keyword and parameter are separated by a single space.
+            len: self.keyword.len() + 1 + self.parameter.len(),
+            line: self.line,
+        }
+    }
+
+    /// Creates an error for this statement appearing where it is not allowed.
+    pub(crate) fn unexpected(&self, section: &str) -> Error {
+        Error::UnexpectedKeyword {
+            section: section.to_string(),
+            keyword: self.keyword.to_string(),
+            line: self.line,
+            column: 1,
+        }
+    }
+
+    /// Creates an error for a field of this statement's parameter that could not be parsed.
+    pub(crate) fn bad_field(&self, section: &str, err: impl Into<FieldError>) -> Error {
+        let consumed = self.parameter.len().saturating_sub(err.into().remaining);
+        Error::BadField {
+            section: section.to_string(),
+            keyword: self.keyword.to_string(),
+            line: self.line,
+            // The parameter starts after the keyword and a single space.
+            column: self.keyword.len() + 2 + consumed,
+        }
+    }
+
+    /// Creates a warning for this statement being ignored, with the reason it was ignored.
+    pub(crate) fn ignored(&self, section: &str, reason: &str) -> Diagnostic {
+        self.warning(format!(
+            "ignored {} in ${}: {}",
+            self.keyword, section, reason
+        ))
+    }
+
+    /// Creates a warning for this statement repeating one that was already used.
+    pub(crate) fn duplicate(&self, section: &str) -> Diagnostic {
+        self.warning(format!(
+            "ignored duplicate {} in ${}",
+            self.keyword, section
+        ))
+    }
+
+    /// Creates a warning for this statement replacing a value set by an earlier statement.
+    pub(crate) fn overrides(&self, section: &str) -> Diagnostic {
+        self.warning(format!(
+            "{} in ${} overrides an earlier {}",
+            self.keyword, section, self.keyword
+        ))
+    }
+
+    fn warning(&self, message: String) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Warning,
+            line: self.line,
+            column: Some(1),
+            message,
+        }
+    }
+}
+
+/// Maps byte offsets in the file to line and column numbers.
+struct LineIndex {
+    /// The byte offset of the start of each line.
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(buffer: &[u8]) -> Self {
+        let starts = std::iter::once(0)
+            .chain(
+                buffer
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &c)| c == b'\n')
+                    .map(|(i, _)| i + 1),
+            )
+            .collect();
+        Self { starts }
+    }
+
+    /// Returns the 1-based line number containing the offset.
+    fn line(&self, offset: usize) -> usize {
+        self.starts.partition_point(|&start| start <= offset)
+    }
+
+    /// Returns the 1-based column number of the offset within its line.
+    fn column(&self, offset: usize) -> usize {
+        offset - self.starts[self.line(offset) - 1] + 1
+    }
 }
 
 /// A section in the GenCAD file.
@@ -132,46 +286,294 @@ struct Section<'a> {
     name: &'a str,
     /// List of parameters in the section.
     parameters: Vec<KeywordParam<'a>>,
+    /// The 1-based line number of the section's `$END` line.
+    end_line: usize,
 }
 
 impl<'a> Section<'a> {
-    fn parse(input: &'a [u8]) -> IResult<&'a [u8], Self> {
-        let (remaining, (start_tag, parameters, end_tag)) =
-            (section_start, many0(KeywordParam::parse), section_end).parse(input)?;
+    /// Parses the statements of the section into their structured form, adding a warning to
+    /// `warnings` for each statement that is ignored or overrides an earlier one.
+    fn parse<S: InputStr<'a>>(
+        &self,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<ParsedSection<S>, Error> {
+        let params = &self.parameters;
+        let parsed = match self.name {
+            "HEADER" => Header::new(params, warnings).map(ParsedSection::Header),
+            "BOARD" => Board::new(params, warnings).map(ParsedSection::Board),
+            "PADS" => parse_pads(params, warnings).map(ParsedSection::Pads),
+            "PADSTACKS" => Padstacks::new(params).map(ParsedSection::Padstacks),
+            "SHAPES" => parse_shapes(params, warnings).map(ParsedSection::Shapes),
+            "COMPONENTS" => parse_components(params, warnings).map(ParsedSection::Components),
+            "DEVICES" => parse_devices(params, warnings).map(ParsedSection::Devices),
+            "SIGNALS" => Signals::new(params).map(ParsedSection::Signals),
+            "ROUTES" => parse_routes(params).map(ParsedSection::Routes),
+            "TRACKS" => Tracks::new(params).map(ParsedSection::Tracks),
+            "LAYERS" => Layers::new(params).map(ParsedSection::Layers),
+            "ARTWORKS" => parse_artworks(params).map(ParsedSection::Artworks),
+            "MECH" => parse_mech(params, warnings).map(ParsedSection::Mech),
+            "TESTPINS" => TestPins::new_testpins(params).map(ParsedSection::Testpins),
+            "POWERPINS" => TestPins::new_powerpins(params).map(ParsedSection::Powerpins),
+            "PSEUDOS" => parse_pseudos(params, warnings).map(ParsedSection::Pseudos),
+            "CHANGES" => Changes::new(params).map(ParsedSection::Changes),
+            unknown_name => Unknown::new(unknown_name, params).map(ParsedSection::Unknown),
+        };
+        parsed.map_err(|err| err.at_section_end(self.end_line))
+    }
 
-        if start_tag != end_tag {
-            return fail().parse(input);
+    /// Returns the keywords that start a new record in the section.
+    fn record_keywords(&self) -> &'static [&'static str] {
+        match self.name {
+            "BOARD" => &["CUTOUT", "MASK", "ARTWORK"],
+            "PADS" => &["PAD"],
+            "PADSTACKS" => &["PADSTACK"],
+            "SHAPES" => &["SHAPE"],
+            "COMPONENTS" => &["COMPONENT"],
+            "DEVICES" => &["DEVICE"],
+            "SIGNALS" | "TESTPINS" | "POWERPINS" => &["SIGNAL"],
+            "ROUTES" => &["ROUTE"],
+            "LAYERS" => &["LAYERSET"],
+            "ARTWORKS" => &["ARTWORK"],
+            "MECH" => &["MECHANICAL"],
+            "PSEUDOS" => &["PSEUDO"],
+            _ => &[],
         }
+    }
+
+    /// Removes the statements that caused `err` so that the section can be parsed again.
+    ///
+    /// If the failing statement starts a record, the whole record is removed. Returns `false` if
+    /// the error can't be traced to a statement, in which case the section can't be recovered.
+    fn skip_failed(&mut self, err: &Error) -> bool {
+        let Some(start) = self
+            .parameters
+            .iter()
+            .position(|kp| Some(kp.line) == err.line())
+        else {
+            return false;
+        };
 
-        let name = start_tag;
+        let record_keywords = self.record_keywords();
+        let end = if record_keywords.contains(&self.parameters[start].keyword) {
+            self.parameters[start + 1..]
+                .iter()
+                .position(|kp| record_keywords.contains(&kp.keyword))
+                .map_or(self.parameters.len(), |len| start + 1 + len)
+        } else {
+            start + 1
+        };
+        self.parameters.drain(start..end);
 
-        Ok((remaining, Self { name, parameters }))
+        true
     }
 }
 
-fn sections<'a>(input: &'a [u8]) -> IResult<&'a [u8], Vec<Section<'a>>> {
-    many1(Section::parse).parse(input)
+/// Returns the input following the current line.
+fn skip_line(input: &[u8]) -> &[u8] {
+    let line_end = input
+        .iter()
+        .position(|&c| c == b'\n')
+        .map_or(input.len(), |pos| pos + 1);
+    let rest = &input[line_end..];
+    let blank = rest
+        .iter()
+        .position(|&c| c != b'\r' && c != b'\n')
+        .unwrap_or(rest.len());
+    &rest[blank..]
 }
 
+/// Splits the file into sections and their statements.
+///
+/// In [ParseOptions::recover] mode, lines that can't be parsed are skipped and reported in
+/// `diagnostics`, and sections that are never closed end where the next section starts.
+fn sections<'a>(
+    buffer: &'a [u8],
+    options: &ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<Section<'a>>, Error> {
+    let index = LineIndex::new(buffer);
+    let offset = |rest: &[u8]| buffer.len() - rest.len();
+    let syntax_error = |failed_at: usize| Error::Syntax {
+        line: index.line(failed_at),
+        column: index.column(failed_at),
+    };
+    let failure_point = |err: nom::Err<nom::error::Error<&[u8]>>, rest: &[u8]| match err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => offset(e.input),
+        nom::Err::Incomplete(_) => offset(rest),
+    };
+    let mut report = |err: Error| {
+        if options.recover {
+            diagnostics.push(err.into());
+            Ok(())
+        } else {
+            Err(err)
+        }
+    };
+
+    let mut sections = Vec::new();
+    let mut input = buffer;
+
+    while !input.is_empty() || (sections.is_empty() && !options.recover) {
+        let start_line = index.line(offset(input));
+        let (rest, name) = match section_start(input) {
+            Ok(parsed) => parsed,
+            Err(_) => {
+                report(syntax_error(offset(input)))?;
+                input = skip_line(input);
+                continue;
+            }
+        };
+        input = rest;
+
+        let mut parameters = Vec::new();
+        let end_line = loop {
+            if let Ok((rest, end_name)) = section_end(input) {
+                let end_line = index.line(offset(input));
+                if end_name != name {
+                    report(Error::MismatchedEnd {
+                        section: name.to_string(),
+                        end: end_name.to_string(),
+                        line: end_line,
+                    })?;
+                }
+                input = rest;
+                break end_line;
+            }
+
+            match KeywordParam::parse(input) {
+                Ok((rest, mut kp)) => {
+                    kp.offset = offset(input);
+                    kp.line = index.line(kp.offset);
+                    parameters.push(kp);
+                    input = rest;
+                }
+                Err(_) if input.is_empty() || section_start(input).is_ok() => {
+                    report(Error::UnterminatedSection {
+                        section: name.to_string(),
+                        line: start_line,
+                    })?;
+                    break index.line(offset(input));
+                }
+                Err(err) => {
+                    report(syntax_error(failure_point(err, input)))?;
+                    input = skip_line(input);
+                }
+            }
+        };
+
+        sections.push(Section {
+            name,
+            parameters,
+            end_line,
+        });
+    }
+
+    Ok(sections)
+}
+
+/// Parses a section into its structured form.
+///
+/// In [ParseOptions::recover] mode, statements that can't be parsed are skipped and reported in
+/// `diagnostics`, and `None` is returned if the whole section had to be skipped. The text of the
+/// numbers of the statements that are left is added to `numbers` if
+/// [ParseOptions::keep_number_text] is set.
+fn parse_section<'a, S: InputStr<'a>>(
+    mut section: Section<'a>,
+    options: &ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+    numbers: &mut NumberText,
+) -> Result<Option<ParsedSection<S>>, Error> {
+    loop {
+        // Only keep the warnings from the attempt that succeeds.
+        let mut warnings = Vec::new();
+        match section.parse(&mut warnings) {
+            Ok(parsed) => {
+                diagnostics.append(&mut warnings);
+                if options.keep_number_text {
+                    numbers.push_section(section.name, &section.parameters);
+                }
+                return Ok(Some(parsed));
+            }
+            Err(err) if options.recover => {
+                let recovered = section.skip_failed(&err);
+                diagnostics.push(err.into());
+                if !recovered {
+                    return Ok(None);
+                }
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Parses each of the sections in turn, dropping any that had to be skipped.
+#[cfg(not(feature = "rayon"))]
+fn parse_sections<'a, S: InputStr<'a>>(
+    sections: Vec<Section<'a>>,
+    options: &ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+    numbers: &mut NumberText,
+) -> Result<Vec<ParsedSection<S>>, Error> {
+    let mut parsed = Vec::new();
+    for section in sections {
+        if let Some(section) = parse_section(section, options, diagnostics, numbers)? {
+            parsed.push(section);
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(feature = "rayon")]
+use parallel::parse_sections;
+
 /// A section in the GenCAD file.
 #[derive(Debug, Clone, PartialEq)]
-pub enum ParsedSection {
-    Header(Header),
-    Board(Board),
-    Pads(Vec<Pad>),
-    Padstacks(Padstacks),
-    Shapes(Vec<Shape>),
-    Components(Vec<Component>),
-    Devices(Vec<Device>),
-    Signals(Signals),
-    Unknown(Unknown),
+pub enum ParsedSection<S = String> {
+    Header(Header<S>),
+    Board(Board<S>),
+    Pads(Vec<Pad<S>>),
+    Padstacks(Padstacks<S>),
+    Shapes(Vec<Shape<S>>),
+    Components(Vec<Component<S>>),
+    Devices(Vec<Device<S>>),
+    Signals(Signals<S>),
+    Routes(Vec<Route<S>>),
+    Tracks(Tracks<S>),
+    Layers(Layers<S>),
+    Artworks(Vec<Artwork<S>>),
+    Mech(Vec<Mechanical<S>>),
+    Testpins(TestPins<S>),
+    Powerpins(TestPins<S>),
+    Pseudos(Vec<Pseudo<S>>),
+    Changes(Changes<S>),
+    Unknown(Unknown<S>),
+}
+
+/// Options controlling how a GenCAD file is parsed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Skip statements and sections that can't be parsed instead of failing, and report each of
+    /// them in [ParsedGencadFile::diagnostics].
+    ///
+    /// A bad statement that starts a record (e.g., `COMPONENT`) skips the whole record, and a
+    /// section missing one of its required statements is skipped entirely.
+    pub recover: bool,
+    /// Keep the text of the numbers in [ParsedGencadFile::number_text], so that
+    /// [ParsedGencadFile::write] writes the numbers that haven't changed back exactly as they
+    /// were, e.g., `1200.0` rather than `1200`.
+    pub keep_number_text: bool,
 }
 
 /// A fully parsed GenCAD file.
 #[derive(Debug, Clone, PartialEq)]
-pub struct ParsedGencadFile {
+pub struct ParsedGencadFile<S = String> {
     /// The parsed sections of the file.
-    pub sections: Vec<ParsedSection>,
+    pub sections: Vec<ParsedSection<S>>,
+    /// Problems that were recovered from while parsing the file, and warnings about statements
+    /// that were ignored or overrode earlier ones, in file order.
+    pub diagnostics: Vec<Diagnostic>,
+    /// The text of the numbers in the file, if [ParseOptions::keep_number_text] was set.
+    pub number_text: NumberText,
 }
 
 impl ParsedGencadFile {
@@ -183,46 +585,63 @@ impl ParsedGencadFile {
     ///
     /// # Returns
     ///
-    /// A `Result` containing the parsed file or an error.
-    pub fn new<R: std::io::Read>(mut reader: R) -> Result<Self, Box<dyn std::error::Error>> {
+    /// A `Result` containing the parsed file or an [Error] locating the first problem found.
+    pub fn new<R: std::io::Read>(reader: R) -> Result<Self, Error> {
+        Self::with_options(reader, &ParseOptions::default())
+    }
+
+    /// Parses a GenCAD file into a structured format using the given options.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A reader over the file data.
+    /// * `options` - Options controlling how the file is parsed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the parsed file or an [Error]. In [ParseOptions::recover] mode, only
+    /// failing to read the file is an error.
+    pub fn with_options<R: std::io::Read>(
+        mut reader: R,
+        options: &ParseOptions,
+    ) -> Result<Self, Error> {
         let mut buffer = Vec::new();
         reader.read_to_end(&mut buffer)?;
+        Self::from_slice(&buffer, options)
+    }
+}
 
-        let (remaining, unparsed_sections) = sections(&buffer).map_err(|err| err.to_owned())?;
+/// A fully parsed GenCAD file whose strings borrow from the file data.
+pub type BorrowedGencadFile<'a> = ParsedGencadFile<Cow<'a, str>>;
 
-        if !remaining.is_empty() {
-            return Err(format!("Unparsed data remaining in file: {:?}", remaining).into());
-        }
+impl<'a, S: InputStr<'a>> ParsedGencadFile<S> {
+    /// Parses GenCAD file data that is already in memory.
+    ///
+    /// With `S` set to `Cow<'a, str>`, as in [BorrowedGencadFile], strings borrow from `buffer`
+    /// instead of being copied, and only quoted strings containing backslashes are allocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The file data.
+    /// * `options` - Options controlling how the file is parsed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the parsed file or an [Error]. In [ParseOptions::recover] mode,
+    /// this never fails.
+    pub fn from_slice(buffer: &'a [u8], options: &ParseOptions) -> Result<Self, Error> {
+        let mut diagnostics = Vec::new();
+        let mut number_text = NumberText::default();
+        let sections = self::sections(buffer, options, &mut diagnostics)?;
+        let sections = parse_sections(sections, options, &mut diagnostics, &mut number_text)?;
 
-        let mut sections = Vec::new();
-
-        for section in unparsed_sections {
-            match section.name {
-                "HEADER" => sections.push(ParsedSection::Header(Header::new(&section.parameters)?)),
-                "BOARD" => sections.push(ParsedSection::Board(Board::new(&section.parameters)?)),
-                "PADS" => sections.push(ParsedSection::Pads(parse_pads(&section.parameters)?)),
-                "PADSTACKS" => sections.push(ParsedSection::Padstacks(Padstacks::new(
-                    &section.parameters,
-                )?)),
-                "SHAPES" => {
-                    sections.push(ParsedSection::Shapes(parse_shapes(&section.parameters)?))
-                }
-                "COMPONENTS" => sections.push(ParsedSection::Components(parse_components(
-                    &section.parameters,
-                )?)),
-                "DEVICES" => {
-                    sections.push(ParsedSection::Devices(parse_devices(&section.parameters)?))
-                }
-                "SIGNALS" => {
-                    sections.push(ParsedSection::Signals(Signals::new(&section.parameters)?))
-                }
-                unknown_name => sections.push(ParsedSection::Unknown(Unknown::new(
-                    &unknown_name,
-                    &section.parameters,
-                )?)),
-            }
-        }
+        // Structural problems are found before the sections are parsed, so restore file order.
+        diagnostics.sort_by_key(|diagnostic| diagnostic.line);
 
-        Ok(Self { sections })
+        Ok(Self {
+            sections,
+            diagnostics,
+            number_text,
+        })
     }
 }
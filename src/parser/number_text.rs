@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  The original text of the numbers in a GenCAD file.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+
+use super::KeywordParam;
+use super::types::number;
+use crate::types::Number;
+
+/// The text the numbers of a file were written as, e.g., `1200.0` or `+.5`, so that they can be
+/// written back the same way.
+///
+/// Only the numbers that aren't in their shortest form are kept, by where they are in the file:
+/// their section, their statement in the section, and their word in the statement's parameter. A
+/// writer only uses a text for a number in the same place that still has the value it was read
+/// as, so changing, adding or removing numbers doesn't change how the other numbers are written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumberText {
+    /// The texts of the sections, by section name, with one for each section with that name in
+    /// file order.
+    sections: HashMap<String, Vec<SectionNumberText>>,
+}
+
+impl NumberText {
+    /// Returns `true` if no texts are kept.
+    pub fn is_empty(&self) -> bool {
+        self.sections
+            .values()
+            .flatten()
+            .all(|section| section.statements.is_empty())
+    }
+
+    /// Returns the texts of the `index`th section with the name, counting from 0.
+    pub(crate) fn section(&self, name: &str, index: usize) -> Option<&SectionNumberText> {
+        self.sections.get(name)?.get(index)
+    }
+
+    /// Keeps the texts of the numbers in the statements of a section, after the sections already
+    /// kept.
+    pub(crate) fn push_section(&mut self, name: &str, statements: &[KeywordParam]) {
+        let mut section = SectionNumberText::default();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for statement in statements {
+            let count = counts.entry(statement.keyword).or_default();
+            let index = *count;
+            *count += 1;
+
+            let texts: Vec<_> = words(statement.parameter)
+                .enumerate()
+                .filter(|(_, word)| {
+                    matches!(number(word), Ok(("", value)) if value.to_string() != *word)
+                })
+                .map(|(i, word)| (i, word.to_string()))
+                .collect();
+            if !texts.is_empty() {
+                section
+                    .statements
+                    .entry(statement.keyword.to_string())
+                    .or_default()
+                    .insert(index, texts);
+            }
+        }
+        self.sections
+            .entry(name.to_string())
+            .or_default()
+            .push(section);
+    }
+
+    /// Moves the sections of `other` after the sections of `self`, leaving `other` empty.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn append(&mut self, other: &mut Self) {
+        for (name, mut sections) in other.sections.drain() {
+            self.sections.entry(name).or_default().append(&mut sections);
+        }
+    }
+}
+
+/// The text of the numbers of a single section.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct SectionNumberText {
+    /// The texts of the numbers in each statement, with the index of their word in the
+    /// parameter, by keyword and by the index of the statement among the statements of the
+    /// section with that keyword.
+    statements: HashMap<String, HashMap<usize, Vec<(usize, String)>>>,
+}
+
+impl SectionNumberText {
+    /// Returns the text of the number in the `word`th word of the `index`th statement with the
+    /// keyword, if it reads back as `value`.
+    pub(crate) fn get(
+        &self,
+        keyword: &str,
+        index: usize,
+        word: usize,
+        value: Number,
+    ) -> Option<&str> {
+        let (_, text) = self
+            .statements
+            .get(keyword)?
+            .get(&index)?
+            .iter()
+            .find(|(i, _)| *i == word)?;
+        // Compare the bits so that, e.g., `-0.0` isn't used for `0`.
+        match number(text) {
+            Ok(("", read)) if read.to_bits() == value.to_bits() => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the parameter of a statement into its words, which are separated by spaces.
+///
+/// A word starting with a quote mark runs until the closing quote, so quoted strings are one word.
+pub(crate) fn words(mut parameter: &str) -> impl Iterator<Item = &str> {
+    std::iter::from_fn(move || {
+        parameter = parameter.trim_start_matches(' ');
+        if parameter.is_empty() {
+            return None;
+        }
+
+        let mut quoted = parameter.starts_with('"');
+        let mut escaped = false;
+        let end = parameter
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' if quoted => escaped = true,
+                    '"' if quoted => quoted = false,
+                    ' ' => return !quoted,
+                    _ => (),
+                }
+                false
+            })
+            .map_or(parameter.len(), |(i, _)| i);
+        let (word, rest) = parameter.split_at(end);
+        parameter = rest;
+        Some(word)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        let split = |parameter| words(parameter).collect::<Vec<_>>();
+        assert_eq!(split("1 p  -100 100.0"), ["1", "p", "-100", "100.0"]);
+        assert_eq!(
+            split(r#"10 0 TOP "A \" B" 1.0 "#),
+            ["10", "0", "TOP", r#""A \" B""#, "1.0"]
+        );
+        assert_eq!(split(r#"A"B C"#), [r#"A"B"#, "C"]);
+        assert!(split("  ").is_empty());
+    }
+
+    #[test]
+    fn test_section() {
+        let statements = [
+            KeywordParam {
+                keyword: "LINE",
+                parameter: "0 0 1200.0 .5",
+                line: 1,
+                offset: 0,
+            },
+            KeywordParam {
+                keyword: "TRACK",
+                parameter: "1.0",
+                line: 2,
+                offset: 19,
+            },
+            KeywordParam {
+                keyword: "LINE",
+                parameter: "1200 1e3 0 0",
+                line: 3,
+                offset: 29,
+            },
+        ];
+        let mut number_text = NumberText::default();
+        number_text.push_section("BOARD", &statements);
+        number_text.push_section("BOARD", &[]);
+        assert!(!number_text.is_empty());
+        assert!(number_text.section("BOARD", 1).is_some());
+        assert!(number_text.section("BOARD", 2).is_none());
+
+        let section = number_text.section("BOARD", 0).unwrap();
+        assert_eq!(section.get("LINE", 0, 2, 1200.0), Some("1200.0"));
+        assert_eq!(section.get("LINE", 0, 3, 0.5), Some(".5"));
+        assert_eq!(section.get("LINE", 1, 1, 1000.0), Some("1e3"));
+        assert_eq!(section.get("TRACK", 0, 0, 1.0), Some("1.0"));
+        // Numbers in their shortest form aren't kept, and texts are only used for their value.
+        assert_eq!(section.get("LINE", 1, 0, 1200.0), None);
+        assert_eq!(section.get("LINE", 0, 2, 1201.0), None);
+    }
+}
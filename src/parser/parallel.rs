@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parallel parsing of GenCAD sections.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use rayon::prelude::*;
+
+use super::{InputStr, NumberText, ParseOptions, ParsedSection, Section, parse_section};
+use crate::diagnostic::Diagnostic;
+use crate::error::Error;
+
+/// The number of statements a section needs to have before it's split, and the minimum number of
+/// statements in each chunk it's split into.
+const CHUNK_LEN: usize = 4096;
+
+/// The sections whose records don't depend on each other, so can be parsed separately.
+const SPLIT_SECTIONS: [&str; 3] = ["COMPONENTS", "SHAPES", "SIGNALS"];
+
+/// Parses the sections in parallel, dropping any that had to be skipped.
+///
+/// The parsed sections and their diagnostics are the same as when the sections are parsed one
+/// after another, and the error returned is the one from the earliest failing section.
+pub(super) fn parse_sections<'a, S: InputStr<'a>>(
+    sections: Vec<Section<'a>>,
+    options: &ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+    numbers: &mut NumberText,
+) -> Result<Vec<ParsedSection<S>>, Error> {
+    let results: Vec<_> = sections
+        .into_par_iter()
+        .map(|section| {
+            let mut section_diagnostics = Vec::new();
+            let mut section_numbers = NumberText::default();
+            let parsed = parse_split_section(
+                section,
+                options,
+                &mut section_diagnostics,
+                &mut section_numbers,
+            );
+            (parsed, section_diagnostics, section_numbers)
+        })
+        .collect();
+
+    let mut parsed = Vec::new();
+    for (result, mut section_diagnostics, mut section_numbers) in results {
+        diagnostics.append(&mut section_diagnostics);
+        numbers.append(&mut section_numbers);
+        if let Some(section) = result? {
+            parsed.push(section);
+        }
+    }
+    Ok(parsed)
+}
+
+/// Parses a section, splitting it into chunks of records that are parsed in parallel if it's
+/// large enough.
+///
+/// If any of the chunks fails to parse, the section is parsed again in one piece, so that the
+/// error and any recovery from it are the same as without splitting.
+fn parse_split_section<'a, S: InputStr<'a>>(
+    section: Section<'a>,
+    options: &ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+    numbers: &mut NumberText,
+) -> Result<Option<ParsedSection<S>>, Error> {
+    if !SPLIT_SECTIONS.contains(&section.name) || section.parameters.len() < 2 * CHUNK_LEN {
+        return parse_section(section, options, diagnostics, numbers);
+    }
+
+    let chunks: Result<Vec<_>, Error> = split(&section)
+        .into_par_iter()
+        .map(|chunk| {
+            let mut warnings = Vec::new();
+            chunk.parse(&mut warnings).map(|parsed| (parsed, warnings))
+        })
+        .collect();
+    let Ok(chunks) = chunks else {
+        return parse_section(section, options, diagnostics, numbers);
+    };
+
+    let mut parsed = Vec::new();
+    for (chunk, mut warnings) in chunks {
+        diagnostics.append(&mut warnings);
+        parsed.push(chunk);
+    }
+    // The numbers are kept from the whole section, since each chunk repeats some statements.
+    if options.keep_number_text {
+        numbers.push_section(section.name, &section.parameters);
+    }
+    Ok(merge(parsed))
+}
+
+/// Splits a section at record boundaries into chunks of at least [CHUNK_LEN] statements.
+///
+/// The statements before the first `SHAPE` set the default `INSERT` type of every shape, so they
+/// are repeated at the start of each chunk of the `SHAPES` section.
+fn split<'a>(section: &Section<'a>) -> Vec<Section<'a>> {
+    let params = &section.parameters;
+    let record_keywords = section.record_keywords();
+
+    let first_record = params
+        .iter()
+        .position(|kp| record_keywords.contains(&kp.keyword))
+        .unwrap_or(params.len());
+    let context = match section.name {
+        "SHAPES" => &params[..first_record],
+        _ => &[],
+    };
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    for (i, kp) in params.iter().enumerate() {
+        if i - start >= CHUNK_LEN && record_keywords.contains(&kp.keyword) {
+            chunks.push(&params[start..i]);
+            start = i;
+        }
+    }
+    chunks.push(&params[start..]);
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| {
+            let context = if i == 0 { &[] } else { context };
+            Section {
+                name: section.name,
+                parameters: context.iter().chain(chunk).copied().collect(),
+                end_line: section.end_line,
+            }
+        })
+        .collect()
+}
+
+/// Joins the parsed chunks of a section back together.
+fn merge<S>(chunks: Vec<ParsedSection<S>>) -> Option<ParsedSection<S>> {
+    chunks
+        .into_iter()
+        .reduce(|merged, chunk| match (merged, chunk) {
+            (ParsedSection::Components(mut merged), ParsedSection::Components(chunk)) => {
+                merged.extend(chunk);
+                ParsedSection::Components(merged)
+            }
+            (ParsedSection::Shapes(mut merged), ParsedSection::Shapes(chunk)) => {
+                merged.extend(chunk);
+                ParsedSection::Shapes(merged)
+            }
+            (ParsedSection::Signals(mut merged), ParsedSection::Signals(chunk)) => {
+                merged.signals.extend(chunk.signals);
+                merged.attributes.extend(chunk.attributes);
+                ParsedSection::Signals(merged)
+            }
+            _ => unreachable!("only COMPONENTS, SHAPES and SIGNALS sections are split"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::sections;
+
+    /// Builds a file with large `SHAPES`, `COMPONENTS` and `SIGNALS` sections.
+    fn large_file(records: usize, extra: impl Fn(usize) -> &'static str) -> Vec<u8> {
+        let mut file = String::from("$SHAPES\r\nINSERT SMD\r\n");
+        for i in 0..records {
+            file += &format!("SHAPE S{i}\r\nLINE 0 0 {i}.0 0\r\nPIN 1 p 0 0 TOP 0 0\r\n");
+        }
+        file += "$ENDSHAPES\r\n$COMPONENTS\r\n";
+        for i in 0..records {
+            file += &format!(
+                "COMPONENT C{i}\r\nDEVICE D\r\nPLACE {i} 0\r\nLAYER TOP\r\nROTATION 0\r\nSHAPE S{i} 0 0\r\n{}",
+                extra(i)
+            );
+        }
+        file += "$ENDCOMPONENTS\r\n$SIGNALS\r\n";
+        for i in 0..records {
+            file += &format!("SIGNAL N{i}\r\nNODE C{i} 1\r\nATTRIBUTE net n \"{i}\"\r\n");
+        }
+        file += "$ENDSIGNALS\r\n";
+        file.into_bytes()
+    }
+
+    /// The parsed sections, or the error as a string so that results can be compared, the
+    /// diagnostics and the numbers.
+    type Outcome = (
+        Result<Vec<ParsedSection>, String>,
+        Vec<Diagnostic>,
+        NumberText,
+    );
+
+    /// Parses the file both in parallel and one section after another.
+    fn parse_both(buffer: &[u8], options: &ParseOptions) -> [Outcome; 2] {
+        let parallel = {
+            let mut diagnostics = Vec::new();
+            let mut numbers = NumberText::default();
+            let split = sections(buffer, options, &mut diagnostics).unwrap();
+            let parsed = parse_sections(split, options, &mut diagnostics, &mut numbers);
+            (parsed.map_err(|err| err.to_string()), diagnostics, numbers)
+        };
+        let sequential = {
+            let mut diagnostics = Vec::new();
+            let mut numbers = NumberText::default();
+            let parsed: Result<_, Error> = sections(buffer, options, &mut diagnostics)
+                .unwrap()
+                .into_iter()
+                .filter_map(|section| {
+                    parse_section(section, options, &mut diagnostics, &mut numbers).transpose()
+                })
+                .collect();
+            (parsed.map_err(|err| err.to_string()), diagnostics, numbers)
+        };
+        [parallel, sequential]
+    }
+
+    #[test]
+    fn test_split() {
+        let buffer = large_file(3000, |_| "");
+        let mut diagnostics = Vec::new();
+        let split_sections = sections(&buffer, &ParseOptions::default(), &mut diagnostics).unwrap();
+
+        for section in &split_sections {
+            let chunks = split(section);
+            assert!(chunks.len() > 1);
+            assert_eq!(
+                chunks
+                    .iter()
+                    .map(|chunk| chunk.parameters.len())
+                    .sum::<usize>(),
+                section.parameters.len()
+                    + usize::from(section.name == "SHAPES") * (chunks.len() - 1)
+            );
+            for chunk in &chunks[1..] {
+                let first = chunk.parameters[0].keyword;
+                match section.name {
+                    "SHAPES" => assert_eq!(first, "INSERT"),
+                    _ => assert_eq!(first, section.record_keywords()[0]),
+                }
+            }
+        }
+
+        let options = ParseOptions {
+            keep_number_text: true,
+            ..Default::default()
+        };
+        let [parallel, sequential] = parse_both(&buffer, &options);
+        assert_eq!(parallel, sequential);
+        let shapes_text = parallel.2.section("SHAPES", 0).unwrap();
+        assert_eq!(shapes_text.get("LINE", 2999, 2, 2999.0), Some("2999.0"));
+        assert_eq!(shapes_text.get("LINE", 2999, 2, 2998.0), None);
+        let parsed = parallel.0.unwrap();
+        let ParsedSection::Shapes(shapes) = &parsed[0] else {
+            panic!("expected SHAPES");
+        };
+        assert_eq!(shapes.len(), 3000);
+        assert!(shapes[0].insert.is_some());
+        assert!(shapes.iter().all(|shape| shape.insert == shapes[0].insert));
+    }
+
+    #[test]
+    fn test_warnings_and_errors() {
+        // Duplicate statements are reported as warnings in file order.
+        let buffer = large_file(3000, |i| if i % 1000 == 0 { "LAYER TOP\r\n" } else { "" });
+        let [parallel, sequential] = parse_both(&buffer, &ParseOptions::default());
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.1.len(), 3);
+
+        // A bad statement in a later chunk fails the same way.
+        let buffer = large_file(3000, |i| if i == 2500 { "FOO 1\r\n" } else { "" });
+        let [parallel, sequential] = parse_both(&buffer, &ParseOptions::default());
+        assert!(parallel.0.is_err());
+        assert_eq!(parallel, sequential);
+
+        // And is recovered from the same way.
+        let options = ParseOptions {
+            recover: true,
+            keep_number_text: true,
+        };
+        let [parallel, sequential] = parse_both(&buffer, &options);
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.1.len(), 1);
+    }
+}
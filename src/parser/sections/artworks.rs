@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD ARTWORKS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use nom::Parser;
+use nom::sequence::preceded;
+
+use crate::error::{Error, FieldError};
+use crate::parser::sections::board::{ArtworkComponent, Text};
+use crate::parser::types::util::spaces;
+use crate::parser::types::{
+    arc_ref, artwork_name, attrib_ref, circle_ref, filled_ref, layer, line_ref, rectangle_ref,
+    text_par, track_name, x_y_ref,
+};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::Attribute;
+
+const SECTION: &str = "ARTWORKS";
+
+/// A reusable artwork feature defined in the `ARTWORKS` section, referenced by name from the
+/// `SHAPES` and `COMPONENTS` sections. All coordinates are relative to the artwork's origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Artwork<S = String> {
+    /// The unique name of the artwork.
+    pub name: S,
+    /// Components (layers, tracks, lines, arcs, text, etc.) defining the artwork.
+    pub components: Vec<ArtworkComponent<S>>,
+    /// Additional metadata associated with the artwork.
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `ARTWORK` statement, if the artwork was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> Artwork<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, name) = artwork_name(params)?;
+
+        Ok(Self {
+            name,
+            components: Vec::new(),
+            attributes: Vec::new(),
+            span: Some(span),
+        })
+    }
+
+    fn update(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
+        match kp.keyword {
+            "LAYER" => {
+                let (_, layer) = layer(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components.push(ArtworkComponent::Layer(layer));
+                Ok(())
+            }
+            "TRACK" => {
+                let (_, track) =
+                    track_name(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components.push(ArtworkComponent::Track(track));
+                Ok(())
+            }
+            "FILLED" => {
+                let (_, filled) =
+                    filled_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components.push(ArtworkComponent::Filled(filled));
+                Ok(())
+            }
+            "LINE" => {
+                let (_, line) = line_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components.push(ArtworkComponent::Line(line));
+                Ok(())
+            }
+            "ARC" => {
+                let (_, arc) = arc_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components.push(ArtworkComponent::Arc(arc));
+                Ok(())
+            }
+            "CIRCLE" => {
+                let (_, circle) =
+                    circle_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components.push(ArtworkComponent::Circle(circle));
+                Ok(())
+            }
+            "RECTANGLE" => {
+                let (_, rectangle) =
+                    rectangle_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components.push(ArtworkComponent::Rectangle(rectangle));
+                Ok(())
+            }
+            "TEXT" => {
+                let (_, (origin, text)) = (x_y_ref, preceded(spaces, text_par))
+                    .parse(kp.parameter)
+                    .map_err(|err| kp.bad_field(SECTION, err))?;
+                self.components
+                    .push(ArtworkComponent::Text(Text::new(origin, text)));
+                Ok(())
+            }
+            "ATTRIBUTE" => {
+                let (_, attribute) =
+                    attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.attributes.push(attribute);
+                Ok(())
+            }
+
+            _ => Err(kp.unexpected(SECTION)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ArtworksParserState<S> {
+    Reset,
+    Artwork(Artwork<S>),
+}
+
+struct ArtworksParser<S> {
+    state: ArtworksParserState<S>,
+    artworks: Vec<Artwork<S>>,
+}
+
+impl<'a, S: InputStr<'a>> ArtworksParser<S> {
+    fn new() -> Self {
+        let state = ArtworksParserState::Reset;
+        let artworks = Vec::new();
+        Self { state, artworks }
+    }
+
+    fn ingest(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
+        if let ArtworksParserState::Artwork(ref mut artwork) = self.state {
+            match kp.keyword {
+                "ARTWORK" => {
+                    self.artworks.push(artwork.clone());
+                    let artwork = Artwork::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = ArtworksParserState::Artwork(artwork);
+                    Ok(())
+                }
+                _ => artwork.update(kp),
+            }
+        } else {
+            match kp.keyword {
+                "ARTWORK" => {
+                    let artwork = Artwork::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = ArtworksParserState::Artwork(artwork);
+                    Ok(())
+                }
+                _ => Err(kp.unexpected(SECTION)),
+            }
+        }
+    }
+
+    fn finalize(mut self) -> Result<Vec<Artwork<S>>, Error> {
+        if let ArtworksParserState::Artwork(artwork) = self.state {
+            self.artworks.push(artwork);
+            self.state = ArtworksParserState::Reset;
+        }
+        Ok(self.artworks)
+    }
+}
+
+/// Parse the `ARTWORKS` section of a GenCAD file.
+pub(crate) fn parse_artworks<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+) -> Result<Vec<Artwork<S>>, Error> {
+    let mut ap = ArtworksParser::new();
+    for param in params {
+        ap.ingest(param)?;
+    }
+    ap.finalize()
+}
@@ -21,15 +21,19 @@
 use nom::Parser;
 use nom::sequence::preceded;
 
-use crate::parser::KeywordParam;
+use crate::diagnostic::Diagnostic;
+use crate::error::Error;
 use crate::parser::types::{
     arc_ref, attrib_ref, circle_ref, filled_ref, layer, line_ref, number, rectangle_ref, string,
     text_par, track_name, util::spaces, x_y_ref,
 };
+use crate::parser::{InputStr, KeywordParam};
 use crate::types::{
     ArcRef, Attribute, CircleRef, Layer, LineRef, Number, RectangleRef, TextPar, XYRef,
 };
 
+const SECTION: &str = "BOARD";
+
 /// Represents a geometric shape used in board outlines, cutouts, or masks.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BoardShape {
@@ -45,18 +49,17 @@ pub enum BoardShape {
 
 /// Represents an internal area of the board where all layers are cut away.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Cutout {
+pub struct Cutout<S = String> {
     /// A unique identifier for the cutout (e.g., "cutout1", "cutout2").
-    pub name: String,
+    pub name: S,
     /// Geometric shapes defining the cutout's boundary.
     pub shapes: Vec<BoardShape>,
     /// Additional metadata associated with the cutout.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Cutout {
-    fn new(name: &str) -> Self {
-        let name = name.to_string();
+impl<'a, S: InputStr<'a>> Cutout<S> {
+    fn new(name: S) -> Self {
         let shapes = Vec::new();
         let attributes = Vec::new();
         Self {
@@ -69,20 +72,19 @@ impl Cutout {
 
 /// Represents an area of the board that is inaccessible to test pins.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Mask {
+pub struct Mask<S = String> {
     /// A unique identifier for the masked area (e.g., "mask1", "mask2").
-    pub name: String,
+    pub name: S,
     /// The board layer to which this mask applies (e.g., [Layer::Top], [Layer::Bottom]).
     pub layer: Layer,
     /// Geometric shapes defining the masked area's boundary.
     pub shapes: Vec<BoardShape>,
     /// Additional metadata associated with the mask.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Mask {
-    fn new(name: &str, layer: Layer) -> Self {
-        let name = name.to_string();
+impl<'a, S: InputStr<'a>> Mask<S> {
+    fn new(name: S, layer: Layer) -> Self {
         let shapes = Vec::new();
         let attributes = Vec::new();
         Self {
@@ -96,22 +98,22 @@ impl Mask {
 
 /// Represents a text string attached to a component or artwork feature.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Text {
+pub struct Text<S = String> {
     /// The bottom-left corner of the text relative to the component's origin.
     pub origin: XYRef,
     /// Specifies the text's size, rotation, mirror, layer, and bounding rectangle.
-    pub text: TextPar,
+    pub text: TextPar<S>,
 }
 
-impl Text {
-    fn new(origin: XYRef, text: TextPar) -> Self {
+impl<'a, S: InputStr<'a>> Text<S> {
+    pub(crate) fn new(origin: XYRef, text: TextPar<S>) -> Self {
         Self { origin, text }
     }
 }
 
 /// Represents a component of an artwork feature on the board.
 #[derive(Debug, Clone, PartialEq)]
-pub enum ArtworkComponent {
+pub enum ArtworkComponent<S = String> {
     /// A straight line forming part of the artwork.
     Line(LineRef),
     /// A circular arc forming part of the artwork.
@@ -121,29 +123,30 @@ pub enum ArtworkComponent {
     /// A rectangle forming part of the artwork.
     Rectangle(RectangleRef),
     /// A track type defined in the `TRACKS` section.
-    Track(String),
+    Track(S),
+    /// The layer of the following artwork components. Only used in the `ARTWORKS` section.
+    Layer(Layer),
     /// Indicates whether the following shapes form an enclosed area.
     Filled(bool),
     /// A text string attached to the artwork.
-    Text(Text),
+    Text(Text<S>),
 }
 
 /// Represents an artwork feature on the board (e.g., silkscreen, routing).
 #[derive(Debug, Clone, PartialEq)]
-pub struct Artwork {
+pub struct Artwork<S = String> {
     /// A unique identifier for the artwork feature (e.g., "artwork1", "artwork2").
-    pub name: String,
+    pub name: S,
     /// The board layer to which this artwork applies (e.g., [Layer::Top], [Layer::Bottom]).
     pub layer: Layer,
     /// Components (lines, arcs, text, etc.) defining the artwork.
-    pub components: Vec<ArtworkComponent>,
+    pub components: Vec<ArtworkComponent<S>>,
     /// Additional metadata associated with the artwork.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Artwork {
-    fn new(name: &str, layer: Layer) -> Self {
-        let name = name.to_string();
+impl<'a, S: InputStr<'a>> Artwork<S> {
+    fn new(name: S, layer: Layer) -> Self {
         let components = Vec::new();
         let attributes = Vec::new();
         Self {
@@ -157,36 +160,39 @@ impl Artwork {
 
 /// Represents a subsection within the `BOARD` section (e.g., cutouts, masks, artwork).
 #[derive(Debug, Clone, PartialEq)]
-pub enum Subsection {
+pub enum Subsection<S = String> {
     /// A named internal area of the board where all layers are cut away.
-    Cutout(Cutout),
+    Cutout(Cutout<S>),
     /// A named area of the board that is inaccessible to test pins.
-    Mask(Mask),
+    Mask(Mask<S>),
     /// A named artwork feature on the board.
-    Artwork(Artwork),
+    Artwork(Artwork<S>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum BoardParserState {
+enum BoardParserState<S> {
     Board,
-    Subsection(Subsection),
+    Subsection(Subsection<S>),
 }
 
 /// Represents the `BOARD` section of a GenCAD file, defining the board's outer shape and internal features.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Board {
+pub struct Board<S = String> {
     /// The thickness of the board in the [crate::types::Dimension] units specified in the `HEADER` section.
     pub thickness: Option<Number>,
     /// Geometric shapes defining the board's outer edge.
     pub outline_shapes: Vec<BoardShape>,
     /// Additional metadata associated with the board.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
     /// Subsections such as cutouts, masks, and artwork features.
-    pub subsections: Vec<Subsection>,
+    pub subsections: Vec<Subsection<S>>,
 }
 
-impl Board {
-    pub(crate) fn new(params: &[KeywordParam]) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Board<S> {
+    pub(crate) fn new(
+        params: &[KeywordParam<'a>],
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<Self, Error> {
         let mut thickness = None;
         let mut outline_shapes = Vec::new();
         let mut attributes = Vec::new();
@@ -196,14 +202,20 @@ impl Board {
 
         for param in params {
             match param.keyword {
-                "THICKNESS" => {
-                    if parser_state == BoardParserState::Board && thickness.is_none() {
-                        let (_, value) = number(param.parameter).map_err(|err| err.to_owned())?;
-                        thickness = Some(value);
-                    }
+                "THICKNESS"
+                    if matches!(parser_state, BoardParserState::Board) && thickness.is_none() =>
+                {
+                    let (_, value) =
+                        number(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    thickness = Some(value);
                 }
+                "THICKNESS" if matches!(parser_state, BoardParserState::Board) => {
+                    warnings.push(param.duplicate(SECTION));
+                }
+                "THICKNESS" => warnings.push(param.ignored(SECTION, "not allowed in a subsection")),
                 "LINE" => {
-                    let (_, line) = line_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, line) =
+                        line_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Cutout(ref mut cutout) => {
@@ -222,7 +234,8 @@ impl Board {
                     }
                 }
                 "ARC" => {
-                    let (_, arc) = arc_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, arc) =
+                        arc_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Cutout(ref mut cutout) => {
@@ -241,7 +254,8 @@ impl Board {
                     }
                 }
                 "CIRCLE" => {
-                    let (_, circle) = circle_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, circle) =
+                        circle_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Cutout(ref mut cutout) => {
@@ -260,8 +274,8 @@ impl Board {
                     }
                 }
                 "RECTANGLE" => {
-                    let (_, rectangle) =
-                        rectangle_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, rectangle) = rectangle_ref(param.parameter)
+                        .map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Cutout(ref mut cutout) => {
@@ -281,7 +295,7 @@ impl Board {
                 }
                 "ATTRIBUTE" => {
                     let (_, attribute) =
-                        attrib_ref(param.parameter).map_err(|err| err.to_owned())?;
+                        attrib_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Cutout(ref mut cutout) => cutout.attributes.push(attribute),
@@ -298,47 +312,49 @@ impl Board {
 
                 // Artwork-only keywords
                 "TRACK" => {
-                    let (_, track) = track_name(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, track) =
+                        track_name(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Artwork(ref mut artwork) => {
                                 artwork.components.push(ArtworkComponent::Track(track))
                             }
-                            _ => return Err("TRACK is only supported in ARTWORK section!".into()),
+                            _ => return Err(param.unexpected(SECTION)),
                         }
                         parser_state = BoardParserState::Subsection(subsection);
                     } else {
-                        return Err("TRACK is only supported in ARTWORK section!".into());
+                        return Err(param.unexpected(SECTION));
                     }
                 }
                 "FILLED" => {
-                    let (_, filled) = filled_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, filled) =
+                        filled_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Artwork(ref mut artwork) => {
                                 artwork.components.push(ArtworkComponent::Filled(filled))
                             }
-                            _ => return Err("FILLED is only supported in ARTWORK section!".into()),
+                            _ => return Err(param.unexpected(SECTION)),
                         }
                         parser_state = BoardParserState::Subsection(subsection);
                     } else {
-                        return Err("FILLED is only supported in ARTWORK section!".into());
+                        return Err(param.unexpected(SECTION));
                     }
                 }
                 "TEXT" => {
                     let (_, (origin, text)) = (x_y_ref, preceded(spaces, text_par))
                         .parse(param.parameter)
-                        .map_err(|err| err.to_owned())?;
+                        .map_err(|err| param.bad_field(SECTION, err))?;
                     if let BoardParserState::Subsection(mut subsection) = parser_state {
                         match subsection {
                             Subsection::Artwork(ref mut artwork) => artwork
                                 .components
                                 .push(ArtworkComponent::Text(Text::new(origin, text))),
-                            _ => return Err("TEXT is only supported in ARTWORK section!".into()),
+                            _ => return Err(param.unexpected(SECTION)),
                         }
                         parser_state = BoardParserState::Subsection(subsection);
                     } else {
-                        return Err("TEXT is only supported in ARTWORK section!".into());
+                        return Err(param.unexpected(SECTION));
                     }
                 }
 
@@ -347,10 +363,10 @@ impl Board {
                     if let BoardParserState::Subsection(subsection) = parser_state {
                         subsections.push(subsection);
                     }
-                    let (_, cutout_name) = string(param.parameter).map_err(|err| err.to_owned())?;
-                    parser_state = BoardParserState::Subsection(Subsection::Cutout(Cutout::new(
-                        cutout_name.as_str(),
-                    )))
+                    let (_, cutout_name) =
+                        string(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    parser_state =
+                        BoardParserState::Subsection(Subsection::Cutout(Cutout::new(cutout_name)))
                 }
                 "MASK" => {
                     if let BoardParserState::Subsection(subsection) = parser_state {
@@ -358,10 +374,9 @@ impl Board {
                     }
                     let (_, (mask_name, mask_layer)) = (string, preceded(spaces, layer))
                         .parse(param.parameter)
-                        .map_err(|err| err.to_owned())?;
+                        .map_err(|err| param.bad_field(SECTION, err))?;
                     parser_state = BoardParserState::Subsection(Subsection::Mask(Mask::new(
-                        mask_name.as_str(),
-                        mask_layer,
+                        mask_name, mask_layer,
                     )))
                 }
                 "ARTWORK" => {
@@ -370,13 +385,12 @@ impl Board {
                     }
                     let (_, (mask_name, mask_layer)) = (string, preceded(spaces, layer))
                         .parse(param.parameter)
-                        .map_err(|err| err.to_owned())?;
+                        .map_err(|err| param.bad_field(SECTION, err))?;
                     parser_state = BoardParserState::Subsection(Subsection::Artwork(Artwork::new(
-                        mask_name.as_str(),
-                        mask_layer,
+                        mask_name, mask_layer,
                     )))
                 }
-                _ => {}
+                _ => warnings.push(param.ignored(SECTION, "unknown keyword")),
             }
         }
 
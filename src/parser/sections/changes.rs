@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD CHANGES section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::borrow::Cow;
+
+use nom::Parser;
+use nom::combinator::opt;
+use nom::sequence::preceded;
+
+use crate::error::{Error, FieldError};
+use crate::parser::types::util::spaces;
+use crate::parser::types::{attrib_ref, part_name, string};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::Attribute;
+
+const SECTION: &str = "CHANGES";
+
+/// A single engineering change order (ECO) applied to the design.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change<S = String> {
+    /// The identifier of the change (e.g., an ECO number or revision letter).
+    pub id: S,
+    /// A free-form description of the change. Empty if none was given.
+    pub description: S,
+    /// Additional metadata associated with the change.
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `CHANGE` statement, if the change was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> Change<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, (id, description)) = (string, opt(preceded(spaces, part_name))).parse(params)?;
+
+        Ok(Self {
+            id,
+            description: description.unwrap_or_else(|| S::from(Cow::Borrowed(""))),
+            attributes: Vec::new(),
+            span: Some(span),
+        })
+    }
+}
+
+/// Represents the optional `CHANGES` section of a GenCAD file, recording the design's revision history.
+///
+/// An `ATTRIBUTE` that follows a `CHANGE` belongs to that change. Attributes that come before the
+/// first `CHANGE` belong to the section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Changes<S = String> {
+    /// All changes in the section, in the order they appear in the file.
+    pub changes: Vec<Change<S>>,
+    /// Additional metadata associated with the `CHANGES` section.
+    pub attributes: Vec<Attribute<S>>,
+}
+
+impl<'a, S: InputStr<'a>> Changes<S> {
+    pub(crate) fn new(params: &[KeywordParam<'a>]) -> Result<Self, Error> {
+        let mut changes: Vec<Change<S>> = Vec::new();
+        let mut attributes = Vec::new();
+
+        for param in params {
+            match param.keyword {
+                "CHANGE" => changes.push(
+                    Change::from_parameters(param.parameter, param.span())
+                        .map_err(|err| param.bad_field(SECTION, err))?,
+                ),
+                "ATTRIBUTE" => {
+                    let (_, attribute) =
+                        attrib_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    match changes.last_mut() {
+                        Some(change) => change.attributes.push(attribute),
+                        None => attributes.push(attribute),
+                    }
+                }
+                _ => {
+                    return Err(param.unexpected(SECTION));
+                }
+            }
+        }
+
+        Ok(Self {
+            changes,
+            attributes,
+        })
+    }
+}
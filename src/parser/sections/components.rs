@@ -21,31 +21,33 @@
 use nom::Parser;
 use nom::sequence::preceded;
 
-use crate::parser::KeywordParam;
+use crate::diagnostic::Diagnostic;
+use crate::error::{Error, FieldError};
 use crate::parser::types::util::spaces;
 use crate::parser::types::{
     artwork_name, attrib_ref, component_name, fid_name, flip, layer, mirror, pad_name, part_name,
     rot, shape_name, string, text_par, x_y_ref,
 };
+use crate::parser::{InputStr, KeywordParam, Span};
 use crate::types::{Attribute, Layer, Mirror, Number, TextPar, XYRef};
 
+const SECTION: &str = "COMPONENTS";
+
 /// A shape definition used by a component to describe its geometry and orientation.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Shape {
+pub struct Shape<S = String> {
     /// The name of the shape as defined in the `SHAPES` section.
-    pub name: String,
+    pub name: S,
     /// The mirror state of the shape. Mirroring is applied before rotation.
     pub mirror: Mirror,
     /// The flip state of the shape. Flipping changes the layer of the shape and its features.
     pub flip: bool,
 }
 
-impl Shape {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Shape<S> {
+    pub(crate) fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
         let (_, (name, mirror, flip)) =
-            (shape_name, preceded(spaces, mirror), preceded(spaces, flip))
-                .parse(params)
-                .map_err(|err| err.to_owned())?;
+            (shape_name, preceded(spaces, mirror), preceded(spaces, flip)).parse(params)?;
 
         Ok(Self { name, mirror, flip })
     }
@@ -53,9 +55,9 @@ impl Shape {
 
 /// An artwork feature defined in the `ARTWORKS` section, placed relative to a component.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Artwork {
+pub struct Artwork<S = String> {
     /// The name of the artwork as defined in the `ARTWORKS` section.
-    pub name: String,
+    pub name: S,
     /// The position of the artwork's origin relative to the component origin.
     pub xy: XYRef,
     /// The rotation of the artwork around its origin, in degrees counterclockwise.
@@ -65,11 +67,11 @@ pub struct Artwork {
     /// The flip state of the artwork. Flipping changes the layer of the artwork and its features.
     pub flip: bool,
     /// Additional metadata associated with the artwork.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Artwork {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Artwork<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
         let (_, (name, xy, rotation, mirror, flip)) = (
             artwork_name,
             preceded(spaces, x_y_ref),
@@ -77,8 +79,7 @@ impl Artwork {
             preceded(spaces, mirror),
             preceded(spaces, flip),
         )
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+            .parse(params)?;
 
         let attributes = Vec::new();
 
@@ -95,11 +96,11 @@ impl Artwork {
 
 /// A fiducial marker used for alignment, referencing a pad or padstack.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Fid {
+pub struct Fid<S = String> {
     /// The name of the fiducial. Must be unique within the component.
-    pub name: String,
+    pub name: S,
     /// The name of the pad or padstack used for the fiducial, as defined in `PADS` or `PADSTACKS`.
-    pub pad_name: String,
+    pub pad_name: S,
     /// The position of the fiducial's center relative to the component origin.
     pub xy: XYRef,
     /// The layer on which the fiducial is placed, relative to the shape's layer.
@@ -111,11 +112,11 @@ pub struct Fid {
     /// The flip state of the fiducial. Flipping changes the layer of the fiducial and its features.
     pub flip: bool,
     /// Additional metadata associated with the fiducial.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Fid {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Fid<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
         let (_, (name, pad_name, xy, layer, rotation, mirror, flip)) = (
             fid_name,
             preceded(spaces, pad_name),
@@ -125,8 +126,7 @@ impl Fid {
             preceded(spaces, mirror),
             preceded(spaces, flip),
         )
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+            .parse(params)?;
 
         let attributes = Vec::new();
 
@@ -145,42 +145,40 @@ impl Fid {
 
 /// A text string associated with a component, such as its name or label.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Text {
+pub struct Text<S = String> {
     /// The bottom-left corner of the text relative to the component's origin.
     pub origin: XYRef,
     /// Specifies the text's size, rotation, mirror, layer, and bounding rectangle.
-    pub text: TextPar,
+    pub text: TextPar<S>,
 }
 
-impl Text {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, (origin, text)) = (x_y_ref, preceded(spaces, text_par))
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> Text<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, (origin, text)) = (x_y_ref, preceded(spaces, text_par)).parse(params)?;
 
         Ok(Self { origin, text })
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum SubComponent {
-    Artwork(Artwork),
-    Fid(Fid),
+pub enum SubComponent<S = String> {
+    Artwork(Artwork<S>),
+    Fid(Fid<S>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum ComponentParserState {
+enum ComponentParserState<S> {
     Component,
-    SubComponent(SubComponent),
+    SubComponent(SubComponent<S>),
 }
 
 /// A component placed on the board, referencing a device and shape definition.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Component {
+pub struct Component<S = String> {
     /// The name of the component. Must be unique per component.
-    pub name: String,
+    pub name: S,
     /// The name of the device that this component references, as defined in the `DEVICES` section.
-    pub device: String,
+    pub device: S,
     /// The origin of the component on the board, used as a reference for shape and pin positions.
     pub place: XYRef,
     /// The side of the board this component is placed on. Does not imply mirroring.
@@ -188,24 +186,28 @@ pub struct Component {
     /// The counterclockwise rotation of the component in degrees, relative to the shape definition.
     pub rotation: Number,
     /// The shape of the component, as defined in the `SHAPES` section.
-    pub shape: Shape,
+    pub shape: Shape<S>,
     /// A list of subcomponents such as artwork or fiducials associated with this component.
-    pub subcomponents: Vec<SubComponent>,
+    pub subcomponents: Vec<SubComponent<S>>,
     /// A list of text strings associated with this component.
-    pub texts: Vec<Text>,
+    pub texts: Vec<Text<S>>,
     /// The schematic sheet number, zone, or anything else that is a location property of the component.
-    pub sheet: Option<String>,
+    pub sheet: Option<S>,
     /// Miscellaneous information that is relevant to this component.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `COMPONENT` statement, if the component was parsed from a file.
+    pub span: Option<Span>,
 }
 
 /// A prototype for a component being parsed, used to build a fully constructed `Component`.
 #[derive(Debug, Clone, PartialEq)]
-struct ComponentPrototype {
+struct ComponentPrototype<S> {
+    /// The location of the `COMPONENT` statement that starts the component.
+    pub span: Span,
     /// The name of the component. Must be unique per component.
-    pub name: String,
+    pub name: S,
     /// The name of the device that this component references, as defined in the `DEVICES` section.
-    pub device: Option<String>,
+    pub device: Option<S>,
     /// The origin of the component on the board, used as a reference for shape and pin positions.
     pub place: Option<XYRef>,
     /// The side of the board this component is placed on. Does not imply mirroring.
@@ -213,25 +215,37 @@ struct ComponentPrototype {
     /// The counterclockwise rotation of the component in degrees, relative to the shape definition.
     pub rotation: Option<Number>,
     /// The shape of the component, as defined in the `SHAPES` section.
-    pub shape: Option<Shape>,
+    pub shape: Option<Shape<S>>,
     /// A list of subcomponents such as artwork or fiducials associated with this component.
-    pub subcomponents: Vec<SubComponent>,
+    pub subcomponents: Vec<SubComponent<S>>,
     /// A list of text strings associated with this component.
-    pub texts: Vec<Text>,
+    pub texts: Vec<Text<S>>,
     /// The schematic sheet number, zone, or anything else that is a location property of the component.
-    pub sheet: Option<String>,
+    pub sheet: Option<S>,
     /// Miscellaneous information that is relevant to this component.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl ComponentPrototype {
-    fn to_component(&self) -> Result<Component, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> ComponentPrototype<S> {
+    fn to_component(&self) -> Result<Component<S>, Error> {
         let name = self.name.clone();
-        let device = self.device.clone().ok_or("")?;
-        let place = self.place.ok_or("")?;
-        let layer = self.layer.ok_or("")?;
-        let rotation = self.rotation.ok_or("")?;
-        let shape = self.shape.clone().ok_or("")?;
+        let device = self
+            .device
+            .clone()
+            .ok_or_else(|| Error::missing(SECTION, "DEVICE", self.span.line))?;
+        let place = self
+            .place
+            .ok_or_else(|| Error::missing(SECTION, "PLACE", self.span.line))?;
+        let layer = self
+            .layer
+            .ok_or_else(|| Error::missing(SECTION, "LAYER", self.span.line))?;
+        let rotation = self
+            .rotation
+            .ok_or_else(|| Error::missing(SECTION, "ROTATION", self.span.line))?;
+        let shape = self
+            .shape
+            .clone()
+            .ok_or_else(|| Error::missing(SECTION, "SHAPE", self.span.line))?;
         let subcomponents = self.subcomponents.clone();
         let texts = self.texts.clone();
         let sheet = self.sheet.clone();
@@ -248,22 +262,23 @@ impl ComponentPrototype {
             texts,
             sheet,
             attributes,
+            span: Some(self.span),
         })
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct ComponentParser {
-    state: ComponentParserState,
-    prototype: ComponentPrototype,
+struct ComponentParser<S> {
+    state: ComponentParserState<S>,
+    prototype: ComponentPrototype<S>,
 }
 
-impl ComponentParser {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, name) = component_name(params).map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> ComponentParser<S> {
+    fn new(kp: &KeywordParam<'a>) -> Result<Self, Error> {
+        let (_, name) = component_name(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
 
         let state = ComponentParserState::Component;
-        let name = name.to_string();
+        let span = kp.span();
         let device = None;
         let place = None;
         let layer = None;
@@ -275,6 +290,7 @@ impl ComponentParser {
         let attributes = Vec::new();
 
         let shape = ComponentPrototype {
+            span,
             name,
             device,
             place,
@@ -293,82 +309,113 @@ impl ComponentParser {
         })
     }
 
-    fn ingest(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn ingest(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
         match &mut self.state {
             ComponentParserState::Component => match kp.keyword {
                 "DEVICE" => {
                     if self.prototype.device.is_none() {
-                        let (_, dev) = part_name(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, dev) =
+                            part_name(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.device = Some(dev);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     Ok(())
                 }
                 "PLACE" => {
                     if self.prototype.place.is_none() {
-                        let (_, place) = x_y_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, place) =
+                            x_y_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.place = Some(place);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     Ok(())
                 }
                 "LAYER" => {
                     if self.prototype.layer.is_none() {
-                        let (_, layer) = layer(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, layer) =
+                            layer(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.layer = Some(layer);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     Ok(())
                 }
                 "ROTATION" => {
                     if self.prototype.rotation.is_none() {
-                        let (_, rotation) = rot(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, rotation) =
+                            rot(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.rotation = Some(rotation);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     Ok(())
                 }
                 "SHAPE" => {
                     if self.prototype.shape.is_none() {
-                        self.prototype.shape = Some(Shape::from_parameters(kp.parameter)?);
+                        self.prototype.shape = Some(
+                            Shape::from_parameters(kp.parameter)
+                                .map_err(|err| kp.bad_field(SECTION, err))?,
+                        );
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     Ok(())
                 }
                 "TEXT" => {
-                    self.prototype
-                        .texts
-                        .push(Text::from_parameters(kp.parameter)?);
+                    self.prototype.texts.push(
+                        Text::from_parameters(kp.parameter)
+                            .map_err(|err| kp.bad_field(SECTION, err))?,
+                    );
                     Ok(())
                 }
                 "SHEET" => {
                     if self.prototype.sheet.is_none() {
-                        let (_, sheet) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, sheet) =
+                            string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.sheet = Some(sheet);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     Ok(())
                 }
 
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.prototype.attributes.push(attribute);
                     Ok(())
                 }
 
                 "ARTWORK" => {
-                    let artwork = Artwork::from_parameters(kp.parameter)?;
+                    let artwork = Artwork::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ComponentParserState::SubComponent(SubComponent::Artwork(artwork));
                     Ok(())
                 }
                 "FID" => {
-                    let fid = Fid::from_parameters(kp.parameter)?;
+                    let fid = Fid::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ComponentParserState::SubComponent(SubComponent::Fid(fid));
                     Ok(())
                 }
 
-                _ => Err(format!("Unexpected keyword in component: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             },
             ComponentParserState::SubComponent(subcomponent) => match kp.keyword {
                 "DEVICE" => {
                     self.done();
                     if self.prototype.device.is_none() {
-                        let (_, dev) = part_name(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, dev) =
+                            part_name(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.device = Some(dev);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     self.state = ComponentParserState::Component;
                     Ok(())
@@ -376,8 +423,11 @@ impl ComponentParser {
                 "PLACE" => {
                     self.done();
                     if self.prototype.place.is_none() {
-                        let (_, place) = x_y_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, place) =
+                            x_y_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.place = Some(place);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     self.state = ComponentParserState::Component;
                     Ok(())
@@ -385,8 +435,11 @@ impl ComponentParser {
                 "LAYER" => {
                     self.done();
                     if self.prototype.layer.is_none() {
-                        let (_, layer) = layer(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, layer) =
+                            layer(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.layer = Some(layer);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     self.state = ComponentParserState::Component;
                     Ok(())
@@ -394,8 +447,11 @@ impl ComponentParser {
                 "ROTATION" => {
                     self.done();
                     if self.prototype.rotation.is_none() {
-                        let (_, rotation) = rot(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, rotation) =
+                            rot(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.rotation = Some(rotation);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     self.state = ComponentParserState::Component;
                     Ok(())
@@ -403,31 +459,41 @@ impl ComponentParser {
                 "SHAPE" => {
                     self.done();
                     if self.prototype.shape.is_none() {
-                        self.prototype.shape = Some(Shape::from_parameters(kp.parameter)?);
+                        self.prototype.shape = Some(
+                            Shape::from_parameters(kp.parameter)
+                                .map_err(|err| kp.bad_field(SECTION, err))?,
+                        );
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     self.state = ComponentParserState::Component;
                     Ok(())
                 }
                 "TEXT" => {
                     self.done();
-                    self.prototype
-                        .texts
-                        .push(Text::from_parameters(kp.parameter)?);
+                    self.prototype.texts.push(
+                        Text::from_parameters(kp.parameter)
+                            .map_err(|err| kp.bad_field(SECTION, err))?,
+                    );
                     self.state = ComponentParserState::Component;
                     Ok(())
                 }
                 "SHEET" => {
                     self.done();
                     if self.prototype.sheet.is_none() {
-                        let (_, sheet) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                        let (_, sheet) =
+                            string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                         self.prototype.sheet = Some(sheet);
+                    } else {
+                        warnings.push(kp.duplicate(SECTION));
                     }
                     self.state = ComponentParserState::Component;
                     Ok(())
                 }
 
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     match subcomponent {
                         SubComponent::Artwork(a) => a.attributes.push(attribute),
                         SubComponent::Fid(f) => f.attributes.push(attribute),
@@ -437,18 +503,20 @@ impl ComponentParser {
 
                 "ARTWORK" => {
                     self.done();
-                    let artwork = Artwork::from_parameters(kp.parameter)?;
+                    let artwork = Artwork::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ComponentParserState::SubComponent(SubComponent::Artwork(artwork));
                     Ok(())
                 }
                 "FID" => {
                     self.done();
-                    let fid = Fid::from_parameters(kp.parameter)?;
+                    let fid = Fid::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ComponentParserState::SubComponent(SubComponent::Fid(fid));
                     Ok(())
                 }
 
-                _ => Err(format!("Unexpected keyword in subcomponent: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             },
         }
     }
@@ -465,48 +533,53 @@ impl ComponentParser {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum ComponentsParserState {
+#[allow(clippy::large_enum_variant)]
+enum ComponentsParserState<S> {
     Reset,
-    ComponentParser(ComponentParser),
+    ComponentParser(ComponentParser<S>),
 }
 
-struct ComponentsParser {
-    state: ComponentsParserState,
-    components: Vec<Component>,
+struct ComponentsParser<S> {
+    state: ComponentsParserState<S>,
+    components: Vec<Component<S>>,
 }
 
-impl ComponentsParser {
+impl<'a, S: InputStr<'a>> ComponentsParser<S> {
     fn new() -> Self {
         let state = ComponentsParserState::Reset;
         let components = Vec::new();
         Self { state, components }
     }
 
-    fn ingest(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn ingest(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
         if let ComponentsParserState::ComponentParser(ref mut parser) = self.state {
             match kp.keyword {
                 "COMPONENT" => {
                     parser.done();
                     self.components.push(parser.prototype.to_component()?);
-                    let parser = ComponentParser::from_parameters(kp.parameter)?;
+                    let parser = ComponentParser::new(kp)?;
                     self.state = ComponentsParserState::ComponentParser(parser);
                     Ok(())
                 }
-                _ => parser.ingest(kp),
+                _ => parser.ingest(kp, warnings),
             }
         } else {
             match kp.keyword {
                 "COMPONENT" => {
-                    let parser = ComponentParser::from_parameters(kp.parameter)?;
+                    let parser = ComponentParser::new(kp)?;
                     self.state = ComponentsParserState::ComponentParser(parser);
                     Ok(())
                 }
-                _ => Err(format!("Unexpected keyword in components: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             }
         }
     }
 
-    fn finalize(mut self) -> Result<Vec<Component>, Box<dyn std::error::Error>> {
+    fn finalize(mut self) -> Result<Vec<Component<S>>, Error> {
         if let ComponentsParserState::ComponentParser(mut parser) = self.state {
             parser.done();
             self.components.push(parser.prototype.to_component()?);
@@ -517,12 +590,13 @@ impl ComponentsParser {
 }
 
 /// Parse the `COMPONENTS` section of a GenCAD file.
-pub(crate) fn parse_components(
-    params: &[KeywordParam],
-) -> Result<Vec<Component>, Box<dyn std::error::Error>> {
+pub(crate) fn parse_components<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<Vec<Component<S>>, Error> {
     let mut sp = ComponentsParser::new();
     for param in params {
-        sp.ingest(param)?;
+        sp.ingest(param, warnings)?;
     }
     sp.finalize()
 }
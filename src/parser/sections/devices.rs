@@ -21,25 +21,27 @@
 use nom::Parser;
 use nom::sequence::preceded;
 
-use crate::parser::KeywordParam;
+use crate::diagnostic::Diagnostic;
+use crate::error::{Error, FieldError};
 use crate::parser::types::util::spaces;
 use crate::parser::types::{attrib_ref, p_integer, part_name, pin_name, string};
+use crate::parser::{InputStr, KeywordParam, Span};
 use crate::types::Attribute;
 
+const SECTION: &str = "DEVICES";
+
 /// A pin description for a device, typically used to describe the function or role of a pin.
 #[derive(Debug, Clone, PartialEq)]
-pub struct PinDesc {
+pub struct PinDesc<S = String> {
     /// The name of the pin as defined in the device's shape and used in signals.
-    pub pin_name: String,
+    pub pin_name: S,
     /// A textual description of the pin's purpose or function.
-    pub text: String,
+    pub text: S,
 }
 
-impl PinDesc {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, (pin_name, text)) = (pin_name, preceded(spaces, string))
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> PinDesc<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, (pin_name, text)) = (pin_name, preceded(spaces, string)).parse(params)?;
 
         Ok(Self { pin_name, text })
     }
@@ -47,18 +49,16 @@ impl PinDesc {
 
 /// A pin function for a device, typically used to describe the pin's role in tester output data.
 #[derive(Debug, Clone, PartialEq)]
-pub struct PinFunct {
+pub struct PinFunct<S = String> {
     /// The name of the pin as defined in the device's shape and used in signals.
-    pub pin_name: String,
+    pub pin_name: S,
     /// A textual description of the pin's functional behavior.
-    pub text: String,
+    pub text: S,
 }
 
-impl PinFunct {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, (pin_name, text)) = (pin_name, preceded(spaces, string))
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> PinFunct<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, (pin_name, text)) = (pin_name, preceded(spaces, string)).parse(params)?;
 
         Ok(Self { pin_name, text })
     }
@@ -66,42 +66,44 @@ impl PinFunct {
 
 /// A device used on the board. These descriptions are independent of board geometry and are used for cross-referencing components.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Device {
+pub struct Device<S = String> {
     /// The name of the device. Must be unique per device.
-    pub name: String,
+    pub name: S,
     /// An optional corporate part number or CAD part name. Does not need to be unique.
-    pub part: Option<String>,
+    pub part: Option<S>,
     /// An optional field to define the component type (e.g., "RES" for resistor).
-    pub dtype: Option<String>,
+    pub dtype: Option<S>,
     /// An optional field to enhance the `dtype` with additional information.
-    pub style: Option<String>,
+    pub style: Option<S>,
     /// An optional field to define the physical package type (e.g., "DIL_8", "TO99").
-    pub package: Option<String>,
+    pub package: Option<S>,
     /// A list of pin descriptions, typically used for CAD data.
-    pub pin_descriptions: Vec<PinDesc>,
+    pub pin_descriptions: Vec<PinDesc<S>>,
     /// A list of pin functions, typically used for tester output data.
-    pub pin_functions: Vec<PinFunct>,
+    pub pin_functions: Vec<PinFunct<S>>,
     /// An optional field to define the number of physical pins on the device.
     pub pincount: Option<u16>,
     /// An optional field to define the value of the device (e.g., resistance for resistors).
-    pub value: Option<String>,
+    pub value: Option<S>,
     /// An optional field to define a ± tolerance for the device.
-    pub tol: Option<String>,
+    pub tol: Option<S>,
     /// An optional field to define the negative (minimum) tolerance for the device.
-    pub ntol: Option<String>,
+    pub ntol: Option<S>,
     /// An optional field to define the positive (maximum) tolerance for the device.
-    pub ptol: Option<String>,
+    pub ptol: Option<S>,
     /// An optional field to define a voltage rating for the device.
-    pub volts: Option<String>,
+    pub volts: Option<S>,
     /// An optional free text field to describe the device.
-    pub desc: Option<String>,
+    pub desc: Option<S>,
     /// A list of additional attributes associated with the device.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `DEVICE` statement, if the device was parsed from a file.
+    pub span: Option<Span>,
 }
 
-impl Device {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, name) = part_name(params).map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> Device<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, name) = part_name(params)?;
 
         Ok(Self {
             name,
@@ -119,151 +121,201 @@ impl Device {
             volts: None,
             desc: None,
             attributes: Vec::new(),
+            span: Some(span),
         })
     }
 
-    fn update(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn update(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
         match kp.keyword {
             "PART" => {
                 if self.part.is_none() {
-                    let (_, dev) = part_name(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, dev) =
+                        part_name(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.part = Some(dev);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "TYPE" => {
                 if self.dtype.is_none() {
-                    let (_, dtype) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, dtype) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.dtype = Some(dtype);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "STYLE" => {
                 if self.style.is_none() {
-                    let (_, style) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, style) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.style = Some(style);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "PACKAGE" => {
                 if self.package.is_none() {
-                    let (_, package) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, package) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.package = Some(package);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "PINDESC" => {
-                self.pin_descriptions
-                    .push(PinDesc::from_parameters(kp.parameter)?);
+                self.pin_descriptions.push(
+                    PinDesc::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?,
+                );
                 Ok(())
             }
             "PINFUNCT" => {
-                self.pin_functions
-                    .push(PinFunct::from_parameters(kp.parameter)?);
+                self.pin_functions.push(
+                    PinFunct::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?,
+                );
                 Ok(())
             }
             "PINCOUNT" => {
                 if self.pincount.is_none() {
-                    let (_, pincount) = p_integer(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, pincount) =
+                        p_integer(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.pincount = Some(pincount);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "VALUE" => {
                 if self.value.is_none() {
-                    let (_, value) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, value) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.value = Some(value);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "TOL" => {
                 if self.tol.is_none() {
-                    let (_, tol) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, tol) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.tol = Some(tol);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "NTOL" => {
                 if self.ntol.is_none() {
-                    let (_, ntol) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, ntol) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.ntol = Some(ntol);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "PTOL" => {
                 if self.ptol.is_none() {
-                    let (_, ptol) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, ptol) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.ptol = Some(ptol);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "VOLTS" => {
                 if self.volts.is_none() {
-                    let (_, volts) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, volts) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.volts = Some(volts);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "DESC" => {
                 if self.desc.is_none() {
-                    let (_, desc) = string(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, desc) =
+                        string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.desc = Some(desc);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
                 }
                 Ok(())
             }
             "ATTRIBUTE" => {
-                let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                let (_, attribute) =
+                    attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                 self.attributes.push(attribute);
                 Ok(())
             }
 
-            _ => Err(format!("Unexpected keyword in component: {}", kp.keyword).into()),
+            _ => Err(kp.unexpected(SECTION)),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum DevicesParserState {
+#[allow(clippy::large_enum_variant)]
+enum DevicesParserState<S> {
     Reset,
-    Device(Device),
+    Device(Device<S>),
 }
 
-struct DevicesParser {
-    state: DevicesParserState,
-    devices: Vec<Device>,
+struct DevicesParser<S> {
+    state: DevicesParserState<S>,
+    devices: Vec<Device<S>>,
 }
 
-impl DevicesParser {
+impl<'a, S: InputStr<'a>> DevicesParser<S> {
     fn new() -> Self {
         let state = DevicesParserState::Reset;
         let devices = Vec::new();
         Self { state, devices }
     }
 
-    fn ingest(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn ingest(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
         if let DevicesParserState::Device(ref mut device) = self.state {
             match kp.keyword {
                 "DEVICE" => {
                     self.devices.push(device.clone());
-                    let device = Device::from_parameters(kp.parameter)?;
+                    let device = Device::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = DevicesParserState::Device(device);
                     Ok(())
                 }
-                _ => device.update(kp),
+                _ => device.update(kp, warnings),
             }
         } else {
             match kp.keyword {
                 "DEVICE" => {
-                    let device = Device::from_parameters(kp.parameter)?;
+                    let device = Device::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = DevicesParserState::Device(device);
                     Ok(())
                 }
-                _ => Err(format!("Unexpected keyword in devices: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             }
         }
     }
 
-    fn finalize(mut self) -> Result<Vec<Device>, Box<dyn std::error::Error>> {
+    fn finalize(mut self) -> Result<Vec<Device<S>>, Error> {
         if let DevicesParserState::Device(device) = self.state {
             self.devices.push(device.clone());
             self.state = DevicesParserState::Reset;
@@ -273,12 +325,13 @@ impl DevicesParser {
 }
 
 /// Parse the `DEVICES` section of a GenCAD file.
-pub(crate) fn parse_devices(
-    params: &[KeywordParam],
-) -> Result<Vec<Device>, Box<dyn std::error::Error>> {
+pub(crate) fn parse_devices<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<Vec<Device<S>>, Error> {
     let mut sp = DevicesParser::new();
     for param in params {
-        sp.ingest(param)?;
+        sp.ingest(param, warnings)?;
     }
     sp.finalize()
 }
@@ -18,21 +18,25 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::parser::KeywordParam;
+use crate::diagnostic::Diagnostic;
+use crate::error::Error;
 use crate::parser::types::{attrib_ref, dimension, number, string, x_y_ref};
+use crate::parser::{InputStr, KeywordParam};
 use crate::types::{Attribute, Dimension, Number, XYRef};
 
+const SECTION: &str = "HEADER";
+
 /// Represents the `HEADER` section of a GenCAD file.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Header {
+pub struct Header<S = String> {
     /// The GenCAD version.
     pub gencad_version: Number,
     /// The originator of the GenCAD file.
-    pub user: String,
+    pub user: S,
     /// The board drawing number or title.
-    pub drawing: String,
+    pub drawing: S,
     /// The revision, issue, or modification status of the board.
-    pub revision: String,
+    pub revision: S,
     /// The dimensional units used in the file.
     pub units: Dimension,
     /// The CAD coordinates of the origin of the board.
@@ -40,11 +44,14 @@ pub struct Header {
     /// The file change version.
     pub intertrack: Number,
     /// A list of attributes associated with the header.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Header {
-    pub(crate) fn new(params: &[KeywordParam]) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Header<S> {
+    pub(crate) fn new(
+        params: &[KeywordParam<'a>],
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<Self, Error> {
         let mut gencad_version = None;
         let mut user = None;
         let mut drawing = None;
@@ -56,64 +63,62 @@ impl Header {
 
         for param in params {
             match param.keyword {
-                "GENCAD" => {
-                    if gencad_version.is_none() {
-                        let (_, value) = number(param.parameter).map_err(|err| err.to_owned())?;
-                        gencad_version = Some(value);
-                    }
+                "GENCAD" if gencad_version.is_none() => {
+                    let (_, value) =
+                        number(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    gencad_version = Some(value);
                 }
-                "USER" => {
-                    if user.is_none() {
-                        let (_, value) = string(param.parameter).map_err(|err| err.to_owned())?;
-                        user = Some(value);
-                    }
+                "USER" if user.is_none() => {
+                    let (_, value) =
+                        string(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    user = Some(value);
                 }
-                "DRAWING" => {
-                    if drawing.is_none() {
-                        let (_, value) = string(param.parameter).map_err(|err| err.to_owned())?;
-                        drawing = Some(value);
-                    }
+                "DRAWING" if drawing.is_none() => {
+                    let (_, value) =
+                        string(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    drawing = Some(value);
                 }
-                "REVISION" => {
-                    if revision.is_none() {
-                        let (_, value) = string(param.parameter).map_err(|err| err.to_owned())?;
-                        revision = Some(value);
-                    }
+                "REVISION" if revision.is_none() => {
+                    let (_, value) =
+                        string(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    revision = Some(value);
                 }
-                "UNITS" => {
-                    if units.is_none() {
-                        let (_, value) =
-                            dimension(param.parameter).map_err(|err| err.to_owned())?;
-                        units = Some(value);
-                    }
+                "UNITS" if units.is_none() => {
+                    let (_, value) =
+                        dimension(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    units = Some(value);
                 }
-                "ORIGIN" => {
-                    if origin.is_none() {
-                        let (_, value) = x_y_ref(param.parameter).map_err(|err| err.to_owned())?;
-                        origin = Some(value);
-                    }
+                "ORIGIN" if origin.is_none() => {
+                    let (_, value) =
+                        x_y_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    origin = Some(value);
                 }
-                "INTERTRACK" => {
-                    if intertrack.is_none() {
-                        let (_, value) = number(param.parameter).map_err(|err| err.to_owned())?;
-                        intertrack = Some(value);
-                    }
+                "INTERTRACK" if intertrack.is_none() => {
+                    let (_, value) =
+                        number(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    intertrack = Some(value);
                 }
                 "ATTRIBUTE" => {
-                    let (_, value) = attrib_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, value) =
+                        attrib_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     attributes.push(value);
                 }
-                _ => {}
+                "GENCAD" | "USER" | "DRAWING" | "REVISION" | "UNITS" | "ORIGIN" | "INTERTRACK" => {
+                    warnings.push(param.duplicate(SECTION));
+                }
+                _ => warnings.push(param.ignored(SECTION, "unknown keyword")),
             }
         }
 
-        let gencad_version = gencad_version.ok_or("missing GENCAD")?;
-        let user = user.ok_or("missing USER")?;
-        let drawing = drawing.ok_or("missing DRAWING")?;
-        let revision = revision.ok_or("missing REVISION")?;
-        let units = units.ok_or("missing UNITS")?;
-        let origin = origin.ok_or("missing ORIGIN")?;
-        let intertrack = intertrack.ok_or("missing INTERTRACK")?;
+        let gencad_version =
+            gencad_version.ok_or_else(|| Error::missing_in_section(SECTION, "GENCAD"))?;
+        let user = user.ok_or_else(|| Error::missing_in_section(SECTION, "USER"))?;
+        let drawing = drawing.ok_or_else(|| Error::missing_in_section(SECTION, "DRAWING"))?;
+        let revision = revision.ok_or_else(|| Error::missing_in_section(SECTION, "REVISION"))?;
+        let units = units.ok_or_else(|| Error::missing_in_section(SECTION, "UNITS"))?;
+        let origin = origin.ok_or_else(|| Error::missing_in_section(SECTION, "ORIGIN"))?;
+        let intertrack =
+            intertrack.ok_or_else(|| Error::missing_in_section(SECTION, "INTERTRACK"))?;
 
         Ok(Self {
             gencad_version,
@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD LAYERS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use nom::Parser;
+use nom::sequence::preceded;
+
+use crate::error::{Error, FieldError};
+use crate::parser::types::util::spaces;
+use crate::parser::types::{attrib_ref, layer, string};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::{Attribute, Layer};
+
+const SECTION: &str = "LAYERS";
+
+/// A description of a single board layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerDef<S = String> {
+    /// The layer being described, usually a [Layer::LayerX].
+    pub layer: Layer,
+    /// A descriptive name for the layer (e.g., "Top copper", "Drill drawing").
+    pub description: S,
+    /// Additional metadata associated with the layer.
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `LAYER` statement, if the layer was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> LayerDef<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, (layer, description)) = (layer, preceded(spaces, string)).parse(params)?;
+
+        Ok(Self {
+            layer,
+            description,
+            attributes: Vec::new(),
+            span: Some(span),
+        })
+    }
+}
+
+/// A named group of layers that can be referenced as a single [Layer::LayersetX].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layerset<S = String> {
+    /// The layerset being defined. Always a [Layer::LayersetX].
+    pub layerset: Layer,
+    /// The layers that are members of this layerset.
+    pub layers: Vec<Layer>,
+    /// Additional metadata associated with the layerset.
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `LAYERSET` statement, if the layerset was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> Layerset<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, layerset) = layer(params)?;
+
+        if !matches!(layerset, Layer::LayersetX(_)) {
+            return Err(FieldError::at(params));
+        }
+
+        Ok(Self {
+            layerset,
+            layers: Vec::new(),
+            attributes: Vec::new(),
+            span: Some(span),
+        })
+    }
+
+    fn update(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
+        match kp.keyword {
+            "LAYER" => {
+                let (_, layer) = layer(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.layers.push(layer);
+                Ok(())
+            }
+            "ATTRIBUTE" => {
+                let (_, attribute) =
+                    attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.attributes.push(attribute);
+                Ok(())
+            }
+
+            _ => Err(kp.unexpected(SECTION)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum LayersParserState<S> {
+    Reset,
+    Layer(LayerDef<S>),
+    Layerset(Layerset<S>),
+}
+
+struct LayersParser<S> {
+    state: LayersParserState<S>,
+    layers: Vec<LayerDef<S>>,
+    layersets: Vec<Layerset<S>>,
+    attributes: Vec<Attribute<S>>,
+}
+
+impl<'a, S: InputStr<'a>> LayersParser<S> {
+    fn new() -> Self {
+        let state = LayersParserState::Reset;
+        let layers = Vec::new();
+        let layersets = Vec::new();
+        let attributes = Vec::new();
+        Self {
+            state,
+            layers,
+            layersets,
+            attributes,
+        }
+    }
+
+    fn done(&mut self) {
+        match std::mem::replace(&mut self.state, LayersParserState::Reset) {
+            LayersParserState::Reset => (),
+            LayersParserState::Layer(layer) => self.layers.push(layer),
+            LayersParserState::Layerset(layerset) => self.layersets.push(layerset),
+        }
+    }
+
+    fn ingest(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
+        match &mut self.state {
+            LayersParserState::Layerset(layerset) => match kp.keyword {
+                "LAYERSET" => {
+                    self.done();
+                    let layerset = Layerset::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = LayersParserState::Layerset(layerset);
+                    Ok(())
+                }
+                _ => layerset.update(kp),
+            },
+            _ => match kp.keyword {
+                "LAYER" => {
+                    self.done();
+                    let layer = LayerDef::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = LayersParserState::Layer(layer);
+                    Ok(())
+                }
+                "LAYERSET" => {
+                    self.done();
+                    let layerset = Layerset::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = LayersParserState::Layerset(layerset);
+                    Ok(())
+                }
+                "ATTRIBUTE" => {
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    match &mut self.state {
+                        LayersParserState::Layer(layer) => layer.attributes.push(attribute),
+                        _ => self.attributes.push(attribute),
+                    }
+                    Ok(())
+                }
+                _ => Err(kp.unexpected(SECTION)),
+            },
+        }
+    }
+
+    fn finalize(mut self) -> Result<Layers<S>, Error> {
+        self.done();
+        Ok(Layers {
+            layers: self.layers,
+            layersets: self.layersets,
+            attributes: self.attributes,
+        })
+    }
+}
+
+/// Represents the optional `LAYERS` section of a GenCAD file, describing user layers and layersets.
+///
+/// Inside a `LAYERSET`, every `LAYER` statement adds a member to that layerset, so layer
+/// descriptions must come before the first `LAYERSET`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layers<S = String> {
+    /// Descriptions of individual layers.
+    pub layers: Vec<LayerDef<S>>,
+    /// Definitions of layersets and their member layers.
+    pub layersets: Vec<Layerset<S>>,
+    /// Additional metadata associated with the `LAYERS` section.
+    pub attributes: Vec<Attribute<S>>,
+}
+
+impl<'a, S: InputStr<'a>> Layers<S> {
+    pub(crate) fn new(params: &[KeywordParam<'a>]) -> Result<Self, Error> {
+        let mut p = LayersParser::new();
+        for param in params {
+            p.ingest(param)?;
+        }
+        p.finalize()
+    }
+}
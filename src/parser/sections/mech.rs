@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD MECH section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use nom::Parser;
+use nom::sequence::preceded;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::{Error, FieldError};
+use crate::parser::sections::components::Shape;
+use crate::parser::types::util::spaces;
+use crate::parser::types::{attrib_ref, drill_size, layer, rot, string, x_y_ref};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::{Attribute, Layer, Number, XYRef};
+
+const SECTION: &str = "MECH";
+
+/// A drilled hole belonging to a mechanical part, such as a mounting or tooling hole.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hole {
+    /// The position of the hole's center relative to the mechanical part's origin.
+    pub xy: XYRef,
+    /// The drill hole size in [crate::types::Dimension] units.
+    pub drill_size: Number,
+}
+
+impl Hole {
+    fn from_parameters(params: &str) -> Result<Self, FieldError> {
+        let (_, (xy, drill_size)) = (x_y_ref, preceded(spaces, drill_size)).parse(params)?;
+
+        Ok(Self { xy, drill_size })
+    }
+}
+
+/// A non-electrical part placed on the board, such as a heatsink, bracket, or mounting hole.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mechanical<S = String> {
+    /// The name of the mechanical part. Must be unique per mechanical part.
+    pub name: S,
+    /// The origin of the mechanical part on the board, used as a reference for its shape and holes.
+    pub place: XYRef,
+    /// The side of the board this mechanical part is placed on. Does not imply mirroring.
+    pub layer: Layer,
+    /// The counterclockwise rotation of the mechanical part in degrees, relative to the shape definition.
+    pub rotation: Number,
+    /// The shape of the mechanical part, as defined in the `SHAPES` section, if it has one.
+    pub shape: Option<Shape<S>>,
+    /// The holes drilled for this mechanical part.
+    pub holes: Vec<Hole>,
+    /// Miscellaneous information that is relevant to this mechanical part.
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `MECHANICAL` statement, if the mechanical part was parsed from a file.
+    pub span: Option<Span>,
+}
+
+/// A prototype for a mechanical part being parsed, used to build a fully constructed `Mechanical`.
+#[derive(Debug, Clone, PartialEq)]
+struct MechanicalPrototype<S> {
+    /// The location of the `MECHANICAL` statement that starts the mechanical part.
+    pub span: Span,
+    /// The name of the mechanical part. Must be unique per mechanical part.
+    pub name: S,
+    /// The origin of the mechanical part on the board, used as a reference for its shape and holes.
+    pub place: Option<XYRef>,
+    /// The side of the board this mechanical part is placed on. Does not imply mirroring.
+    pub layer: Option<Layer>,
+    /// The counterclockwise rotation of the mechanical part in degrees, relative to the shape definition.
+    pub rotation: Option<Number>,
+    /// The shape of the mechanical part, as defined in the `SHAPES` section, if it has one.
+    pub shape: Option<Shape<S>>,
+    /// The holes drilled for this mechanical part.
+    pub holes: Vec<Hole>,
+    /// Miscellaneous information that is relevant to this mechanical part.
+    pub attributes: Vec<Attribute<S>>,
+}
+
+impl<'a, S: InputStr<'a>> MechanicalPrototype<S> {
+    fn new(kp: &KeywordParam<'a>) -> Result<Self, Error> {
+        let (_, name) = string(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+
+        Ok(Self {
+            span: kp.span(),
+            name,
+            place: None,
+            layer: None,
+            rotation: None,
+            shape: None,
+            holes: Vec::new(),
+            attributes: Vec::new(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
+        match kp.keyword {
+            "PLACE" => {
+                if self.place.is_none() {
+                    let (_, place) =
+                        x_y_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.place = Some(place);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
+                }
+                Ok(())
+            }
+            "LAYER" => {
+                if self.layer.is_none() {
+                    let (_, layer) =
+                        layer(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.layer = Some(layer);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
+                }
+                Ok(())
+            }
+            "ROTATION" => {
+                if self.rotation.is_none() {
+                    let (_, rotation) =
+                        rot(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.rotation = Some(rotation);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
+                }
+                Ok(())
+            }
+            "SHAPE" => {
+                if self.shape.is_none() {
+                    self.shape = Some(
+                        Shape::from_parameters(kp.parameter)
+                            .map_err(|err| kp.bad_field(SECTION, err))?,
+                    );
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
+                }
+                Ok(())
+            }
+            "HOLE" => {
+                self.holes.push(
+                    Hole::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?,
+                );
+                Ok(())
+            }
+            "ATTRIBUTE" => {
+                let (_, attribute) =
+                    attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.attributes.push(attribute);
+                Ok(())
+            }
+
+            _ => Err(kp.unexpected(SECTION)),
+        }
+    }
+
+    fn to_mechanical(&self) -> Result<Mechanical<S>, Error> {
+        let name = self.name.clone();
+        let place = self
+            .place
+            .ok_or_else(|| Error::missing(SECTION, "PLACE", self.span.line))?;
+        let layer = self
+            .layer
+            .ok_or_else(|| Error::missing(SECTION, "LAYER", self.span.line))?;
+        let rotation = self
+            .rotation
+            .ok_or_else(|| Error::missing(SECTION, "ROTATION", self.span.line))?;
+        let shape = self.shape.clone();
+        let holes = self.holes.clone();
+        let attributes = self.attributes.clone();
+
+        Ok(Mechanical {
+            name,
+            place,
+            layer,
+            rotation,
+            shape,
+            holes,
+            attributes,
+            span: Some(self.span),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MechParserState<S> {
+    Reset,
+    Mechanical(MechanicalPrototype<S>),
+}
+
+struct MechParser<S> {
+    state: MechParserState<S>,
+    mechanicals: Vec<Mechanical<S>>,
+}
+
+impl<'a, S: InputStr<'a>> MechParser<S> {
+    fn new() -> Self {
+        let state = MechParserState::Reset;
+        let mechanicals = Vec::new();
+        Self { state, mechanicals }
+    }
+
+    fn ingest(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
+        if let MechParserState::Mechanical(ref mut prototype) = self.state {
+            match kp.keyword {
+                "MECHANICAL" => {
+                    self.mechanicals.push(prototype.to_mechanical()?);
+                    let prototype = MechanicalPrototype::new(kp)?;
+                    self.state = MechParserState::Mechanical(prototype);
+                    Ok(())
+                }
+                _ => prototype.update(kp, warnings),
+            }
+        } else {
+            match kp.keyword {
+                "MECHANICAL" => {
+                    let prototype = MechanicalPrototype::new(kp)?;
+                    self.state = MechParserState::Mechanical(prototype);
+                    Ok(())
+                }
+                _ => Err(kp.unexpected(SECTION)),
+            }
+        }
+    }
+
+    fn finalize(mut self) -> Result<Vec<Mechanical<S>>, Error> {
+        if let MechParserState::Mechanical(prototype) = self.state {
+            self.mechanicals.push(prototype.to_mechanical()?);
+            self.state = MechParserState::Reset;
+        }
+        Ok(self.mechanicals)
+    }
+}
+
+/// Parse the `MECH` section of a GenCAD file.
+pub(crate) fn parse_mech<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<Vec<Mechanical<S>>, Error> {
+    let mut mp = MechParser::new();
+    for param in params {
+        mp.ingest(param, warnings)?;
+    }
+    mp.finalize()
+}
@@ -18,14 +18,22 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod artworks;
 pub mod board;
+pub mod changes;
 pub mod components;
 pub mod devices;
 pub mod header;
+pub mod layers;
+pub mod mech;
 pub mod pads;
 pub mod padstacks;
+pub mod pseudos;
+pub mod routes;
 pub mod shapes;
 pub mod signals;
+pub mod testpins;
+pub mod tracks;
 pub mod unknown;
 
 #[cfg(test)]
@@ -21,13 +21,17 @@
 use nom::Parser;
 use nom::sequence::preceded;
 
-use crate::parser::KeywordParam;
+use crate::diagnostic::Diagnostic;
+use crate::error::Error;
 use crate::parser::types::{
     arc_ref, attrib_ref, circle_ref, drill_size, line_ref, pad_name, pad_type, rectangle_ref,
     util::spaces,
 };
+use crate::parser::{InputStr, KeywordParam, Span};
 use crate::types::{ArcRef, Attribute, CircleRef, LineRef, Number, PadType, RectangleRef};
 
+const SECTION: &str = "PADS";
+
 /// A geometric shape that is used to define the outer edge of a pad. All coordinates are relative to the pad's origin.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PadShape {
@@ -43,11 +47,11 @@ pub enum PadShape {
 
 /// A pad on the circuit board. Pads define the physical shape and drill hole of contact points on the board.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Pad {
+pub struct Pad<S = String> {
     /// The name of the pad. Must be unique per pad and used consistently
     /// throughout the file. If the CAD system does not assign names, sequential
     /// names like "pad1", "pad2", etc., must be used.
-    pub name: String,
+    pub name: S,
     /// The type of the pad.
     pub ptype: PadType,
     /// The drill hole size in [crate::types::Dimension] units. A value of `0.0`
@@ -57,12 +61,13 @@ pub struct Pad {
     /// coordinates are relative to the pad's origin.
     pub shapes: Vec<PadShape>,
     /// Optional metadata associated with the pad.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `PAD` statement, if the pad was parsed from a file.
+    pub span: Option<Span>,
 }
 
-impl Pad {
-    fn new(name: &str, ptype: PadType, drill_size: Number) -> Self {
-        let name = name.to_string();
+impl<'a, S: InputStr<'a>> Pad<S> {
+    fn new(name: S, ptype: PadType, drill_size: Number, span: Span) -> Self {
         let shapes = Vec::new();
         let attributes = Vec::new();
         Self {
@@ -71,18 +76,22 @@ impl Pad {
             drill_size,
             shapes,
             attributes,
+            span: Some(span),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum ParserState {
+enum ParserState<S> {
     Init,
-    Pad(Pad),
+    Pad(Pad<S>),
 }
 
 /// Parse the `PADS` section of a GenCAD file.
-pub(crate) fn parse_pads(params: &[KeywordParam]) -> Result<Vec<Pad>, Box<dyn std::error::Error>> {
+pub(crate) fn parse_pads<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<Vec<Pad<S>>, Error> {
     let mut pads = Vec::new();
 
     let mut parser_state = ParserState::Init;
@@ -91,39 +100,52 @@ pub(crate) fn parse_pads(params: &[KeywordParam]) -> Result<Vec<Pad>, Box<dyn st
         match param.keyword {
             "LINE" => {
                 if let ParserState::Pad(mut pad) = parser_state {
-                    let (_, line) = line_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, line) =
+                        line_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     pad.shapes.push(PadShape::Line(line));
                     parser_state = ParserState::Pad(pad);
+                } else {
+                    warnings.push(param.ignored(SECTION, "not inside a PAD"));
                 }
             }
             "ARC" => {
                 if let ParserState::Pad(mut pad) = parser_state {
-                    let (_, arc) = arc_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, arc) =
+                        arc_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     pad.shapes.push(PadShape::Arc(arc));
                     parser_state = ParserState::Pad(pad);
+                } else {
+                    warnings.push(param.ignored(SECTION, "not inside a PAD"));
                 }
             }
             "CIRCLE" => {
                 if let ParserState::Pad(mut pad) = parser_state {
-                    let (_, circle) = circle_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, circle) =
+                        circle_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     pad.shapes.push(PadShape::Circle(circle));
                     parser_state = ParserState::Pad(pad);
+                } else {
+                    warnings.push(param.ignored(SECTION, "not inside a PAD"));
                 }
             }
             "RECTANGLE" => {
                 if let ParserState::Pad(mut pad) = parser_state {
-                    let (_, rectangle) =
-                        rectangle_ref(param.parameter).map_err(|err| err.to_owned())?;
+                    let (_, rectangle) = rectangle_ref(param.parameter)
+                        .map_err(|err| param.bad_field(SECTION, err))?;
                     pad.shapes.push(PadShape::Rectangle(rectangle));
                     parser_state = ParserState::Pad(pad);
+                } else {
+                    warnings.push(param.ignored(SECTION, "not inside a PAD"));
                 }
             }
             "ATTRIBUTE" => {
                 if let ParserState::Pad(mut pad) = parser_state {
                     let (_, attribute) =
-                        attrib_ref(param.parameter).map_err(|err| err.to_owned())?;
+                        attrib_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
                     pad.attributes.push(attribute);
                     parser_state = ParserState::Pad(pad);
+                } else {
+                    warnings.push(param.ignored(SECTION, "not inside a PAD"));
                 }
             }
 
@@ -138,10 +160,10 @@ pub(crate) fn parse_pads(params: &[KeywordParam]) -> Result<Vec<Pad>, Box<dyn st
                     preceded(spaces, drill_size),
                 )
                     .parse(param.parameter)
-                    .map_err(|err| err.to_owned())?;
-                parser_state = ParserState::Pad(Pad::new(name.as_str(), ptype, drill_size))
+                    .map_err(|err| param.bad_field(SECTION, err))?;
+                parser_state = ParserState::Pad(Pad::new(name, ptype, drill_size, param.span()))
             }
-            _ => {}
+            _ => warnings.push(param.ignored(SECTION, "unknown keyword")),
         }
     }
 
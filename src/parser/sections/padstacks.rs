@@ -21,16 +21,19 @@
 use nom::Parser;
 use nom::sequence::preceded;
 
-use crate::parser::KeywordParam;
+use crate::error::{Error, FieldError};
 use crate::parser::types::util::spaces;
 use crate::parser::types::{attrib_ref, drill_size, layer, mirror, pad_name, rot};
+use crate::parser::{InputStr, KeywordParam, Span};
 use crate::types::{Attribute, Layer, Mirror, Number};
 
+const SECTION: &str = "PADSTACKS";
+
 /// A single pad within a [Padstack]. All pads in a stack share the same origin.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Pad {
+pub struct Pad<S = String> {
     /// The name of the pad, referencing a definition in the `PADS` section.
-    pub name: String,
+    pub name: S,
     /// The board layer on which the pad is placed (e.g., [Layer::Top], [Layer::Inner]).
     /// Mirroring the pad stack will invert this layer.
     pub layer: Layer,
@@ -41,16 +44,15 @@ pub struct Pad {
     pub mirror: Mirror,
 }
 
-impl Pad {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Pad<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
         let (_, (name, layer, rotation, mirror)) = (
             pad_name,
             preceded(spaces, layer),
             preceded(spaces, rot),
             preceded(spaces, mirror),
         )
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+            .parse(params)?;
 
         Ok(Self {
             name,
@@ -64,57 +66,60 @@ impl Pad {
 /// A collection of pads arranged to form a single logical pad stack.
 /// Used to define complex pad arrangements for components.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Padstack {
+pub struct Padstack<S = String> {
     /// A unique name for this pad stack. Must not conflict with any pad name in the `PADS` section.
     /// If undefined, sequential names like "padstack1", "padstack2", etc., should be used.
-    pub name: String,
+    pub name: S,
     /// The drill hole size in [crate::types::Dimension] units for the entire stack.
     /// - `0.0` means no hole.
     /// - `-1.0` means undefined (use individual pad definitions).
     /// - `-2.0` means use the drill size of the first pad in the stack.
     pub drill_size: Number,
     /// The list of pads in this stack. All pads share the same origin.
-    pub pads: Vec<Pad>,
+    pub pads: Vec<Pad<S>>,
+    /// The location of the `PADSTACK` statement, if the padstack was parsed from a file.
+    pub span: Option<Span>,
 }
 
-impl Padstack {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, (name, drill_size)) = (pad_name, preceded(spaces, drill_size))
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> Padstack<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, (name, drill_size)) = (pad_name, preceded(spaces, drill_size)).parse(params)?;
 
         Ok(Self {
             name,
             drill_size,
             pads: Vec::new(),
+            span: Some(span),
         })
     }
 
-    fn update(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn update(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
         match kp.keyword {
             "PAD" => {
-                self.pads.push(Pad::from_parameters(kp.parameter)?);
+                self.pads.push(
+                    Pad::from_parameters(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?,
+                );
                 Ok(())
             }
 
-            _ => Err(format!("Unexpected keyword in padstack: {}", kp.keyword).into()),
+            _ => Err(kp.unexpected(SECTION)),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum PadstacksParserState {
+enum PadstacksParserState<S> {
     Reset,
-    Device(Padstack),
+    Device(Padstack<S>),
 }
 
-struct PadstacksParser {
-    state: PadstacksParserState,
-    padstacks: Vec<Padstack>,
-    attributes: Vec<Attribute>,
+struct PadstacksParser<S> {
+    state: PadstacksParserState<S>,
+    padstacks: Vec<Padstack<S>>,
+    attributes: Vec<Attribute<S>>,
 }
 
-impl PadstacksParser {
+impl<'a, S: InputStr<'a>> PadstacksParser<S> {
     fn new() -> Self {
         let state = PadstacksParserState::Reset;
         let padstacks = Vec::new();
@@ -126,17 +131,19 @@ impl PadstacksParser {
         }
     }
 
-    fn ingest(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn ingest(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
         if let PadstacksParserState::Device(ref mut device) = self.state {
             match kp.keyword {
                 "PADSTACK" => {
                     self.padstacks.push(device.clone());
-                    let device = Padstack::from_parameters(kp.parameter)?;
+                    let device = Padstack::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = PadstacksParserState::Device(device);
                     Ok(())
                 }
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.attributes.push(attribute);
                     Ok(())
                 }
@@ -145,21 +152,23 @@ impl PadstacksParser {
         } else {
             match kp.keyword {
                 "PADSTACK" => {
-                    let device = Padstack::from_parameters(kp.parameter)?;
+                    let device = Padstack::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = PadstacksParserState::Device(device);
                     Ok(())
                 }
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.attributes.push(attribute);
                     Ok(())
                 }
-                _ => Err(format!("Unexpected keyword in padstacks: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             }
         }
     }
 
-    fn finalize(mut self) -> Result<Padstacks, Box<dyn std::error::Error>> {
+    fn finalize(mut self) -> Result<Padstacks<S>, Error> {
         if let PadstacksParserState::Device(device) = self.state {
             self.padstacks.push(device.clone());
             self.state = PadstacksParserState::Reset;
@@ -174,15 +183,15 @@ impl PadstacksParser {
 /// Represents the optional `PADSTACKS` section of a GenCAD file.
 /// Defines how multiple pads can be grouped into a single logical pad stack.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Padstacks {
+pub struct Padstacks<S = String> {
     /// All defined pad stacks in the section.
-    pub padstacks: Vec<Padstack>,
+    pub padstacks: Vec<Padstack<S>>,
     /// Additional metadata associated with the `PADSTACKS` section.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Padstacks {
-    pub(crate) fn new(params: &[KeywordParam]) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Padstacks<S> {
+    pub(crate) fn new(params: &[KeywordParam<'a>]) -> Result<Self, Error> {
         let mut p = PadstacksParser::new();
         for param in params {
             p.ingest(param)?;
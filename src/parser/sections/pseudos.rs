@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD PSEUDOS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use nom::Parser;
+use nom::sequence::preceded;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::{Error, FieldError};
+use crate::parser::types::util::spaces;
+use crate::parser::types::{attrib_ref, component_name, part_name, pin_name, string};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::Attribute;
+
+const SECTION: &str = "PSEUDOS";
+
+/// Maps a pin of a pseudo-component to a pin of one of its member components.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinMap<S = String> {
+    /// The name of the pin on the pseudo-component.
+    pub pseudo_pin: S,
+    /// The name of the pin on the member component, as defined in the `SHAPES` section.
+    pub pin_name: S,
+}
+
+impl<'a, S: InputStr<'a>> PinMap<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, (pseudo_pin, pin_name)) = (pin_name, preceded(spaces, pin_name)).parse(params)?;
+
+        Ok(Self {
+            pseudo_pin,
+            pin_name,
+        })
+    }
+}
+
+/// A physical component that is part of a pseudo-component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member<S = String> {
+    /// The name of the component as defined in the `COMPONENTS` section.
+    pub component_name: S,
+    /// The mapping of pseudo-component pins to this component's pins.
+    pub pins: Vec<PinMap<S>>,
+}
+
+impl<'a, S: InputStr<'a>> Member<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, component_name) = component_name(params)?;
+
+        Ok(Self {
+            component_name,
+            pins: Vec::new(),
+        })
+    }
+}
+
+/// A logical device built from several physical components (e.g., a resistor network made of
+/// discrete resistors).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pseudo<S = String> {
+    /// The name of the pseudo-component. Must be unique per pseudo-component.
+    pub name: S,
+    /// The name of the device that this pseudo-component references, as defined in the `DEVICES` section.
+    pub device: Option<S>,
+    /// The physical components that make up this pseudo-component.
+    pub members: Vec<Member<S>>,
+    /// Additional metadata associated with the pseudo-component.
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `PSEUDO` statement, if the pseudo was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> Pseudo<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, name) = string(params)?;
+
+        Ok(Self {
+            name,
+            device: None,
+            members: Vec::new(),
+            attributes: Vec::new(),
+            span: Some(span),
+        })
+    }
+
+    fn update(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
+        match kp.keyword {
+            "DEVICE" => {
+                if self.device.is_none() {
+                    let (_, dev) =
+                        part_name(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.device = Some(dev);
+                } else {
+                    warnings.push(kp.duplicate(SECTION));
+                }
+                Ok(())
+            }
+            "COMPONENT" => {
+                self.members.push(
+                    Member::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?,
+                );
+                Ok(())
+            }
+            "PIN" => match self.members.last_mut() {
+                Some(member) => {
+                    member.pins.push(
+                        PinMap::from_parameters(kp.parameter)
+                            .map_err(|err| kp.bad_field(SECTION, err))?,
+                    );
+                    Ok(())
+                }
+                None => Err(kp.unexpected(SECTION)),
+            },
+            "ATTRIBUTE" => {
+                let (_, attribute) =
+                    attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.attributes.push(attribute);
+                Ok(())
+            }
+
+            _ => Err(kp.unexpected(SECTION)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PseudosParserState<S> {
+    Reset,
+    Pseudo(Pseudo<S>),
+}
+
+struct PseudosParser<S> {
+    state: PseudosParserState<S>,
+    pseudos: Vec<Pseudo<S>>,
+}
+
+impl<'a, S: InputStr<'a>> PseudosParser<S> {
+    fn new() -> Self {
+        let state = PseudosParserState::Reset;
+        let pseudos = Vec::new();
+        Self { state, pseudos }
+    }
+
+    fn ingest(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
+        if let PseudosParserState::Pseudo(ref mut pseudo) = self.state {
+            match kp.keyword {
+                "PSEUDO" => {
+                    self.pseudos.push(pseudo.clone());
+                    let pseudo = Pseudo::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = PseudosParserState::Pseudo(pseudo);
+                    Ok(())
+                }
+                _ => pseudo.update(kp, warnings),
+            }
+        } else {
+            match kp.keyword {
+                "PSEUDO" => {
+                    let pseudo = Pseudo::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = PseudosParserState::Pseudo(pseudo);
+                    Ok(())
+                }
+                _ => Err(kp.unexpected(SECTION)),
+            }
+        }
+    }
+
+    fn finalize(mut self) -> Result<Vec<Pseudo<S>>, Error> {
+        if let PseudosParserState::Pseudo(pseudo) = self.state {
+            self.pseudos.push(pseudo);
+            self.state = PseudosParserState::Reset;
+        }
+        Ok(self.pseudos)
+    }
+}
+
+/// Parse the `PSEUDOS` section of a GenCAD file.
+pub(crate) fn parse_pseudos<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<Vec<Pseudo<S>>, Error> {
+    let mut pp = PseudosParser::new();
+    for param in params {
+        pp.ingest(param, warnings)?;
+    }
+    pp.finalize()
+}
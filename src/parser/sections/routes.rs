@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD ROUTES section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use nom::Parser;
+use nom::sequence::preceded;
+
+use crate::error::{Error, FieldError};
+use crate::parser::sections::board::Text;
+use crate::parser::types::util::spaces;
+use crate::parser::types::{
+    arc_ref, attrib_ref, circle_ref, drill_size, filled_ref, layer, line_ref, mirror, pad_name,
+    rectangle_ref, rot, sig_name, testpad_name, text_par, track_name, via_name, x_y_ref,
+};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::{
+    ArcRef, Attribute, CircleRef, Layer, LineRef, Mirror, Number, RectangleRef, XYRef,
+};
+
+const SECTION: &str = "ROUTES";
+
+/// A via connecting copper on two or more layers of a route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Via<S = String> {
+    /// The name of the pad or padstack used for the via, as defined in `PADS` or `PADSTACKS`.
+    pub pad_name: S,
+    /// The absolute position of the via's center.
+    pub xy: XYRef,
+    /// The layer or layerset the via spans.
+    pub layer: Layer,
+    /// The drill hole size in [crate::types::Dimension] units. A value of `-1.0` means undefined.
+    pub drill_size: Number,
+    /// The name of the via. Use "-1" if no unique name is assigned.
+    pub name: S,
+}
+
+impl<'a, S: InputStr<'a>> Via<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, (pad_name, xy, layer, drill_size, name)) = (
+            pad_name,
+            preceded(spaces, x_y_ref),
+            preceded(spaces, layer),
+            preceded(spaces, drill_size),
+            preceded(spaces, via_name),
+        )
+            .parse(params)?;
+
+        Ok(Self {
+            pad_name,
+            xy,
+            layer,
+            drill_size,
+            name,
+        })
+    }
+}
+
+/// A test pad placed on a route, used as a probe point for the route's signal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestPad<S = String> {
+    /// The name of the test pad.
+    pub name: S,
+    /// The name of the pad or padstack used for the test pad, as defined in `PADS` or `PADSTACKS`.
+    pub pad_name: S,
+    /// The absolute position of the test pad's center.
+    pub xy: XYRef,
+    /// The layer on which the test pad is placed.
+    pub layer: Layer,
+    /// The rotation of the test pad around its origin, in degrees counterclockwise.
+    pub rotation: Number,
+    /// The mirror state of the test pad (applied before rotation).
+    pub mirror: Mirror,
+}
+
+impl<'a, S: InputStr<'a>> TestPad<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, (name, pad_name, xy, layer, rotation, mirror)) = (
+            testpad_name,
+            preceded(spaces, pad_name),
+            preceded(spaces, x_y_ref),
+            preceded(spaces, layer),
+            preceded(spaces, rot),
+            preceded(spaces, mirror),
+        )
+            .parse(params)?;
+
+        Ok(Self {
+            name,
+            pad_name,
+            xy,
+            layer,
+            rotation,
+            mirror,
+        })
+    }
+}
+
+/// An element of a route, in the order it appears in the file.
+///
+/// [RouteElement::Track], [RouteElement::Layer], and [RouteElement::Filled] set the context for
+/// all the elements that follow them, until they are changed again.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteElement<S = String> {
+    /// A track type defined in the `TRACKS` section, used for the following copper.
+    Track(S),
+    /// The layer of the following copper.
+    Layer(Layer),
+    /// Indicates whether the following shapes form an enclosed area.
+    Filled(bool),
+    /// A straight copper segment.
+    Line(LineRef),
+    /// A circular or elliptical copper arc.
+    Arc(ArcRef),
+    /// A full copper circle.
+    Circle(CircleRef),
+    /// A copper rectangle.
+    Rectangle(RectangleRef),
+    /// A via between layers.
+    Via(Via<S>),
+    /// A test pad on the route.
+    TestPad(TestPad<S>),
+    /// A text string attached to the route.
+    Text(Text<S>),
+}
+
+/// The routed copper of a single signal, defined in the `ROUTES` section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route<S = String> {
+    /// The name of the signal as defined in the `SIGNALS` section.
+    pub name: S,
+    /// Tracks, layers, segments, vias, and other features that make up the route.
+    pub elements: Vec<RouteElement<S>>,
+    /// Additional metadata associated with the route.
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `ROUTE` statement, if the route was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> Route<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, name) = sig_name(params)?;
+
+        Ok(Self {
+            name,
+            elements: Vec::new(),
+            attributes: Vec::new(),
+            span: Some(span),
+        })
+    }
+
+    fn update(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
+        match kp.keyword {
+            "TRACK" => {
+                let (_, track) =
+                    track_name(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements.push(RouteElement::Track(track));
+                Ok(())
+            }
+            "LAYER" => {
+                let (_, layer) = layer(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements.push(RouteElement::Layer(layer));
+                Ok(())
+            }
+            "FILLED" => {
+                let (_, filled) =
+                    filled_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements.push(RouteElement::Filled(filled));
+                Ok(())
+            }
+            "LINE" => {
+                let (_, line) = line_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements.push(RouteElement::Line(line));
+                Ok(())
+            }
+            "ARC" => {
+                let (_, arc) = arc_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements.push(RouteElement::Arc(arc));
+                Ok(())
+            }
+            "CIRCLE" => {
+                let (_, circle) =
+                    circle_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements.push(RouteElement::Circle(circle));
+                Ok(())
+            }
+            "RECTANGLE" => {
+                let (_, rectangle) =
+                    rectangle_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements.push(RouteElement::Rectangle(rectangle));
+                Ok(())
+            }
+            "VIA" => {
+                self.elements.push(RouteElement::Via(
+                    Via::from_parameters(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?,
+                ));
+                Ok(())
+            }
+            "TESTPAD" => {
+                self.elements.push(RouteElement::TestPad(
+                    TestPad::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?,
+                ));
+                Ok(())
+            }
+            "TEXT" => {
+                let (_, (origin, text)) = (x_y_ref, preceded(spaces, text_par))
+                    .parse(kp.parameter)
+                    .map_err(|err| kp.bad_field(SECTION, err))?;
+                self.elements
+                    .push(RouteElement::Text(Text::new(origin, text)));
+                Ok(())
+            }
+            "ATTRIBUTE" => {
+                let (_, attribute) =
+                    attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                self.attributes.push(attribute);
+                Ok(())
+            }
+
+            _ => Err(kp.unexpected(SECTION)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum RoutesParserState<S> {
+    Reset,
+    Route(Route<S>),
+}
+
+struct RoutesParser<S> {
+    state: RoutesParserState<S>,
+    routes: Vec<Route<S>>,
+}
+
+impl<'a, S: InputStr<'a>> RoutesParser<S> {
+    fn new() -> Self {
+        let state = RoutesParserState::Reset;
+        let routes = Vec::new();
+        Self { state, routes }
+    }
+
+    fn ingest(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
+        if let RoutesParserState::Route(ref mut route) = self.state {
+            match kp.keyword {
+                "ROUTE" => {
+                    self.routes.push(route.clone());
+                    let route = Route::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = RoutesParserState::Route(route);
+                    Ok(())
+                }
+                _ => route.update(kp),
+            }
+        } else {
+            match kp.keyword {
+                "ROUTE" => {
+                    let route = Route::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
+                    self.state = RoutesParserState::Route(route);
+                    Ok(())
+                }
+                _ => Err(kp.unexpected(SECTION)),
+            }
+        }
+    }
+
+    fn finalize(mut self) -> Result<Vec<Route<S>>, Error> {
+        if let RoutesParserState::Route(route) = self.state {
+            self.routes.push(route);
+            self.state = RoutesParserState::Reset;
+        }
+        Ok(self.routes)
+    }
+}
+
+/// Parse the `ROUTES` section of a GenCAD file.
+pub(crate) fn parse_routes<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+) -> Result<Vec<Route<S>>, Error> {
+    let mut rp = RoutesParser::new();
+    for param in params {
+        rp.ingest(param)?;
+    }
+    rp.finalize()
+}
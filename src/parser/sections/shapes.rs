@@ -21,16 +21,20 @@
 use nom::Parser;
 use nom::sequence::preceded;
 
-use crate::parser::KeywordParam;
+use crate::diagnostic::Diagnostic;
+use crate::error::{Error, FieldError};
 use crate::parser::types::util::spaces;
 use crate::parser::types::{
     arc_ref, artwork_name, attrib_ref, circle_ref, fid_name, height, layer, line_ref, mirror,
-    pad_name, pin_name, rectangle_ref, rot, shape_name, string, x_y_ref,
+    pad_name, pin_name, rectangle_ref, rot, shape_name, string_cow, x_y_ref,
 };
+use crate::parser::{InputStr, KeywordParam, Span};
 use crate::types::{
     ArcRef, Attribute, CircleRef, Layer, LineRef, Mirror, Number, RectangleRef, XYRef,
 };
 
+const SECTION: &str = "SHAPES";
+
 /// Geometric elements that define the outline of a component shape.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ShapeElement {
@@ -70,27 +74,27 @@ pub enum Insert {
 }
 
 impl Insert {
-    fn new(s: &str) -> Result<Self, String> {
+    fn new(s: &str) -> Option<Self> {
         match s {
-            "TH" => Ok(Self::Th),
-            "AXIAL" => Ok(Self::Axial),
-            "RADIAL" => Ok(Self::Radial),
-            "DIP" => Ok(Self::Dip),
-            "SIP" => Ok(Self::Sip),
-            "ZIP" => Ok(Self::Zip),
-            "CONN" => Ok(Self::Conn),
-            "SMD" => Ok(Self::Smd),
-            "OTHER" => Ok(Self::Other),
-            _ => Err(format!("Unexpected INSERT statement: {}", s)),
+            "TH" => Some(Self::Th),
+            "AXIAL" => Some(Self::Axial),
+            "RADIAL" => Some(Self::Radial),
+            "DIP" => Some(Self::Dip),
+            "SIP" => Some(Self::Sip),
+            "ZIP" => Some(Self::Zip),
+            "CONN" => Some(Self::Conn),
+            "SMD" => Some(Self::Smd),
+            "OTHER" => Some(Self::Other),
+            _ => None,
         }
     }
 }
 
 /// An artwork feature defined in the `ARTWORKS` section, placed relative to the shape origin.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Artwork {
+pub struct Artwork<S = String> {
     /// The name of the artwork as defined in the `ARTWORKS` section.
-    pub name: String,
+    pub name: S,
     /// The position of the artwork's origin relative to the shape origin.
     pub xy: XYRef,
     /// The rotation of the artwork around its origin, in degrees counterclockwise.
@@ -98,19 +102,18 @@ pub struct Artwork {
     /// The mirror state of the artwork (applied before rotation).
     pub mirror: Mirror,
     /// Additional metadata associated with the artwork.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Artwork {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Artwork<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
         let (_, (name, xy, rotation, mirror)) = (
             artwork_name,
             preceded(spaces, x_y_ref),
             preceded(spaces, rot),
             preceded(spaces, mirror),
         )
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+            .parse(params)?;
 
         let attributes = Vec::new();
 
@@ -126,11 +129,11 @@ impl Artwork {
 
 /// A fiducial marker using a pad or padstack, defined relative to the shape origin.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Fid {
+pub struct Fid<S = String> {
     /// The name of the fiducial (must be unique within the shape).
-    pub name: String,
+    pub name: S,
     /// The name of the pad or padstack used for the fiducial.
-    pub pad_name: String,
+    pub pad_name: S,
     /// The position of the fiducial's center relative to the shape origin.
     pub xy: XYRef,
     /// The layer on which the fiducial is placed, relative to the shape's layer.
@@ -140,11 +143,11 @@ pub struct Fid {
     /// The mirror state of the fiducial (applied before rotation).
     pub mirror: Mirror,
     /// Additional metadata associated with the fiducial.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Fid {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Fid<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
         let (_, (name, pad_name, xy, layer, rotation, mirror)) = (
             fid_name,
             preceded(spaces, pad_name),
@@ -153,8 +156,7 @@ impl Fid {
             preceded(spaces, rot),
             preceded(spaces, mirror),
         )
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+            .parse(params)?;
 
         let attributes = Vec::new();
 
@@ -172,11 +174,11 @@ impl Fid {
 
 /// A pin defined using a pad or padstack, placed relative to the shape origin.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Pin {
+pub struct Pin<S = String> {
     /// The name of the pin (must match device pin names).
-    pub name: String,
+    pub name: S,
     /// The name of the pad or padstack used for the pin.
-    pub pad_name: String,
+    pub pad_name: S,
     /// The position of the pin's center relative to the shape origin.
     pub xy: XYRef,
     /// The layer on which the pin is placed, relative to the shape's layer.
@@ -186,11 +188,13 @@ pub struct Pin {
     /// The mirror state of the pin (applied before rotation).
     pub mirror: Mirror,
     /// Additional metadata associated with the pin.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `PIN` statement, if the pin was parsed from a file.
+    pub span: Option<Span>,
 }
 
-impl Pin {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Pin<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
         let (_, (name, pad_name, xy, layer, rotation, mirror)) = (
             pin_name,
             preceded(spaces, pad_name),
@@ -199,8 +203,7 @@ impl Pin {
             preceded(spaces, rot),
             preceded(spaces, mirror),
         )
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+            .parse(params)?;
 
         let attributes = Vec::new();
 
@@ -212,32 +215,33 @@ impl Pin {
             rotation,
             mirror,
             attributes,
+            span: Some(span),
         })
     }
 }
 
 /// A subcomponent (artwork, fiducial, or pin) associated with a shape.
 #[derive(Debug, Clone, PartialEq)]
-pub enum SubShape {
+pub enum SubShape<S = String> {
     /// An artwork feature defined in the `ARTWORKS` section.
-    Artwork(Artwork),
+    Artwork(Artwork<S>),
     /// A fiducial marker using a pad or padstack.
-    Fid(Fid),
+    Fid(Fid<S>),
     /// A pin defined using a pad or padstack.
-    Pin(Pin),
+    Pin(Pin<S>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum ShapeParserState {
+enum ShapeParserState<S> {
     Shape,
-    SubShape(SubShape),
+    SubShape(SubShape<S>),
 }
 
 /// A reusable component outline defined in the `SHAPES` section.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Shape {
+pub struct Shape<S = String> {
     /// The unique name of the shape.
-    pub name: String,
+    pub name: S,
     /// Geometric elements defining the shape's outline.
     pub elements: Vec<ShapeElement>,
     /// Optional package style for component insertion.
@@ -245,26 +249,30 @@ pub struct Shape {
     /// The maximum height of the component from the board's surface.
     pub height: Option<Number>,
     /// Subcomponents (artwork, fiducials, or pins) associated with the shape.
-    pub subshapes: Vec<SubShape>,
+    pub subshapes: Vec<SubShape<S>>,
     /// Additional metadata associated with the shape.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
+    /// The location of the `SHAPE` statement, if the shape was parsed from a file.
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct ShapeParser {
-    state: ShapeParserState,
-    shape: Shape,
+struct ShapeParser<S> {
+    state: ShapeParserState<S>,
+    shape: Shape<S>,
+    /// Whether the shape's own `INSERT` statement has replaced the section's default insert.
+    has_insert: bool,
 }
 
-impl ShapeParser {
+impl<'a, S: InputStr<'a>> ShapeParser<S> {
     fn from_parameters(
-        params: &str,
+        params: &'a str,
         insert: Option<Insert>,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, name) = shape_name(params).map_err(|err| err.to_owned())?;
+        span: Span,
+    ) -> Result<Self, FieldError> {
+        let (_, name) = shape_name(params)?;
 
         let state = ShapeParserState::Shape;
-        let name = name.to_string();
         let elements = Vec::new();
         let height = None;
         let subshapes = Vec::new();
@@ -277,79 +285,108 @@ impl ShapeParser {
             height,
             subshapes,
             attributes,
+            span: Some(span),
         };
 
-        Ok(Self { state, shape })
+        Ok(Self {
+            state,
+            shape,
+            has_insert: false,
+        })
     }
 
-    fn ingest(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn ingest(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
         match &mut self.state {
             ShapeParserState::Shape => match kp.keyword {
                 "LINE" => {
-                    let (_, line) = line_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, line) =
+                        line_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.shape.elements.push(ShapeElement::Line(line));
                     Ok(())
                 }
                 "ARC" => {
-                    let (_, arc) = arc_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, arc) =
+                        arc_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.shape.elements.push(ShapeElement::Arc(arc));
                     Ok(())
                 }
                 "CIRCLE" => {
-                    let (_, circle) = circle_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, circle) =
+                        circle_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.shape.elements.push(ShapeElement::Circle(circle));
                     Ok(())
                 }
                 "RECTANGLE" => {
                     let (_, rectangle) =
-                        rectangle_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                        rectangle_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.shape.elements.push(ShapeElement::Rectangle(rectangle));
                     Ok(())
                 }
                 "FIDUCIAL" => {
-                    let (_, fiducial) = x_y_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, fiducial) =
+                        x_y_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.shape.elements.push(ShapeElement::Fiducial(fiducial));
                     Ok(())
                 }
 
                 "INSERT" => {
-                    let (_, insert) = string(kp.parameter).map_err(|err| err.to_owned())?;
-                    self.shape.insert = Some(Insert::new(&insert)?);
+                    let (_, insert) =
+                        string_cow(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    let insert = Insert::new(&insert)
+                        .ok_or_else(|| kp.bad_field(SECTION, FieldError::at(kp.parameter)))?;
+                    if self.has_insert {
+                        warnings.push(kp.overrides(SECTION));
+                    }
+                    self.shape.insert = Some(insert);
+                    self.has_insert = true;
                     Ok(())
                 }
                 "HEIGHT" => {
-                    let (_, height) = height(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, height) =
+                        height(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    if self.shape.height.is_some() {
+                        warnings.push(kp.overrides(SECTION));
+                    }
                     self.shape.height = Some(height);
                     Ok(())
                 }
 
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.shape.attributes.push(attribute);
                     Ok(())
                 }
 
                 "ARTWORK" => {
-                    let artwork = Artwork::from_parameters(kp.parameter)?;
+                    let artwork = Artwork::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapeParserState::SubShape(SubShape::Artwork(artwork));
                     Ok(())
                 }
                 "FID" => {
-                    let fid = Fid::from_parameters(kp.parameter)?;
+                    let fid = Fid::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapeParserState::SubShape(SubShape::Fid(fid));
                     Ok(())
                 }
                 "PIN" => {
-                    let pin = Pin::from_parameters(kp.parameter)?;
+                    let pin = Pin::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapeParserState::SubShape(SubShape::Pin(pin));
                     Ok(())
                 }
 
-                _ => Err(format!("Unexpected keyword in shape: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             },
             ShapeParserState::SubShape(subshape) => match kp.keyword {
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     match subshape {
                         SubShape::Artwork(a) => a.attributes.push(attribute),
                         SubShape::Fid(f) => f.attributes.push(attribute),
@@ -360,24 +397,27 @@ impl ShapeParser {
 
                 "ARTWORK" => {
                     self.done();
-                    let artwork = Artwork::from_parameters(kp.parameter)?;
+                    let artwork = Artwork::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapeParserState::SubShape(SubShape::Artwork(artwork));
                     Ok(())
                 }
                 "FID" => {
                     self.done();
-                    let fid = Fid::from_parameters(kp.parameter)?;
+                    let fid = Fid::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapeParserState::SubShape(SubShape::Fid(fid));
                     Ok(())
                 }
                 "PIN" => {
                     self.done();
-                    let pin = Pin::from_parameters(kp.parameter)?;
+                    let pin = Pin::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapeParserState::SubShape(SubShape::Pin(pin));
                     Ok(())
                 }
 
-                _ => Err(format!("Unexpected keyword in subshape: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             },
         }
     }
@@ -392,18 +432,19 @@ impl ShapeParser {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum ShapesParserState {
+#[allow(clippy::large_enum_variant)]
+enum ShapesParserState<S> {
     Reset,
-    ShapeParser(ShapeParser),
+    ShapeParser(ShapeParser<S>),
 }
 
-struct ShapesParser {
-    state: ShapesParserState,
+struct ShapesParser<S> {
+    state: ShapesParserState<S>,
     insert: Option<Insert>,
-    shapes: Vec<Shape>,
+    shapes: Vec<Shape<S>>,
 }
 
-impl ShapesParser {
+impl<'a, S: InputStr<'a>> ShapesParser<S> {
     fn new() -> Self {
         let state = ShapesParserState::Reset;
         let insert = None;
@@ -415,41 +456,51 @@ impl ShapesParser {
         }
     }
 
-    fn ingest(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn ingest(
+        &mut self,
+        kp: &KeywordParam<'a>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<(), Error> {
         if let ShapesParserState::ShapeParser(ref mut parser) = self.state {
             match kp.keyword {
                 "SHAPE" => {
                     parser.done();
                     self.shapes.push(parser.shape.clone());
-                    let parser = ShapeParser::from_parameters(kp.parameter, self.insert)?;
+                    let parser = ShapeParser::from_parameters(kp.parameter, self.insert, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapesParserState::ShapeParser(parser);
                     Ok(())
                 }
-                "INSERT" => {
-                    let (_, insert) = string(kp.parameter).map_err(|err| err.to_owned())?;
-                    self.insert = Some(Insert::new(&insert)?);
-                    Ok(())
-                }
-                _ => parser.ingest(kp),
+                // An `INSERT` inside a shape belongs to that shape, and doesn't change the
+                // default for the shapes after it.
+                _ => parser.ingest(kp, warnings),
             }
         } else {
             match kp.keyword {
                 "SHAPE" => {
-                    let parser = ShapeParser::from_parameters(kp.parameter, self.insert)?;
+                    let parser = ShapeParser::from_parameters(kp.parameter, self.insert, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = ShapesParserState::ShapeParser(parser);
                     Ok(())
                 }
                 "INSERT" => {
-                    let (_, insert) = string(kp.parameter).map_err(|err| err.to_owned())?;
-                    self.insert = Some(Insert::new(&insert)?);
+                    let (_, insert) =
+                        string_cow(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
+                    if self.insert.is_some() {
+                        warnings.push(kp.overrides(SECTION));
+                    }
+                    self.insert = Some(
+                        Insert::new(&insert)
+                            .ok_or_else(|| kp.bad_field(SECTION, FieldError::at(kp.parameter)))?,
+                    );
                     Ok(())
                 }
-                _ => Err(format!("Unexpected keyword in shapes: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             }
         }
     }
 
-    fn finalize(mut self) -> Vec<Shape> {
+    fn finalize(mut self) -> Vec<Shape<S>> {
         if let ShapesParserState::ShapeParser(mut parser) = self.state {
             parser.done();
             self.shapes.push(parser.shape);
@@ -460,12 +511,13 @@ impl ShapesParser {
 }
 
 /// Parse the `SHAPES` section of a GenCAD file.
-pub(crate) fn parse_shapes(
-    params: &[KeywordParam],
-) -> Result<Vec<Shape>, Box<dyn std::error::Error>> {
+pub(crate) fn parse_shapes<'a, S: InputStr<'a>>(
+    params: &[KeywordParam<'a>],
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<Vec<Shape<S>>, Error> {
     let mut sp = ShapesParser::new();
     for param in params {
-        sp.ingest(param)?;
+        sp.ingest(param, warnings)?;
     }
     let shapes = sp.finalize();
     Ok(shapes)
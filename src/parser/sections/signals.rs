@@ -18,73 +18,89 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use nom::Parser;
 use nom::sequence::preceded;
+use nom::{IResult, Parser};
 
-use crate::parser::KeywordParam;
+use crate::error::{Error, FieldError};
 use crate::parser::types::util::spaces;
 use crate::parser::types::{
     attrib_ref, component_name, layer, pin_name, probe, sig_name, tan, tin, tp_name, x_y_ref,
 };
+use crate::parser::{InputStr, KeywordParam, Span};
 use crate::types::{Attribute, Layer, XYRef};
 
+const SECTION: &str = "SIGNALS";
+
 /// A connection point on a component, defined by its component and pin names.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Node {
+pub struct Node<S = String> {
     /// The name of the component as defined in the `COMPONENTS` section.
-    pub component_name: String,
+    pub component_name: S,
     /// The name of the pin as defined in the `SHAPES` section.
-    pub pin_name: String,
+    pub pin_name: S,
+    /// The location of the `NODE` statement, if the node was parsed from a file.
+    pub span: Option<Span>,
 }
 
-impl Node {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, (component_name, pin_name)) = (component_name, preceded(spaces, pin_name))
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> Node<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, (component_name, pin_name)) =
+            (component_name, preceded(spaces, pin_name)).parse(params)?;
 
         Ok(Self {
             component_name,
             pin_name,
+            span: Some(span),
         })
     }
 }
 
 /// A preferred test point location for a signal, used in bed-of-nails testing.
 #[derive(Debug, Clone, PartialEq)]
-pub struct NailLoc {
+pub struct NailLoc<S = String> {
     /// The name of the component as defined in the `COMPONENTS` section.
-    pub component_name: String,
+    pub component_name: S,
     /// The name of the pin as defined in the `SHAPES` section.
-    pub pin_name: String,
+    pub pin_name: S,
     /// The test pin name. Use "-1" if no unique name is assigned.
-    pub tp_name: String,
+    pub tp_name: S,
     /// The absolute coordinate of the nail location. Use "-32767 -32767" to inherit the node's position.
     pub xy: XYRef,
     /// Tester Assigned Number. Use "-1" if undefined.
-    pub tan: String,
+    pub tan: S,
     /// Tester Interface Name. Use "-1" if undefined.
-    pub tin: String,
+    pub tin: S,
     /// Probe type (e.g., "100T", "75C"). Use "-1" if undefined.
-    pub probe: String,
+    pub probe: S,
     /// The layer on which the probe is applied. Only [Layer::Top] or [Layer::Bottom] are valid.
     pub layer: Layer,
 }
 
-impl NailLoc {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, (component_name, pin_name, tp_name, xy, tan, tin, probe, layer)) = (
+/// Parses the tester fields shared by nail locations, test pins, and power pins:
+/// `tp_name x_y_ref tan tin probe layer`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn tester_fields<'a, S: InputStr<'a>>(
+    s: &'a str,
+) -> IResult<&'a str, (S, XYRef, S, S, S, Layer)> {
+    (
+        tp_name,
+        preceded(spaces, x_y_ref),
+        preceded(spaces, tan),
+        preceded(spaces, tin),
+        preceded(spaces, probe),
+        preceded(spaces, layer),
+    )
+        .parse(s)
+}
+
+impl<'a, S: InputStr<'a>> NailLoc<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, (component_name, pin_name, (tp_name, xy, tan, tin, probe, layer))) = (
             component_name,
             preceded(spaces, pin_name),
-            preceded(spaces, tp_name),
-            preceded(spaces, x_y_ref),
-            preceded(spaces, tan),
-            preceded(spaces, tin),
-            preceded(spaces, probe),
-            preceded(spaces, layer),
+            preceded(spaces, tester_fields),
         )
-            .parse(params)
-            .map_err(|err| err.to_owned())?;
+            .parse(params)?;
 
         Ok(Self {
             component_name,
@@ -101,56 +117,64 @@ impl NailLoc {
 
 /// A signal or net defined in the `SIGNALS` section, representing electrical connectivity.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Signal {
+pub struct Signal<S = String> {
     /// The unique name of the signal or net, used in the `ROUTES` section.
-    pub name: String,
+    pub name: S,
     /// A list of [Node] objects defining the connections to components and pins.
-    pub nodes: Vec<Node>,
+    pub nodes: Vec<Node<S>>,
     /// A list of [NailLoc] objects defining preferred test point locations for this signal.
-    pub nail_locations: Vec<NailLoc>,
+    pub nail_locations: Vec<NailLoc<S>>,
+    /// The location of the `SIGNAL` statement, if the signal was parsed from a file.
+    pub span: Option<Span>,
 }
 
-impl Signal {
-    fn from_parameters(params: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let (_, name) = sig_name(params).map_err(|err| err.to_owned())?;
+impl<'a, S: InputStr<'a>> Signal<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, name) = sig_name(params)?;
 
         Ok(Self {
             name,
             nodes: Vec::new(),
             nail_locations: Vec::new(),
+            span: Some(span),
         })
     }
 
-    fn update(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn update(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
         match kp.keyword {
             "NODE" => {
-                self.nodes.push(Node::from_parameters(kp.parameter)?);
+                self.nodes.push(
+                    Node::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?,
+                );
                 Ok(())
             }
             "NAILLOC" => {
-                self.nail_locations
-                    .push(NailLoc::from_parameters(kp.parameter)?);
+                self.nail_locations.push(
+                    NailLoc::from_parameters(kp.parameter)
+                        .map_err(|err| kp.bad_field(SECTION, err))?,
+                );
                 Ok(())
             }
 
-            _ => Err(format!("Unexpected keyword in signal: {}", kp.keyword).into()),
+            _ => Err(kp.unexpected(SECTION)),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum SignalsParserState {
+enum SignalsParserState<S> {
     Reset,
-    Device(Signal),
+    Device(Signal<S>),
 }
 
-struct SignalsParser {
-    state: SignalsParserState,
-    signals: Vec<Signal>,
-    attributes: Vec<Attribute>,
+struct SignalsParser<S> {
+    state: SignalsParserState<S>,
+    signals: Vec<Signal<S>>,
+    attributes: Vec<Attribute<S>>,
 }
 
-impl SignalsParser {
+impl<'a, S: InputStr<'a>> SignalsParser<S> {
     fn new() -> Self {
         let state = SignalsParserState::Reset;
         let signals = Vec::new();
@@ -162,17 +186,19 @@ impl SignalsParser {
         }
     }
 
-    fn ingest(&mut self, kp: &KeywordParam) -> Result<(), Box<dyn std::error::Error>> {
+    fn ingest(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
         if let SignalsParserState::Device(ref mut device) = self.state {
             match kp.keyword {
                 "SIGNAL" => {
                     self.signals.push(device.clone());
-                    let device = Signal::from_parameters(kp.parameter)?;
+                    let device = Signal::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = SignalsParserState::Device(device);
                     Ok(())
                 }
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.attributes.push(attribute);
                     Ok(())
                 }
@@ -181,21 +207,23 @@ impl SignalsParser {
         } else {
             match kp.keyword {
                 "SIGNAL" => {
-                    let device = Signal::from_parameters(kp.parameter)?;
+                    let device = Signal::from_parameters(kp.parameter, kp.span())
+                        .map_err(|err| kp.bad_field(SECTION, err))?;
                     self.state = SignalsParserState::Device(device);
                     Ok(())
                 }
                 "ATTRIBUTE" => {
-                    let (_, attribute) = attrib_ref(kp.parameter).map_err(|err| err.to_owned())?;
+                    let (_, attribute) =
+                        attrib_ref(kp.parameter).map_err(|err| kp.bad_field(SECTION, err))?;
                     self.attributes.push(attribute);
                     Ok(())
                 }
-                _ => Err(format!("Unexpected keyword in signals: {}", kp.keyword).into()),
+                _ => Err(kp.unexpected(SECTION)),
             }
         }
     }
 
-    fn finalize(mut self) -> Result<Signals, Box<dyn std::error::Error>> {
+    fn finalize(mut self) -> Result<Signals<S>, Error> {
         if let SignalsParserState::Device(device) = self.state {
             self.signals.push(device.clone());
             self.state = SignalsParserState::Reset;
@@ -209,15 +237,15 @@ impl SignalsParser {
 
 /// Represents the `SIGNALS` section of a GenCAD file, defining all connectivity information.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Signals {
+pub struct Signals<S = String> {
     /// A list of all defined signals and their connections.
-    pub signals: Vec<Signal>,
+    pub signals: Vec<Signal<S>>,
     /// Additional metadata associated with the `SIGNALS` section.
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<S>>,
 }
 
-impl Signals {
-    pub(crate) fn new(params: &[KeywordParam]) -> Result<Self, Box<dyn std::error::Error>> {
+impl<'a, S: InputStr<'a>> Signals<S> {
+    pub(crate) fn new(params: &[KeywordParam<'a>]) -> Result<Self, Error> {
         let mut p = SignalsParser::new();
         for param in params {
             p.ingest(param)?;
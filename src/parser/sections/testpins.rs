@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD TESTPINS and POWERPINS sections.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::error::{Error, FieldError};
+use crate::parser::sections::signals::tester_fields;
+use crate::parser::types::{attrib_ref, sig_name};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::{Attribute, Layer, XYRef};
+
+/// A tester pin assigned to a signal, used in bed-of-nails testing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestPin<S = String> {
+    /// The test pin name. Use "-1" if no unique name is assigned.
+    pub tp_name: S,
+    /// The absolute coordinate of the test pin.
+    pub xy: XYRef,
+    /// Tester Assigned Number. Use "-1" if undefined.
+    pub tan: S,
+    /// Tester Interface Name. Use "-1" if undefined.
+    pub tin: S,
+    /// Probe type (e.g., "100T", "75C"). Use "-1" if undefined.
+    pub probe: S,
+    /// The layer on which the probe is applied. Only [Layer::Top] or [Layer::Bottom] are valid.
+    pub layer: Layer,
+    /// The location of the `TESTPIN` or `POWERPIN` statement, if the test pin was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> TestPin<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, (tp_name, xy, tan, tin, probe, layer)) = tester_fields(params)?;
+
+        Ok(Self {
+            tp_name,
+            xy,
+            tan,
+            tin,
+            probe,
+            layer,
+            span: Some(span),
+        })
+    }
+}
+
+/// The tester pins assigned to a single signal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalPins<S = String> {
+    /// The name of the signal as defined in the `SIGNALS` section.
+    pub name: S,
+    /// The tester pins assigned to this signal.
+    pub pins: Vec<TestPin<S>>,
+}
+
+impl<'a, S: InputStr<'a>> SignalPins<S> {
+    fn from_parameters(params: &'a str) -> Result<Self, FieldError> {
+        let (_, name) = sig_name(params)?;
+
+        Ok(Self {
+            name,
+            pins: Vec::new(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TestPinsParserState<S> {
+    Reset,
+    Signal(SignalPins<S>),
+}
+
+struct TestPinsParser<S> {
+    section: &'static str,
+    pin_keyword: &'static str,
+    state: TestPinsParserState<S>,
+    signals: Vec<SignalPins<S>>,
+    attributes: Vec<Attribute<S>>,
+}
+
+impl<'a, S: InputStr<'a>> TestPinsParser<S> {
+    fn new(section: &'static str, pin_keyword: &'static str) -> Self {
+        let state = TestPinsParserState::Reset;
+        let signals = Vec::new();
+        let attributes = Vec::new();
+        Self {
+            section,
+            pin_keyword,
+            state,
+            signals,
+            attributes,
+        }
+    }
+
+    fn ingest(&mut self, kp: &KeywordParam<'a>) -> Result<(), Error> {
+        match kp.keyword {
+            "SIGNAL" => {
+                if let TestPinsParserState::Signal(signal) =
+                    std::mem::replace(&mut self.state, TestPinsParserState::Reset)
+                {
+                    self.signals.push(signal);
+                }
+                let signal = SignalPins::from_parameters(kp.parameter)
+                    .map_err(|err| kp.bad_field(self.section, err))?;
+                self.state = TestPinsParserState::Signal(signal);
+                Ok(())
+            }
+            "ATTRIBUTE" => {
+                let (_, attribute) =
+                    attrib_ref(kp.parameter).map_err(|err| kp.bad_field(self.section, err))?;
+                self.attributes.push(attribute);
+                Ok(())
+            }
+            keyword if keyword == self.pin_keyword => match self.state {
+                TestPinsParserState::Signal(ref mut signal) => {
+                    signal.pins.push(
+                        TestPin::from_parameters(kp.parameter, kp.span())
+                            .map_err(|err| kp.bad_field(self.section, err))?,
+                    );
+                    Ok(())
+                }
+                TestPinsParserState::Reset => Err(kp.unexpected(self.section)),
+            },
+            _ => Err(kp.unexpected(self.section)),
+        }
+    }
+
+    fn finalize(mut self) -> Result<TestPins<S>, Error> {
+        if let TestPinsParserState::Signal(signal) = self.state {
+            self.signals.push(signal);
+            self.state = TestPinsParserState::Reset;
+        }
+        Ok(TestPins {
+            signals: self.signals,
+            attributes: self.attributes,
+        })
+    }
+}
+
+/// Represents the `TESTPINS` or `POWERPINS` section of a GenCAD file, assigning tester pins to
+/// signals.
+///
+/// Both sections share the same structure: a `SIGNAL` statement followed by the `TESTPIN` or
+/// `POWERPIN` statements for that signal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestPins<S = String> {
+    /// The tester pins, grouped by signal.
+    pub signals: Vec<SignalPins<S>>,
+    /// Additional metadata associated with the section.
+    pub attributes: Vec<Attribute<S>>,
+}
+
+impl<'a, S: InputStr<'a>> TestPins<S> {
+    fn parse(
+        section: &'static str,
+        pin_keyword: &'static str,
+        params: &[KeywordParam<'a>],
+    ) -> Result<Self, Error> {
+        let mut p = TestPinsParser::new(section, pin_keyword);
+        for param in params {
+            p.ingest(param)?;
+        }
+        p.finalize()
+    }
+
+    /// Parse the `TESTPINS` section of a GenCAD file.
+    pub(crate) fn new_testpins(params: &[KeywordParam<'a>]) -> Result<Self, Error> {
+        Self::parse("TESTPINS", "TESTPIN", params)
+    }
+
+    /// Parse the `POWERPINS` section of a GenCAD file.
+    pub(crate) fn new_powerpins(params: &[KeywordParam<'a>]) -> Result<Self, Error> {
+        Self::parse("POWERPINS", "POWERPIN", params)
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD ARTWORKS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::artworks::*;
+use super::super::board::ArtworkComponent;
+
+use crate::parser::KeywordParam;
+use crate::types::{CircleRef, Layer, LineRef, XYRef};
+
+#[test]
+fn test_example_artworks() {
+    let params = vec![
+        KeywordParam {
+            keyword: "ARTWORK",
+            parameter: "PIN1_MARKER",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "SILKSCREEN_TOP",
+            line: 2,
+            offset: 21,
+        },
+        KeywordParam {
+            keyword: "TRACK",
+            parameter: "1",
+            line: 3,
+            offset: 43,
+        },
+        KeywordParam {
+            keyword: "CIRCLE",
+            parameter: "0 0 20",
+            line: 4,
+            offset: 52,
+        },
+        KeywordParam {
+            keyword: "ARTWORK",
+            parameter: "ORIGIN_MARKER",
+            line: 5,
+            offset: 67,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "TOP",
+            line: 6,
+            offset: 90,
+        },
+        KeywordParam {
+            keyword: "FILLED",
+            parameter: "0",
+            line: 7,
+            offset: 101,
+        },
+        KeywordParam {
+            keyword: "LINE",
+            parameter: "-100 0 100 0",
+            line: 8,
+            offset: 111,
+        },
+        KeywordParam {
+            keyword: "LINE",
+            parameter: "0 -100 0 100",
+            line: 9,
+            offset: 130,
+        },
+    ];
+
+    let artworks = parse_artworks(&params).unwrap();
+
+    assert_eq!(
+        artworks,
+        vec![
+            Artwork {
+                name: "PIN1_MARKER".to_string(),
+                components: vec![
+                    ArtworkComponent::Layer(Layer::SilkscreenTop),
+                    ArtworkComponent::Track("1".to_string()),
+                    ArtworkComponent::Circle(CircleRef {
+                        center: XYRef { x: 0.0, y: 0.0 },
+                        radius: 20.0
+                    })
+                ],
+                attributes: vec![],
+                span: Some(params[0].span())
+            },
+            Artwork {
+                name: "ORIGIN_MARKER".to_string(),
+                components: vec![
+                    ArtworkComponent::Layer(Layer::Top),
+                    ArtworkComponent::Filled(false),
+                    ArtworkComponent::Line(LineRef {
+                        start: XYRef { x: -100.0, y: 0.0 },
+                        end: XYRef { x: 100.0, y: 0.0 }
+                    }),
+                    ArtworkComponent::Line(LineRef {
+                        start: XYRef { x: 0.0, y: -100.0 },
+                        end: XYRef { x: 0.0, y: 100.0 }
+                    })
+                ],
+                attributes: vec![],
+                span: Some(params[4].span())
+            }
+        ]
+    );
+}
@@ -29,74 +29,108 @@ fn test_example_board() {
         KeywordParam {
             keyword: "LINE",
             parameter: "1000 2000 1200 2000",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "ARC",
             parameter: "1200 2000 1200 3000 1180 2500",
+            line: 2,
+            offset: 26,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "1200 3000 1000 3000",
+            line: 3,
+            offset: 61,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "1000 3000 1000 2000",
+            line: 4,
+            offset: 87,
         },
         KeywordParam {
             keyword: "CUTOUT",
             parameter: "TRANSFORMER_HOLE",
+            line: 5,
+            offset: 113,
         },
         KeywordParam {
             keyword: "CIRCLE",
             parameter: "1180 2500 20",
+            line: 6,
+            offset: 138,
         },
         KeywordParam {
             keyword: "ATTRIBUTE",
             parameter: "board mill \"tool 255\"",
+            line: 7,
+            offset: 159,
         },
         KeywordParam {
             keyword: "MASK",
             parameter: "Fixture_1 TOP",
+            line: 8,
+            offset: 192,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "1005 2005 1195 2005",
+            line: 9,
+            offset: 212,
         },
         KeywordParam {
             keyword: "ARC",
             parameter: "1195 2005 1195 2995 1195 2500",
+            line: 10,
+            offset: 238,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "1195 2995 1005 2995",
+            line: 11,
+            offset: 273,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "1005 2995 1005 2005",
+            line: 12,
+            offset: 299,
         },
         KeywordParam {
             keyword: "ARTWORK",
             parameter: "ORIGIN_MARKER TOP",
+            line: 13,
+            offset: 325,
         },
         KeywordParam {
             keyword: "TRACK",
             parameter: "10",
+            line: 14,
+            offset: 352,
         },
         KeywordParam {
             keyword: "FILLED",
             parameter: "YES",
+            line: 15,
+            offset: 362,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "-100 0 100 0",
+            line: 16,
+            offset: 374,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "0 -100 0 100",
+            line: 17,
+            offset: 393,
         },
     ];
 
-    let board = Board::new(&params).unwrap();
+    let board = Board::new(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(board.thickness, None);
     assert_eq!(
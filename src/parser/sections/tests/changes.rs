@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD CHANGES section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::changes::*;
+
+use crate::parser::KeywordParam;
+use crate::types::Attribute;
+
+#[test]
+fn test_example_changes() {
+    let params = vec![
+        KeywordParam {
+            keyword: "ATTRIBUTE",
+            parameter: "eco system \"PLM\"",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "CHANGE",
+            parameter: "ECO-101 \"Swapped U1 and U2\"",
+            line: 2,
+            offset: 28,
+        },
+        KeywordParam {
+            keyword: "ATTRIBUTE",
+            parameter: "eco author jdoe",
+            line: 3,
+            offset: 64,
+        },
+        KeywordParam {
+            keyword: "CHANGE",
+            parameter: "B Moved R12 off the keepout",
+            line: 4,
+            offset: 91,
+        },
+        KeywordParam {
+            keyword: "CHANGE",
+            parameter: "C",
+            line: 5,
+            offset: 127,
+        },
+    ];
+
+    let changes = Changes::<String>::new(&params).unwrap();
+
+    assert_eq!(
+        changes,
+        Changes {
+            changes: vec![
+                Change {
+                    id: "ECO-101".to_string(),
+                    description: "Swapped U1 and U2".to_string(),
+                    attributes: vec![Attribute {
+                        category: "eco".to_string(),
+                        name: "author".to_string(),
+                        data: "jdoe".to_string()
+                    }],
+                    span: Some(params[1].span())
+                },
+                Change {
+                    id: "B".to_string(),
+                    description: "Moved R12 off the keepout".to_string(),
+                    attributes: vec![],
+                    span: Some(params[3].span())
+                },
+                Change {
+                    id: "C".to_string(),
+                    description: "".to_string(),
+                    attributes: vec![],
+                    span: Some(params[4].span())
+                }
+            ],
+            attributes: vec![Attribute {
+                category: "eco".to_string(),
+                name: "system".to_string(),
+                data: "PLM".to_string()
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_changes_unexpected_keyword() {
+    let params = vec![KeywordParam {
+        keyword: "REVISION",
+        parameter: "B",
+        line: 1,
+        offset: 0,
+    }];
+
+    assert!(Changes::<String>::new(&params).is_err());
+}
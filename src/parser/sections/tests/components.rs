@@ -29,70 +29,102 @@ fn test_example_components() {
         KeywordParam {
             keyword: "COMPONENT",
             parameter: "D102",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "DEVICE",
             parameter: "1N4148",
+            line: 2,
+            offset: 16,
         },
         KeywordParam {
             keyword: "PLACE",
             parameter: "1200 1800",
+            line: 3,
+            offset: 31,
         },
         KeywordParam {
             keyword: "LAYER",
             parameter: "TOP",
+            line: 4,
+            offset: 48,
         },
         KeywordParam {
             keyword: "ROTATION",
             parameter: "90",
+            line: 5,
+            offset: 59,
         },
         KeywordParam {
             keyword: "SHAPE",
             parameter: "DO35_a MIRRORX 0",
+            line: 6,
+            offset: 72,
         },
         KeywordParam {
             keyword: "ARTWORK",
             parameter: "ORIGIN_MARKER 0 0 0 MIRRORX 0",
+            line: 7,
+            offset: 96,
         },
         KeywordParam {
             keyword: "TEXT",
             parameter: "50 -50 100 90 0 TOP D102 42 -50 500 200",
+            line: 8,
+            offset: 135,
         },
         KeywordParam {
             keyword: "SHEET",
             parameter: "12_B3",
+            line: 9,
+            offset: 181,
         },
         KeywordParam {
             keyword: "COMPONENT",
             parameter: "U7",
+            line: 10,
+            offset: 194,
         },
         KeywordParam {
             keyword: "DEVICE",
             parameter: "74LS04",
+            line: 11,
+            offset: 208,
         },
         KeywordParam {
             keyword: "PLACE",
             parameter: "0.003 9.52527",
+            line: 12,
+            offset: 223,
         },
         KeywordParam {
             keyword: "LAYER",
             parameter: "BOTTOM",
+            line: 13,
+            offset: 244,
         },
         KeywordParam {
             keyword: "ROTATION",
             parameter: "12.25",
+            line: 14,
+            offset: 258,
         },
         KeywordParam {
             keyword: "SHAPE",
             parameter: "DIL14 0 FLIP",
+            line: 15,
+            offset: 274,
         },
         KeywordParam {
             keyword: "ARTWORK",
             parameter: "PIN1_MARKER 6500 2400 0 MIRRORX FLIP",
+            line: 16,
+            offset: 294,
         },
     ];
 
-    let components = parse_components(&params).unwrap();
+    let components = parse_components(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(
         components,
@@ -135,7 +167,8 @@ fn test_example_components() {
                     }
                 }],
                 sheet: Some("12_B3".to_string()),
-                attributes: vec![]
+                attributes: vec![],
+                span: Some(params[0].span())
             },
             Component {
                 name: "U7".to_string(),
@@ -164,7 +197,8 @@ fn test_example_components() {
                 })],
                 texts: vec![],
                 sheet: None,
-                attributes: vec![]
+                attributes: vec![],
+                span: Some(params[9].span())
             }
         ]
     );
@@ -178,30 +212,42 @@ fn test_component_with_improperly_formatted_strings() {
         KeywordParam {
             keyword: "COMPONENT",
             parameter: "LGA1718",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "PLACE",
             parameter: "5073.52 8389.53",
+            line: 2,
+            offset: 19,
         },
         KeywordParam {
             keyword: "LAYER",
             parameter: "TOP",
+            line: 3,
+            offset: 42,
         },
         KeywordParam {
             keyword: "ROTATION",
             parameter: "0",
+            line: 4,
+            offset: 53,
         },
         KeywordParam {
             keyword: "SHAPE",
             parameter: "LGA1718 0 0",
+            line: 5,
+            offset: 65,
         },
         KeywordParam {
             keyword: "DEVICE",
             parameter: "Device LGA1718",
+            line: 6,
+            offset: 84,
         },
     ];
 
-    let components = parse_components(&params).unwrap();
+    let components = parse_components(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(
         components,
@@ -222,7 +268,8 @@ fn test_component_with_improperly_formatted_strings() {
             subcomponents: vec![],
             texts: vec![],
             sheet: None,
-            attributes: vec![]
+            attributes: vec![],
+            span: Some(params[0].span())
         },]
     );
 }
@@ -28,30 +28,42 @@ fn test_example_devices() {
         KeywordParam {
             keyword: "DEVICE",
             parameter: "89-1N4148",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "PART",
             parameter: "1N4148",
+            line: 2,
+            offset: 18,
         },
         KeywordParam {
             keyword: "TYPE",
             parameter: "DIODE",
+            line: 3,
+            offset: 31,
         },
         KeywordParam {
             keyword: "PINDESC",
             parameter: "1 anode",
+            line: 4,
+            offset: 43,
         },
         KeywordParam {
             keyword: "PINDESC",
             parameter: "2 cathode",
+            line: 5,
+            offset: 60,
         },
         KeywordParam {
             keyword: "DESC",
             parameter: "\"Diode 1N4148 bandoleer reverse voltage 100V\"",
+            line: 6,
+            offset: 79,
         },
     ];
 
-    let devices = parse_devices(&params).unwrap();
+    let devices = parse_devices(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(
         devices,
@@ -79,7 +91,8 @@ fn test_example_devices() {
             ptol: None,
             volts: None,
             desc: Some("Diode 1N4148 bandoleer reverse voltage 100V".to_string()),
-            attributes: vec![]
+            attributes: vec![],
+            span: Some(params[0].span())
         }]
     );
 }
@@ -92,14 +105,18 @@ fn test_device_with_improperly_formatted_strings() {
         KeywordParam {
             keyword: "DEVICE",
             parameter: "Device PANEL",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "PART",
             parameter: "HEADER 2X10P G/F 2.54 BLK/C//LONG SHOUNG/1102014270",
+            line: 2,
+            offset: 21,
         },
     ];
 
-    let devices = parse_devices(&params).unwrap();
+    let devices = parse_devices(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(
         devices,
@@ -118,7 +135,8 @@ fn test_device_with_improperly_formatted_strings() {
             ptol: None,
             volts: None,
             desc: None,
-            attributes: vec![]
+            attributes: vec![],
+            span: Some(params[0].span())
         }]
     );
 }
@@ -20,6 +20,8 @@
 
 use super::super::header::*;
 
+use crate::Error;
+use crate::diagnostic::{Diagnostic, Severity};
 use crate::parser::KeywordParam;
 use crate::types::{Attribute, Dimension, XYRef};
 
@@ -29,42 +31,60 @@ fn test_valid_header() {
         KeywordParam {
             keyword: "GENCAD",
             parameter: "1.4",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "USER",
             parameter: "\"Mitron Europe Ltd. Serial Number 00001\"",
+            line: 2,
+            offset: 12,
         },
         KeywordParam {
             keyword: "DRAWING",
             parameter: "\"Modem C100 motherboard 1234-5678\"",
+            line: 3,
+            offset: 59,
         },
         KeywordParam {
             keyword: "REVISION",
             parameter: "\"Rev 566g 20th September 1990\"",
+            line: 4,
+            offset: 103,
         },
         KeywordParam {
             keyword: "UNITS",
             parameter: "USER 1200",
+            line: 5,
+            offset: 144,
         },
         KeywordParam {
             keyword: "ORIGIN",
             parameter: "0 0",
+            line: 6,
+            offset: 161,
         },
         KeywordParam {
             keyword: "INTERTRACK",
             parameter: "0",
+            line: 7,
+            offset: 173,
         },
         KeywordParam {
             keyword: "ATTRIBUTE",
             parameter: "alpha m_part \"BIS 9600\"",
+            line: 8,
+            offset: 187,
         },
         KeywordParam {
             keyword: "ATTRIBUTE",
             parameter: "alpha m_desc \"Issue 2\"",
+            line: 9,
+            offset: 222,
         },
     ];
 
-    let header = Header::new(&params).unwrap();
+    let header = Header::new(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(header.gencad_version, 1.4);
     assert_eq!(header.user, "Mitron Europe Ltd. Serial Number 00001");
@@ -96,30 +116,44 @@ fn test_missing_required_keywords() {
         KeywordParam {
             keyword: "GENCAD",
             parameter: "1.4",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "USER",
             parameter: "\"Mitron Europe Ltd. Serial Number 00001\"",
+            line: 2,
+            offset: 12,
         },
         KeywordParam {
             keyword: "DRAWING",
             parameter: "\"Modem C100 motherboard 1234-5678\"",
+            line: 3,
+            offset: 59,
         },
         KeywordParam {
             keyword: "REVISION",
             parameter: "\"Rev 566g 20th September 1990\"",
+            line: 4,
+            offset: 103,
         },
         KeywordParam {
             keyword: "UNITS",
             parameter: "USER 1200",
+            line: 5,
+            offset: 144,
         },
         KeywordParam {
             keyword: "ORIGIN",
             parameter: "0 0",
+            line: 6,
+            offset: 161,
         },
         KeywordParam {
             keyword: "INTERTRACK",
             parameter: "0",
+            line: 7,
+            offset: 173,
         },
     ];
 
@@ -137,11 +171,92 @@ fn test_missing_required_keywords() {
         let mut params = base_params.clone();
         params.retain(|p| p.keyword != keyword);
 
-        let result = Header::new(&params);
-        assert!(result.is_err());
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            format!("missing {}", keyword)
-        );
+        let result = Header::<String>::new(&params, &mut Vec::new());
+        assert!(matches!(
+            result,
+            Err(Error::MissingKeyword { keyword: ref missing, .. }) if missing == keyword
+        ));
     }
 }
+
+#[test]
+fn test_ignored_keyword_warnings() {
+    let params = vec![
+        KeywordParam {
+            keyword: "GENCAD",
+            parameter: "1.4",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "USER",
+            parameter: "\"Mitron Europe Ltd. Serial Number 00001\"",
+            line: 2,
+            offset: 12,
+        },
+        KeywordParam {
+            keyword: "DRAWING",
+            parameter: "\"Modem C100 motherboard 1234-5678\"",
+            line: 3,
+            offset: 59,
+        },
+        KeywordParam {
+            keyword: "REVISION",
+            parameter: "\"Rev 566g 20th September 1990\"",
+            line: 4,
+            offset: 103,
+        },
+        KeywordParam {
+            keyword: "UNITS",
+            parameter: "USER 1200",
+            line: 5,
+            offset: 144,
+        },
+        KeywordParam {
+            keyword: "ORIGIN",
+            parameter: "0 0",
+            line: 6,
+            offset: 161,
+        },
+        KeywordParam {
+            keyword: "INTERTRACK",
+            parameter: "0",
+            line: 7,
+            offset: 173,
+        },
+        KeywordParam {
+            keyword: "GENCAD",
+            parameter: "1.3",
+            line: 8,
+            offset: 187,
+        },
+        KeywordParam {
+            keyword: "LAYOUT",
+            parameter: "\"Sheet 1\"",
+            line: 9,
+            offset: 199,
+        },
+    ];
+
+    let mut warnings = Vec::new();
+    let header = Header::<String>::new(&params, &mut warnings).unwrap();
+
+    assert_eq!(header.gencad_version, 1.4);
+    assert_eq!(
+        warnings,
+        vec![
+            Diagnostic {
+                severity: Severity::Warning,
+                line: 8,
+                column: Some(1),
+                message: "ignored duplicate GENCAD in $HEADER".to_string(),
+            },
+            Diagnostic {
+                severity: Severity::Warning,
+                line: 9,
+                column: Some(1),
+                message: "ignored LAYOUT in $HEADER: unknown keyword".to_string(),
+            },
+        ]
+    );
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD LAYERS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::layers::*;
+
+use crate::parser::KeywordParam;
+use crate::types::{Attribute, Layer};
+
+#[test]
+fn test_example_layers() {
+    let params = vec![
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "LAYER5 \"Top assembly drawing\"",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "ATTRIBUTE",
+            parameter: "layer color red",
+            line: 2,
+            offset: 37,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "LAYER6 Drill",
+            line: 3,
+            offset: 64,
+        },
+        KeywordParam {
+            keyword: "LAYERSET",
+            parameter: "LAYERSET1",
+            line: 4,
+            offset: 84,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "TOP",
+            line: 5,
+            offset: 104,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "INNER1",
+            line: 6,
+            offset: 115,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "BOTTOM",
+            line: 7,
+            offset: 129,
+        },
+        KeywordParam {
+            keyword: "LAYERSET",
+            parameter: "LAYERSET2",
+            line: 8,
+            offset: 143,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "POWER1",
+            line: 9,
+            offset: 163,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "GROUND1",
+            line: 10,
+            offset: 177,
+        },
+    ];
+
+    let layers = Layers::new(&params).unwrap();
+
+    assert_eq!(
+        layers,
+        Layers {
+            layers: vec![
+                LayerDef {
+                    layer: Layer::LayerX(5),
+                    description: "Top assembly drawing".to_string(),
+                    attributes: vec![Attribute {
+                        category: "layer".to_string(),
+                        name: "color".to_string(),
+                        data: "red".to_string()
+                    }],
+                    span: Some(params[0].span())
+                },
+                LayerDef {
+                    layer: Layer::LayerX(6),
+                    description: "Drill".to_string(),
+                    attributes: vec![],
+                    span: Some(params[2].span())
+                }
+            ],
+            layersets: vec![
+                Layerset {
+                    layerset: Layer::LayersetX(1),
+                    layers: vec![Layer::Top, Layer::InnerX(1), Layer::Bottom],
+                    attributes: vec![],
+                    span: Some(params[3].span())
+                },
+                Layerset {
+                    layerset: Layer::LayersetX(2),
+                    layers: vec![Layer::PowerX(1), Layer::GroundX(1)],
+                    attributes: vec![],
+                    span: Some(params[7].span())
+                }
+            ],
+            attributes: vec![]
+        }
+    );
+}
+
+#[test]
+fn test_layerset_must_be_layerset() {
+    let params = vec![KeywordParam {
+        keyword: "LAYERSET",
+        parameter: "LAYER1",
+        line: 1,
+        offset: 0,
+    }];
+
+    assert!(Layers::<String>::new(&params).is_err());
+}
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD MECH section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::components::Shape;
+use super::super::mech::*;
+
+use crate::Error;
+use crate::parser::KeywordParam;
+use crate::types::{Attribute, Layer, Mirror, XYRef};
+
+#[test]
+fn test_example_mech() {
+    let params = vec![
+        KeywordParam {
+            keyword: "MECHANICAL",
+            parameter: "HEATSINK1",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "PLACE",
+            parameter: "2000 1500",
+            line: 2,
+            offset: 22,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "TOP",
+            line: 3,
+            offset: 39,
+        },
+        KeywordParam {
+            keyword: "ROTATION",
+            parameter: "90",
+            line: 4,
+            offset: 50,
+        },
+        KeywordParam {
+            keyword: "SHAPE",
+            parameter: "HS_TO220 0 0",
+            line: 5,
+            offset: 63,
+        },
+        KeywordParam {
+            keyword: "HOLE",
+            parameter: "-150 0 32",
+            line: 6,
+            offset: 83,
+        },
+        KeywordParam {
+            keyword: "HOLE",
+            parameter: "150 0 32",
+            line: 7,
+            offset: 99,
+        },
+        KeywordParam {
+            keyword: "ATTRIBUTE",
+            parameter: "mech vendor \"Aavid 577202\"",
+            line: 8,
+            offset: 114,
+        },
+        KeywordParam {
+            keyword: "MECHANICAL",
+            parameter: "MH1",
+            line: 9,
+            offset: 152,
+        },
+        KeywordParam {
+            keyword: "PLACE",
+            parameter: "100 100",
+            line: 10,
+            offset: 168,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "ALL",
+            line: 11,
+            offset: 183,
+        },
+        KeywordParam {
+            keyword: "ROTATION",
+            parameter: "0",
+            line: 12,
+            offset: 194,
+        },
+        KeywordParam {
+            keyword: "HOLE",
+            parameter: "0 0 125",
+            line: 13,
+            offset: 206,
+        },
+    ];
+
+    let mechanicals = parse_mech(&params, &mut Vec::new()).unwrap();
+
+    assert_eq!(
+        mechanicals,
+        vec![
+            Mechanical {
+                name: "HEATSINK1".to_string(),
+                place: XYRef {
+                    x: 2000.0,
+                    y: 1500.0
+                },
+                layer: Layer::Top,
+                rotation: 90.0,
+                shape: Some(Shape {
+                    name: "HS_TO220".to_string(),
+                    mirror: Mirror::Not,
+                    flip: false
+                }),
+                holes: vec![
+                    Hole {
+                        xy: XYRef { x: -150.0, y: 0.0 },
+                        drill_size: 32.0
+                    },
+                    Hole {
+                        xy: XYRef { x: 150.0, y: 0.0 },
+                        drill_size: 32.0
+                    }
+                ],
+                attributes: vec![Attribute {
+                    category: "mech".to_string(),
+                    name: "vendor".to_string(),
+                    data: "Aavid 577202".to_string()
+                }],
+                span: Some(params[0].span())
+            },
+            Mechanical {
+                name: "MH1".to_string(),
+                place: XYRef { x: 100.0, y: 100.0 },
+                layer: Layer::All,
+                rotation: 0.0,
+                shape: None,
+                holes: vec![Hole {
+                    xy: XYRef { x: 0.0, y: 0.0 },
+                    drill_size: 125.0
+                }],
+                attributes: vec![],
+                span: Some(params[8].span())
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_mechanical_missing_place() {
+    let params = vec![
+        KeywordParam {
+            keyword: "MECHANICAL",
+            parameter: "MH1",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "TOP",
+            line: 2,
+            offset: 16,
+        },
+        KeywordParam {
+            keyword: "ROTATION",
+            parameter: "0",
+            line: 3,
+            offset: 27,
+        },
+    ];
+
+    let result = parse_mech::<String>(&params, &mut Vec::new());
+    assert!(matches!(
+        result,
+        Err(Error::MissingKeyword { ref keyword, line: 1, .. }) if keyword == "PLACE"
+    ));
+}
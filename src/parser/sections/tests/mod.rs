@@ -18,11 +18,19 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod artworks;
 mod board;
+mod changes;
 mod components;
 mod devices;
 mod header;
+mod layers;
+mod mech;
 mod pads;
 mod padstacks;
+mod pseudos;
+mod routes;
 mod shapes;
 mod signals;
+mod testpins;
+mod tracks;
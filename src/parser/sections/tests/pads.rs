@@ -20,6 +20,7 @@
 
 use super::super::pads::*;
 
+use crate::diagnostic::{Diagnostic, Severity};
 use crate::parser::KeywordParam;
 use crate::types::{ArcRef, CircleRef, CircularArcRef, LineRef, PadType, RectangleRef, XYRef};
 
@@ -29,70 +30,102 @@ fn test_example_pads() {
         KeywordParam {
             keyword: "PAD",
             parameter: "p0101 FINGER 32",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "100 50 -100 50",
+            line: 2,
+            offset: 21,
         },
         KeywordParam {
             keyword: "ARC",
             parameter: "-100 50 -100 -50 -100 0",
+            line: 3,
+            offset: 42,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "-100 -50 100 -50",
+            line: 4,
+            offset: 71,
         },
         KeywordParam {
             keyword: "ARC",
             parameter: "100 -50 100 50 100 0",
+            line: 5,
+            offset: 94,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "p1053 ROUND 20",
+            line: 6,
+            offset: 120,
         },
         KeywordParam {
             keyword: "CIRCLE",
             parameter: "0 0 30",
+            line: 7,
+            offset: 140,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "p2034 BULLET 32",
+            line: 8,
+            offset: 155,
         },
         KeywordParam {
             keyword: "ARC",
             parameter: "0 -50 0 50 0 0",
+            line: 9,
+            offset: 176,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "0 50 -100 50",
+            line: 10,
+            offset: 196,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "-100 50 -100 -50",
+            line: 11,
+            offset: 215,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "-100 -50 0 -50",
+            line: 12,
+            offset: 238,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "d_hole_50 ROUND 50",
+            line: 13,
+            offset: 259,
         },
         KeywordParam {
             keyword: "CIRCLE",
             parameter: "0 0 25",
+            line: 14,
+            offset: 283,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "3 RECTANGULAR 0",
+            line: 15,
+            offset: 298,
         },
         KeywordParam {
             keyword: "RECTANGLE",
             parameter: "-5.2 -5.2 10.4 10.4",
+            line: 16,
+            offset: 319,
         },
     ];
 
-    let pads = parse_pads(&params).unwrap();
+    let pads = parse_pads(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(
         pads,
@@ -127,7 +160,8 @@ fn test_example_pads() {
                         center: XYRef { x: 100.0, y: 0.0 }
                     }))
                 ],
-                attributes: vec![]
+                attributes: vec![],
+                span: Some(params[0].span())
             },
             Pad {
                 name: "p1053".to_string(),
@@ -137,7 +171,8 @@ fn test_example_pads() {
                     center: XYRef { x: 0.0, y: 0.0 },
                     radius: 30.0
                 })],
-                attributes: vec![]
+                attributes: vec![],
+                span: Some(params[5].span())
             },
             Pad {
                 name: "p2034".to_string(),
@@ -168,7 +203,8 @@ fn test_example_pads() {
                         end: XYRef { x: 0.0, y: -50.0 }
                     })
                 ],
-                attributes: vec![]
+                attributes: vec![],
+                span: Some(params[7].span())
             },
             Pad {
                 name: "d_hole_50".to_string(),
@@ -178,7 +214,8 @@ fn test_example_pads() {
                     center: XYRef { x: 0.0, y: 0.0 },
                     radius: 25.0
                 })],
-                attributes: vec![]
+                attributes: vec![],
+                span: Some(params[12].span())
             },
             Pad {
                 name: "3".to_string(),
@@ -189,8 +226,60 @@ fn test_example_pads() {
                     x: 10.4,
                     y: 10.4
                 })],
-                attributes: vec![]
+                attributes: vec![],
+                span: Some(params[14].span())
             }
         ]
     );
 }
+
+#[test]
+fn test_shape_outside_pad() {
+    let params = vec![
+        KeywordParam {
+            keyword: "LINE",
+            parameter: "0 0 10 0",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "PAD",
+            parameter: "p1 ROUND 10",
+            line: 2,
+            offset: 15,
+        },
+        KeywordParam {
+            keyword: "CIRCLE",
+            parameter: "0 0 5",
+            line: 3,
+            offset: 32,
+        },
+    ];
+
+    let mut warnings = Vec::new();
+    let pads = parse_pads(&params, &mut warnings).unwrap();
+
+    assert_eq!(
+        pads,
+        vec![Pad {
+            name: "p1".to_string(),
+            ptype: PadType::Round,
+            drill_size: 10.0,
+            shapes: vec![PadShape::Circle(CircleRef {
+                center: XYRef { x: 0.0, y: 0.0 },
+                radius: 5.0
+            })],
+            attributes: vec![],
+            span: Some(params[1].span()),
+        }]
+    );
+    assert_eq!(
+        warnings,
+        vec![Diagnostic {
+            severity: Severity::Warning,
+            line: 1,
+            column: Some(1),
+            message: "ignored LINE in $PADS: not inside a PAD".to_string(),
+        }]
+    );
+}
@@ -29,34 +29,50 @@ fn test_example_padstacks() {
         KeywordParam {
             keyword: "PADSTACK",
             parameter: "p_stack1 -1",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "p102_4 TOP 180 0",
+            line: 2,
+            offset: 22,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "s102_4 BOTTOM 0 0",
+            line: 3,
+            offset: 44,
         },
         KeywordParam {
             keyword: "PADSTACK",
             parameter: "p_stack2 -1",
+            line: 4,
+            offset: 67,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "r_r3 TOP 180 MIRRORX",
+            line: 5,
+            offset: 89,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "r_r0 INNER1 180 MIRRORX",
+            line: 6,
+            offset: 115,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "r_r0 INNER2 180 MIRRORX",
+            line: 7,
+            offset: 144,
         },
         KeywordParam {
             keyword: "PAD",
             parameter: "r_r3 BOTTOM 180 MIRRORY",
+            line: 8,
+            offset: 173,
         },
     ];
 
@@ -82,7 +98,8 @@ fn test_example_padstacks() {
                             rotation: 0.0,
                             mirror: Mirror::Not
                         }
-                    ]
+                    ],
+                    span: Some(params[0].span())
                 },
                 Padstack {
                     name: "p_stack2".to_string(),
@@ -112,7 +129,8 @@ fn test_example_padstacks() {
                             rotation: 180.0,
                             mirror: Mirror::MirrorY
                         }
-                    ]
+                    ],
+                    span: Some(params[3].span())
                 }
             ],
             attributes: vec![]
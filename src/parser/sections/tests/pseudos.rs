@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD PSEUDOS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::pseudos::*;
+
+use crate::parser::KeywordParam;
+
+#[test]
+fn test_example_pseudos() {
+    let params = vec![
+        KeywordParam {
+            keyword: "PSEUDO",
+            parameter: "RN1",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "DEVICE",
+            parameter: "RESNET_4X10K",
+            line: 2,
+            offset: 12,
+        },
+        KeywordParam {
+            keyword: "COMPONENT",
+            parameter: "R1",
+            line: 3,
+            offset: 33,
+        },
+        KeywordParam {
+            keyword: "PIN",
+            parameter: "1 1",
+            line: 4,
+            offset: 47,
+        },
+        KeywordParam {
+            keyword: "PIN",
+            parameter: "8 2",
+            line: 5,
+            offset: 56,
+        },
+        KeywordParam {
+            keyword: "COMPONENT",
+            parameter: "R2",
+            line: 6,
+            offset: 65,
+        },
+        KeywordParam {
+            keyword: "PIN",
+            parameter: "2 1",
+            line: 7,
+            offset: 79,
+        },
+        KeywordParam {
+            keyword: "PIN",
+            parameter: "7 2",
+            line: 8,
+            offset: 88,
+        },
+    ];
+
+    let pseudos = parse_pseudos(&params, &mut Vec::new()).unwrap();
+
+    assert_eq!(
+        pseudos,
+        vec![Pseudo {
+            name: "RN1".to_string(),
+            device: Some("RESNET_4X10K".to_string()),
+            members: vec![
+                Member {
+                    component_name: "R1".to_string(),
+                    pins: vec![
+                        PinMap {
+                            pseudo_pin: "1".to_string(),
+                            pin_name: "1".to_string()
+                        },
+                        PinMap {
+                            pseudo_pin: "8".to_string(),
+                            pin_name: "2".to_string()
+                        }
+                    ]
+                },
+                Member {
+                    component_name: "R2".to_string(),
+                    pins: vec![
+                        PinMap {
+                            pseudo_pin: "2".to_string(),
+                            pin_name: "1".to_string()
+                        },
+                        PinMap {
+                            pseudo_pin: "7".to_string(),
+                            pin_name: "2".to_string()
+                        }
+                    ]
+                }
+            ],
+            attributes: vec![],
+            span: Some(params[0].span())
+        }]
+    );
+}
+
+#[test]
+fn test_pseudo_pin_before_component() {
+    let params = vec![
+        KeywordParam {
+            keyword: "PSEUDO",
+            parameter: "RN1",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "PIN",
+            parameter: "1 1",
+            line: 2,
+            offset: 12,
+        },
+    ];
+
+    assert!(parse_pseudos::<String>(&params, &mut Vec::new()).is_err());
+}
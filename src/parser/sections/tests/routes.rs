@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD ROUTES section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::routes::*;
+
+use crate::parser::KeywordParam;
+use crate::types::{ArcRef, Attribute, CircularArcRef, Layer, LineRef, Mirror, XYRef};
+
+#[test]
+fn test_example_routes() {
+    let params = vec![
+        KeywordParam {
+            keyword: "ROUTE",
+            parameter: "data_bus_7",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "TRACK",
+            parameter: "2",
+            line: 2,
+            offset: 18,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "TOP",
+            line: 3,
+            offset: 27,
+        },
+        KeywordParam {
+            keyword: "LINE",
+            parameter: "1000 1000 1000 1500",
+            line: 4,
+            offset: 38,
+        },
+        KeywordParam {
+            keyword: "ARC",
+            parameter: "1000 1500 1100 1600 1100 1500",
+            line: 5,
+            offset: 64,
+        },
+        KeywordParam {
+            keyword: "VIA",
+            parameter: "via_pad 1100 1600 ALL -1 via1",
+            line: 6,
+            offset: 99,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "BOTTOM",
+            line: 7,
+            offset: 134,
+        },
+        KeywordParam {
+            keyword: "LINE",
+            parameter: "1100 1600 1500 1600",
+            line: 8,
+            offset: 148,
+        },
+        KeywordParam {
+            keyword: "TESTPAD",
+            parameter: "tp1 p1053 1500 1600 BOTTOM 0 0",
+            line: 9,
+            offset: 174,
+        },
+        KeywordParam {
+            keyword: "ATTRIBUTE",
+            parameter: "route class \"high speed\"",
+            line: 10,
+            offset: 214,
+        },
+        KeywordParam {
+            keyword: "ROUTE",
+            parameter: "ADDRESS_BUS_4",
+            line: 11,
+            offset: 250,
+        },
+        KeywordParam {
+            keyword: "TRACK",
+            parameter: "1",
+            line: 12,
+            offset: 271,
+        },
+        KeywordParam {
+            keyword: "LAYER",
+            parameter: "INNER1",
+            line: 13,
+            offset: 280,
+        },
+        KeywordParam {
+            keyword: "LINE",
+            parameter: "200 200 400 200",
+            line: 14,
+            offset: 294,
+        },
+    ];
+
+    let routes = parse_routes(&params).unwrap();
+
+    assert_eq!(
+        routes,
+        vec![
+            Route {
+                name: "data_bus_7".to_string(),
+                elements: vec![
+                    RouteElement::Track("2".to_string()),
+                    RouteElement::Layer(Layer::Top),
+                    RouteElement::Line(LineRef {
+                        start: XYRef {
+                            x: 1000.0,
+                            y: 1000.0
+                        },
+                        end: XYRef {
+                            x: 1000.0,
+                            y: 1500.0
+                        }
+                    }),
+                    RouteElement::Arc(ArcRef::Circular(CircularArcRef {
+                        start: XYRef {
+                            x: 1000.0,
+                            y: 1500.0
+                        },
+                        end: XYRef {
+                            x: 1100.0,
+                            y: 1600.0
+                        },
+                        center: XYRef {
+                            x: 1100.0,
+                            y: 1500.0
+                        }
+                    })),
+                    RouteElement::Via(Via {
+                        pad_name: "via_pad".to_string(),
+                        xy: XYRef {
+                            x: 1100.0,
+                            y: 1600.0
+                        },
+                        layer: Layer::All,
+                        drill_size: -1.0,
+                        name: "via1".to_string()
+                    }),
+                    RouteElement::Layer(Layer::Bottom),
+                    RouteElement::Line(LineRef {
+                        start: XYRef {
+                            x: 1100.0,
+                            y: 1600.0
+                        },
+                        end: XYRef {
+                            x: 1500.0,
+                            y: 1600.0
+                        }
+                    }),
+                    RouteElement::TestPad(TestPad {
+                        name: "tp1".to_string(),
+                        pad_name: "p1053".to_string(),
+                        xy: XYRef {
+                            x: 1500.0,
+                            y: 1600.0
+                        },
+                        layer: Layer::Bottom,
+                        rotation: 0.0,
+                        mirror: Mirror::Not
+                    })
+                ],
+                attributes: vec![Attribute {
+                    category: "route".to_string(),
+                    name: "class".to_string(),
+                    data: "high speed".to_string()
+                }],
+                span: Some(params[0].span())
+            },
+            Route {
+                name: "ADDRESS_BUS_4".to_string(),
+                elements: vec![
+                    RouteElement::Track("1".to_string()),
+                    RouteElement::Layer(Layer::InnerX(1)),
+                    RouteElement::Line(LineRef {
+                        start: XYRef { x: 200.0, y: 200.0 },
+                        end: XYRef { x: 400.0, y: 200.0 }
+                    })
+                ],
+                attributes: vec![],
+                span: Some(params[10].span())
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_route_unexpected_keyword() {
+    let params = vec![KeywordParam {
+        keyword: "LINE",
+        parameter: "0 0 100 0",
+        line: 1,
+        offset: 0,
+    }];
+
+    assert!(parse_routes::<String>(&params).is_err());
+
+    let params = vec![
+        KeywordParam {
+            keyword: "ROUTE",
+            parameter: "GND",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "NODE",
+            parameter: "U1 1",
+            line: 2,
+            offset: 11,
+        },
+    ];
+
+    assert!(parse_routes::<String>(&params).is_err());
+}
@@ -20,6 +20,7 @@
 
 use super::super::shapes::*;
 
+use crate::diagnostic::{Diagnostic, Severity};
 use crate::parser::KeywordParam;
 use crate::types::{ArcRef, CircularArcRef, Layer, LineRef, Mirror, XYRef};
 
@@ -29,50 +30,72 @@ fn test_example_shape() {
         KeywordParam {
             keyword: "SHAPE",
             parameter: "CAP_SUPPRESS_TYPE_____24",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "-1000 200 -1000 -200",
+            line: 2,
+            offset: 32,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "-1000 -200 1000 -200",
+            line: 3,
+            offset: 59,
         },
         KeywordParam {
             keyword: "ARC",
             parameter: "1000 -200 1000 200 1000 0",
+            line: 4,
+            offset: 86,
         },
         KeywordParam {
             keyword: "LINE",
             parameter: "1000 200 -1000 200",
+            line: 5,
+            offset: 117,
         },
         KeywordParam {
             keyword: "PIN",
             parameter: "1 p102_4 -100 100 TOP 315 0",
+            line: 6,
+            offset: 142,
         },
         KeywordParam {
             keyword: "PIN",
             parameter: "1 s106_6 -100 100 BOTTOM 315 MIRRORX",
+            line: 7,
+            offset: 175,
         },
         KeywordParam {
             keyword: "PIN",
             parameter: "2 p102_4 100 -100 TOP 135 0",
+            line: 8,
+            offset: 217,
         },
         KeywordParam {
             keyword: "PIN",
             parameter: "2 s106_6 100 -100 BOTTOM 135 MIRRORX",
+            line: 9,
+            offset: 250,
         },
         KeywordParam {
             keyword: "ARTWORK",
             parameter: "PIN1_MARKER 0 400 0 0",
+            line: 10,
+            offset: 292,
         },
         KeywordParam {
             keyword: "FID",
             parameter: "PRIMARY OPTICAL1 0 0 TOP 0 0",
+            line: 11,
+            offset: 323,
         },
     ];
 
-    let shapes = parse_shapes(&params).unwrap();
+    let shapes = parse_shapes(&params, &mut Vec::new()).unwrap();
 
     assert_eq!(
         shapes,
@@ -134,7 +157,8 @@ fn test_example_shape() {
                     layer: Layer::Top,
                     rotation: 315.0,
                     mirror: Mirror::Not,
-                    attributes: vec![]
+                    attributes: vec![],
+                    span: Some(params[5].span())
                 }),
                 SubShape::Pin(Pin {
                     name: "1".to_string(),
@@ -146,7 +170,8 @@ fn test_example_shape() {
                     layer: Layer::Bottom,
                     rotation: 315.0,
                     mirror: Mirror::MirrorX,
-                    attributes: vec![]
+                    attributes: vec![],
+                    span: Some(params[6].span())
                 }),
                 SubShape::Pin(Pin {
                     name: "2".to_string(),
@@ -158,7 +183,8 @@ fn test_example_shape() {
                     layer: Layer::Top,
                     rotation: 135.0,
                     mirror: Mirror::Not,
-                    attributes: vec![]
+                    attributes: vec![],
+                    span: Some(params[7].span())
                 }),
                 SubShape::Pin(Pin {
                     name: "2".to_string(),
@@ -170,7 +196,8 @@ fn test_example_shape() {
                     layer: Layer::Bottom,
                     rotation: 135.0,
                     mirror: Mirror::MirrorX,
-                    attributes: vec![]
+                    attributes: vec![],
+                    span: Some(params[8].span())
                 }),
                 SubShape::Artwork(Artwork {
                     name: "PIN1_MARKER".to_string(),
@@ -189,7 +216,195 @@ fn test_example_shape() {
                     attributes: vec![]
                 })
             ],
-            attributes: vec![]
+            attributes: vec![],
+            span: Some(params[0].span())
+        }]
+    );
+}
+
+#[test]
+fn test_shape_overrides() {
+    let params = vec![
+        KeywordParam {
+            keyword: "INSERT",
+            parameter: "TH",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "SHAPE",
+            parameter: "SOIC8",
+            line: 2,
+            offset: 11,
+        },
+        KeywordParam {
+            keyword: "INSERT",
+            parameter: "SMD",
+            line: 3,
+            offset: 24,
+        },
+        KeywordParam {
+            keyword: "HEIGHT",
+            parameter: "100",
+            line: 4,
+            offset: 36,
+        },
+        KeywordParam {
+            keyword: "INSERT",
+            parameter: "SIP",
+            line: 5,
+            offset: 48,
+        },
+        KeywordParam {
+            keyword: "HEIGHT",
+            parameter: "120",
+            line: 6,
+            offset: 60,
+        },
+        KeywordParam {
+            keyword: "SHAPE",
+            parameter: "DIP8",
+            line: 7,
+            offset: 72,
+        },
+    ];
+
+    let mut warnings = Vec::new();
+    let shapes = parse_shapes(&params, &mut warnings).unwrap();
+
+    assert_eq!(
+        shapes,
+        vec![
+            Shape {
+                name: "SOIC8".to_string(),
+                elements: vec![],
+                insert: Some(Insert::Sip),
+                height: Some(120.0),
+                subshapes: vec![],
+                attributes: vec![],
+                span: Some(params[1].span()),
+            },
+            Shape {
+                name: "DIP8".to_string(),
+                elements: vec![],
+                insert: Some(Insert::Th),
+                height: None,
+                subshapes: vec![],
+                attributes: vec![],
+                span: Some(params[6].span()),
+            },
+        ]
+    );
+    assert_eq!(
+        warnings,
+        vec![
+            Diagnostic {
+                severity: Severity::Warning,
+                line: 5,
+                column: Some(1),
+                message: "INSERT in $SHAPES overrides an earlier INSERT".to_string(),
+            },
+            Diagnostic {
+                severity: Severity::Warning,
+                line: 6,
+                column: Some(1),
+                message: "HEIGHT in $SHAPES overrides an earlier HEIGHT".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_shape_insert() {
+    let params = vec![
+        KeywordParam {
+            keyword: "INSERT",
+            parameter: "TH",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "SHAPE",
+            parameter: "SOIC8",
+            line: 2,
+            offset: 11,
+        },
+        KeywordParam {
+            keyword: "INSERT",
+            parameter: "SMD",
+            line: 3,
+            offset: 24,
+        },
+        KeywordParam {
+            keyword: "SHAPE",
+            parameter: "DIP8",
+            line: 4,
+            offset: 36,
+        },
+    ];
+
+    let shapes = parse_shapes(&params, &mut Vec::new()).unwrap();
+
+    // The INSERT inside SOIC8 only applies to SOIC8, so DIP8 keeps the section's default.
+    assert_eq!(
+        shapes,
+        vec![
+            Shape {
+                name: "SOIC8".to_string(),
+                elements: vec![],
+                insert: Some(Insert::Smd),
+                height: None,
+                subshapes: vec![],
+                attributes: vec![],
+                span: Some(params[1].span()),
+            },
+            Shape {
+                name: "DIP8".to_string(),
+                elements: vec![],
+                insert: Some(Insert::Th),
+                height: None,
+                subshapes: vec![],
+                attributes: vec![],
+                span: Some(params[3].span()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_section_insert_override() {
+    let params = vec![
+        KeywordParam {
+            keyword: "INSERT",
+            parameter: "TH",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "INSERT",
+            parameter: "SMD",
+            line: 2,
+            offset: 11,
+        },
+        KeywordParam {
+            keyword: "SHAPE",
+            parameter: "SOIC8",
+            line: 3,
+            offset: 23,
+        },
+    ];
+
+    let mut warnings = Vec::new();
+    let shapes: Vec<Shape> = parse_shapes(&params, &mut warnings).unwrap();
+
+    assert_eq!(shapes[0].insert, Some(Insert::Smd));
+    assert_eq!(
+        warnings,
+        vec![Diagnostic {
+            severity: Severity::Warning,
+            line: 2,
+            column: Some(1),
+            message: "INSERT in $SHAPES overrides an earlier INSERT".to_string(),
         }]
     );
 }
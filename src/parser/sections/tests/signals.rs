@@ -29,46 +29,68 @@ fn test_example_signals() {
         KeywordParam {
             keyword: "SIGNAL",
             parameter: "data_bus_7",
+            line: 1,
+            offset: 0,
         },
         KeywordParam {
             keyword: "NODE",
             parameter: "IC3 2",
+            line: 2,
+            offset: 19,
         },
         KeywordParam {
             keyword: "NODE",
             parameter: "R2 2",
+            line: 3,
+            offset: 31,
         },
         KeywordParam {
             keyword: "NAILLOC",
             parameter: "R2 2 -1 500 2500 -1 -1 100T BOTTOM",
+            line: 4,
+            offset: 42,
         },
         KeywordParam {
             keyword: "NODE",
             parameter: "IC4 2",
+            line: 5,
+            offset: 86,
         },
         KeywordParam {
             keyword: "NODE",
             parameter: "6Ic2 p34A",
+            line: 6,
+            offset: 98,
         },
         KeywordParam {
             keyword: "NAILLOC",
             parameter: "6Ic2 p34A -1 800 3000 -1 -1 75T BOTTOM",
+            line: 7,
+            offset: 114,
         },
         KeywordParam {
             keyword: "SIGNAL",
             parameter: "ADDRESS_BUS_4",
+            line: 8,
+            offset: 162,
         },
         KeywordParam {
             keyword: "NODE",
             parameter: "U1 2",
+            line: 9,
+            offset: 184,
         },
         KeywordParam {
             keyword: "NODE",
             parameter: "PL12 132",
+            line: 10,
+            offset: 195,
         },
         KeywordParam {
             keyword: "NAILLOC",
             parameter: "PL12 132 -1 200 200 -1 -1 100T BOTTOM",
+            line: 11,
+            offset: 210,
         },
     ];
 
@@ -83,19 +105,23 @@ fn test_example_signals() {
                     nodes: vec![
                         Node {
                             component_name: "IC3".to_string(),
-                            pin_name: "2".to_string()
+                            pin_name: "2".to_string(),
+                            span: Some(params[1].span())
                         },
                         Node {
                             component_name: "R2".to_string(),
-                            pin_name: "2".to_string()
+                            pin_name: "2".to_string(),
+                            span: Some(params[2].span())
                         },
                         Node {
                             component_name: "IC4".to_string(),
-                            pin_name: "2".to_string()
+                            pin_name: "2".to_string(),
+                            span: Some(params[4].span())
                         },
                         Node {
                             component_name: "6Ic2".to_string(),
-                            pin_name: "p34A".to_string()
+                            pin_name: "p34A".to_string(),
+                            span: Some(params[5].span())
                         }
                     ],
                     nail_locations: vec![
@@ -125,18 +151,21 @@ fn test_example_signals() {
                             probe: "75T".to_string(),
                             layer: Layer::Bottom
                         }
-                    ]
+                    ],
+                    span: Some(params[0].span())
                 },
                 Signal {
                     name: "ADDRESS_BUS_4".to_string(),
                     nodes: vec![
                         Node {
                             component_name: "U1".to_string(),
-                            pin_name: "2".to_string()
+                            pin_name: "2".to_string(),
+                            span: Some(params[8].span())
                         },
                         Node {
                             component_name: "PL12".to_string(),
-                            pin_name: "132".to_string()
+                            pin_name: "132".to_string(),
+                            span: Some(params[9].span())
                         }
                     ],
                     nail_locations: vec![NailLoc {
@@ -148,7 +177,8 @@ fn test_example_signals() {
                         tin: "-1".to_string(),
                         probe: "100T".to_string(),
                         layer: Layer::Bottom
-                    }]
+                    }],
+                    span: Some(params[7].span())
                 }
             ],
             attributes: vec![]
@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD TESTPINS and POWERPINS sections.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::testpins::*;
+
+use crate::parser::KeywordParam;
+use crate::types::{Layer, XYRef};
+
+#[test]
+fn test_example_testpins() {
+    let params = vec![
+        KeywordParam {
+            keyword: "SIGNAL",
+            parameter: "data_bus_7",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "TESTPIN",
+            parameter: "tp12 500 2500 1012 -1 100T BOTTOM",
+            line: 2,
+            offset: 19,
+        },
+        KeywordParam {
+            keyword: "TESTPIN",
+            parameter: "-1 800 3000 -1 -1 75T BOTTOM",
+            line: 3,
+            offset: 62,
+        },
+        KeywordParam {
+            keyword: "SIGNAL",
+            parameter: "ADDRESS_BUS_4",
+            line: 4,
+            offset: 100,
+        },
+        KeywordParam {
+            keyword: "TESTPIN",
+            parameter: "tp13 200 200 1013 -1 100T TOP",
+            line: 5,
+            offset: 122,
+        },
+    ];
+
+    let testpins = TestPins::new_testpins(&params).unwrap();
+
+    assert_eq!(
+        testpins,
+        TestPins {
+            signals: vec![
+                SignalPins {
+                    name: "data_bus_7".to_string(),
+                    pins: vec![
+                        TestPin {
+                            tp_name: "tp12".to_string(),
+                            xy: XYRef {
+                                x: 500.0,
+                                y: 2500.0
+                            },
+                            tan: "1012".to_string(),
+                            tin: "-1".to_string(),
+                            probe: "100T".to_string(),
+                            layer: Layer::Bottom,
+                            span: Some(params[1].span())
+                        },
+                        TestPin {
+                            tp_name: "-1".to_string(),
+                            xy: XYRef {
+                                x: 800.0,
+                                y: 3000.0
+                            },
+                            tan: "-1".to_string(),
+                            tin: "-1".to_string(),
+                            probe: "75T".to_string(),
+                            layer: Layer::Bottom,
+                            span: Some(params[2].span())
+                        }
+                    ]
+                },
+                SignalPins {
+                    name: "ADDRESS_BUS_4".to_string(),
+                    pins: vec![TestPin {
+                        tp_name: "tp13".to_string(),
+                        xy: XYRef { x: 200.0, y: 200.0 },
+                        tan: "1013".to_string(),
+                        tin: "-1".to_string(),
+                        probe: "100T".to_string(),
+                        layer: Layer::Top,
+                        span: Some(params[4].span())
+                    }]
+                }
+            ],
+            attributes: vec![]
+        }
+    );
+}
+
+#[test]
+fn test_example_powerpins() {
+    let params = vec![
+        KeywordParam {
+            keyword: "SIGNAL",
+            parameter: "VCC",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "POWERPIN",
+            parameter: "pp1 1000 1000 2001 -1 100T BOTTOM",
+            line: 2,
+            offset: 12,
+        },
+    ];
+
+    let powerpins = TestPins::new_powerpins(&params).unwrap();
+
+    assert_eq!(
+        powerpins,
+        TestPins {
+            signals: vec![SignalPins {
+                name: "VCC".to_string(),
+                pins: vec![TestPin {
+                    tp_name: "pp1".to_string(),
+                    xy: XYRef {
+                        x: 1000.0,
+                        y: 1000.0
+                    },
+                    tan: "2001".to_string(),
+                    tin: "-1".to_string(),
+                    probe: "100T".to_string(),
+                    layer: Layer::Bottom,
+                    span: Some(params[1].span())
+                }]
+            }],
+            attributes: vec![]
+        }
+    );
+
+    // TESTPIN statements are not valid in the POWERPINS section.
+    let params = vec![
+        KeywordParam {
+            keyword: "SIGNAL",
+            parameter: "VCC",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "TESTPIN",
+            parameter: "pp1 1000 1000 2001 -1 100T BOTTOM",
+            line: 2,
+            offset: 12,
+        },
+    ];
+
+    assert!(TestPins::<String>::new_powerpins(&params).is_err());
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser tests for the GenCAD TRACKS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::super::tracks::*;
+
+use crate::parser::KeywordParam;
+
+#[test]
+fn test_example_tracks() {
+    let params = vec![
+        KeywordParam {
+            keyword: "TRACK",
+            parameter: "1 10",
+            line: 1,
+            offset: 0,
+        },
+        KeywordParam {
+            keyword: "TRACK",
+            parameter: "2 12.5",
+            line: 2,
+            offset: 12,
+        },
+        KeywordParam {
+            keyword: "TRACK",
+            parameter: "power_track 50",
+            line: 3,
+            offset: 26,
+        },
+    ];
+
+    let tracks = Tracks::new(&params).unwrap();
+
+    assert_eq!(
+        tracks,
+        Tracks {
+            tracks: vec![
+                Track {
+                    name: "1".to_string(),
+                    width: 10.0,
+                    span: Some(params[0].span())
+                },
+                Track {
+                    name: "2".to_string(),
+                    width: 12.5,
+                    span: Some(params[1].span())
+                },
+                Track {
+                    name: "power_track".to_string(),
+                    width: 50.0,
+                    span: Some(params[2].span())
+                }
+            ],
+            attributes: vec![]
+        }
+    );
+}
+
+#[test]
+fn test_track_missing_width() {
+    let params = vec![KeywordParam {
+        keyword: "TRACK",
+        parameter: "1",
+        line: 1,
+        offset: 0,
+    }];
+
+    assert!(Tracks::<String>::new(&params).is_err());
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Parser for the GenCAD TRACKS section.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use nom::Parser;
+use nom::sequence::preceded;
+
+use crate::error::{Error, FieldError};
+use crate::parser::types::util::spaces;
+use crate::parser::types::{attrib_ref, track_name, track_width};
+use crate::parser::{InputStr, KeywordParam, Span};
+use crate::types::{Attribute, Number};
+
+const SECTION: &str = "TRACKS";
+
+/// A track type, defining the width of the lines drawn with it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track<S = String> {
+    /// The name of the track, as referenced by `TRACK` statements in other sections.
+    pub name: S,
+    /// The width of the track in [crate::types::Dimension] units.
+    pub width: Number,
+    /// The location of the `TRACK` statement, if the track was parsed from a file.
+    pub span: Option<Span>,
+}
+
+impl<'a, S: InputStr<'a>> Track<S> {
+    fn from_parameters(params: &'a str, span: Span) -> Result<Self, FieldError> {
+        let (_, (name, width)) = (track_name, preceded(spaces, track_width)).parse(params)?;
+
+        Ok(Self {
+            name,
+            width,
+            span: Some(span),
+        })
+    }
+}
+
+/// Represents the `TRACKS` section of a GenCAD file, defining the widths of all track types.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tracks<S = String> {
+    /// All defined track types in the section.
+    pub tracks: Vec<Track<S>>,
+    /// Additional metadata associated with the `TRACKS` section.
+    pub attributes: Vec<Attribute<S>>,
+}
+
+impl<'a, S: InputStr<'a>> Tracks<S> {
+    pub(crate) fn new(params: &[KeywordParam<'a>]) -> Result<Self, Error> {
+        let mut tracks = Vec::new();
+        let mut attributes = Vec::new();
+
+        for param in params {
+            match param.keyword {
+                "TRACK" => tracks.push(
+                    Track::from_parameters(param.parameter, param.span())
+                        .map_err(|err| param.bad_field(SECTION, err))?,
+                ),
+                "ATTRIBUTE" => {
+                    let (_, attribute) =
+                        attrib_ref(param.parameter).map_err(|err| param.bad_field(SECTION, err))?;
+                    attributes.push(attribute);
+                }
+                _ => return Err(param.unexpected(SECTION)),
+            }
+        }
+
+        Ok(Self { tracks, attributes })
+    }
+}
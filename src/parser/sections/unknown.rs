@@ -18,37 +18,37 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::parser::KeywordParam;
+use std::borrow::Cow;
+
+use crate::error::Error;
+use crate::parser::{InputStr, KeywordParam};
 
 /// A keyword/parameter pair.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Statement {
+pub struct Statement<S = String> {
     /// The keyword that determines how to interpret the parameter.
-    pub keyword: String,
+    pub keyword: S,
     /// The parameter associated with the keyword.
-    pub parameter: String,
+    pub parameter: S,
 }
 
 /// Represents an unknown section in a GenCAD file.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Unknown {
+pub struct Unknown<S = String> {
     /// The section name.
-    pub name: String,
+    pub name: S,
     /// The list of statements in this section.
-    pub statements: Vec<Statement>,
+    pub statements: Vec<Statement<S>>,
 }
 
-impl Unknown {
-    pub(crate) fn new(
-        name: &str,
-        params: &[KeywordParam],
-    ) -> Result<Self, Box<dyn std::error::Error>> {
-        let name = name.to_string();
+impl<'a, S: InputStr<'a>> Unknown<S> {
+    pub(crate) fn new(name: &'a str, params: &[KeywordParam<'a>]) -> Result<Self, Error> {
+        let name = S::from(Cow::Borrowed(name));
         let statements = params
             .iter()
             .map(|kp| Statement {
-                keyword: kp.keyword.to_string(),
-                parameter: kp.parameter.to_string(),
+                keyword: S::from(Cow::Borrowed(kp.keyword)),
+                parameter: S::from(Cow::Borrowed(kp.parameter)),
             })
             .collect();
         Ok(Unknown { name, statements })
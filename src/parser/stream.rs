@@ -0,0 +1,587 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Streaming readers for GenCAD files.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::Error;
+use crate::types::Attribute;
+
+use super::sections::artworks::Artwork;
+use super::sections::board::Board;
+use super::sections::changes::Change;
+use super::sections::components::Component;
+use super::sections::devices::Device;
+use super::sections::header::Header;
+use super::sections::layers::Layers;
+use super::sections::mech::Mechanical;
+use super::sections::pads::Pad;
+use super::sections::padstacks::Padstack;
+use super::sections::pseudos::Pseudo;
+use super::sections::routes::Route;
+use super::sections::shapes::Shape;
+use super::sections::signals::Signal;
+use super::sections::testpins::TestPins;
+use super::sections::tracks::Track;
+use super::sections::unknown::Unknown;
+use super::{
+    KeywordParam, NumberText, ParseOptions, ParsedSection, Section, parse_section, section_end,
+    section_start,
+};
+
+/// A statement read from a stream, owning its text.
+struct Statement {
+    keyword: String,
+    parameter: String,
+    line: usize,
+    offset: usize,
+}
+
+impl Statement {
+    fn as_param(&self) -> KeywordParam<'_> {
+        KeywordParam {
+            keyword: &self.keyword,
+            parameter: &self.parameter,
+            line: self.line,
+            offset: self.offset,
+        }
+    }
+}
+
+/// A piece of the file's structure found by the [Lexer].
+enum Token {
+    /// The start of the named section.
+    Start(String),
+    /// A statement inside the current section.
+    Statement(Statement),
+    /// The end of the current section, on the given line.
+    End(usize),
+}
+
+/// Splits a stream into sections and their statements one line at a time.
+///
+/// Every [Token::Start] is followed by the section's statements and then a [Token::End], even if
+/// the section is never closed.
+struct Lexer<R> {
+    reader: R,
+    options: ParseOptions,
+    diagnostics: Vec<Diagnostic>,
+    /// The line being lexed, including its line ending.
+    buf: Vec<u8>,
+    /// Whether `buf` has to be lexed again as the start of the next section.
+    pending: bool,
+    /// The number of lines read so far.
+    line: usize,
+    /// The byte offset of the start of `buf`.
+    offset: usize,
+    /// Whether the last line read ended with a newline.
+    newline: bool,
+    /// The name and starting line of the section being read.
+    section: Option<(String, usize)>,
+    /// Whether a section has been found yet.
+    started: bool,
+}
+
+impl<R: BufRead> Lexer<R> {
+    fn new(reader: R, options: &ParseOptions) -> Self {
+        Self {
+            reader,
+            // The text of the numbers can't be returned with the sections.
+            options: ParseOptions {
+                keep_number_text: false,
+                ..options.clone()
+            },
+            diagnostics: Vec::new(),
+            buf: Vec::new(),
+            pending: false,
+            line: 0,
+            offset: 0,
+            newline: true,
+            section: None,
+            started: false,
+        }
+    }
+
+    /// Reads the next non-blank line into `buf`, returning `false` at the end of the input.
+    fn read_line(&mut self) -> Result<bool, Error> {
+        if self.pending {
+            self.pending = false;
+            return Ok(true);
+        }
+        loop {
+            self.offset += self.buf.len();
+            self.buf.clear();
+            if self.reader.read_until(b'\n', &mut self.buf)? == 0 {
+                return Ok(false);
+            }
+            self.line += 1;
+            self.newline = self.buf.ends_with(b"\n");
+            if self.buf.iter().any(|&c| c != b'\r' && c != b'\n') {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Returns the line number of the end of the input.
+    fn eof_line(&self) -> usize {
+        if self.newline {
+            self.line + 1
+        } else {
+            self.line
+        }
+    }
+
+    /// Records `err` as a diagnostic in recover mode, or returns it otherwise.
+    fn report(&mut self, err: Error) -> Result<(), Error> {
+        if self.options.recover {
+            self.diagnostics.push(err.into());
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>, Error> {
+        loop {
+            if !self.read_line()? {
+                if let Some((section, line)) = self.section.take() {
+                    self.report(Error::UnterminatedSection { section, line })?;
+                    return Ok(Some(Token::End(self.eof_line())));
+                }
+                if !self.started && !self.options.recover {
+                    self.started = true;
+                    return Err(Error::Syntax {
+                        line: self.eof_line(),
+                        column: 1,
+                    });
+                }
+                return Ok(None);
+            }
+
+            let Some((_, start_line)) = self.section else {
+                match section_start(&self.buf) {
+                    Ok((_, name)) => {
+                        let name = name.to_string();
+                        self.section = Some((name.clone(), self.line));
+                        self.started = true;
+                        return Ok(Some(Token::Start(name)));
+                    }
+                    Err(_) => {
+                        self.report(Error::Syntax {
+                            line: self.line,
+                            column: 1,
+                        })?;
+                        continue;
+                    }
+                }
+            };
+
+            if let Ok((_, end)) = section_end(&self.buf) {
+                let end = end.to_string();
+                if let Some((section, _)) = self.section.take()
+                    && section != end
+                {
+                    self.report(Error::MismatchedEnd {
+                        section,
+                        end,
+                        line: self.line,
+                    })?;
+                }
+                return Ok(Some(Token::End(self.line)));
+            }
+
+            match KeywordParam::parse(&self.buf) {
+                Ok((_, kp)) => {
+                    return Ok(Some(Token::Statement(Statement {
+                        keyword: kp.keyword.to_string(),
+                        parameter: kp.parameter.to_string(),
+                        line: self.line,
+                        offset: self.offset,
+                    })));
+                }
+                Err(_) if section_start(&self.buf).is_ok() => {
+                    let (section, _) = self.section.take().unwrap_or_default();
+                    self.report(Error::UnterminatedSection {
+                        section,
+                        line: start_line,
+                    })?;
+                    self.pending = true;
+                    return Ok(Some(Token::End(self.line)));
+                }
+                Err(err) => {
+                    let rest = match err {
+                        nom::Err::Error(e) | nom::Err::Failure(e) => e.input.len(),
+                        nom::Err::Incomplete(_) => self.buf.len(),
+                    };
+                    self.report(Error::Syntax {
+                        line: self.line,
+                        column: self.buf.len() - rest + 1,
+                    })?;
+                }
+            }
+        }
+    }
+
+    /// Parses the statements of a section, returning `None` if it had to be skipped.
+    fn parse(
+        &mut self,
+        name: &str,
+        statements: &[&Statement],
+        end_line: usize,
+    ) -> Result<Option<ParsedSection>, Error> {
+        let section = Section {
+            name,
+            parameters: statements.iter().map(|st| st.as_param()).collect(),
+            end_line,
+        };
+        parse_section(
+            section,
+            &self.options,
+            &mut self.diagnostics,
+            &mut NumberText::default(),
+        )
+    }
+}
+
+/// Reads a GenCAD file one section at a time, without holding the whole file in memory.
+///
+/// Yields the same sections as [super::ParsedGencadFile::with_options], in file order. After an
+/// error is returned, the reader yields nothing more.
+pub struct SectionReader<R> {
+    lexer: Lexer<R>,
+    done: bool,
+}
+
+impl<R: BufRead> SectionReader<R> {
+    /// Creates a reader over the file data.
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, &ParseOptions::default())
+    }
+
+    /// Creates a reader over the file data using the given options.
+    pub fn with_options(reader: R, options: &ParseOptions) -> Self {
+        Self {
+            lexer: Lexer::new(reader, options),
+            done: false,
+        }
+    }
+
+    /// Removes and returns the diagnostics found so far.
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.lexer.diagnostics)
+    }
+
+    fn next_section(&mut self) -> Result<Option<ParsedSection>, Error> {
+        while let Some(token) = self.lexer.next_token()? {
+            let Token::Start(name) = token else {
+                continue;
+            };
+            let mut statements = Vec::new();
+            let end_line = loop {
+                match self.lexer.next_token()? {
+                    Some(Token::Statement(st)) => statements.push(st),
+                    Some(Token::End(line)) => break line,
+                    Some(Token::Start(_)) | None => unreachable!("sections always end"),
+                }
+            };
+            let statements: Vec<_> = statements.iter().collect();
+            if let Some(parsed) = self.lexer.parse(&name, &statements, end_line)? {
+                return Ok(Some(parsed));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<R: BufRead> Iterator for SectionReader<R> {
+    type Item = Result<ParsedSection, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.next_section().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
+/// A single record of a GenCAD file.
+///
+/// Sections made of records, like `COMPONENTS` or `SIGNALS`, are split into one record per
+/// component, signal, etc. Other sections, like `HEADER`, are a single record.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    Header(Header),
+    Board(Board),
+    Pad(Pad),
+    Padstack(Padstack),
+    Shape(Shape),
+    Component(Component),
+    Device(Device),
+    Signal(Signal),
+    Route(Route),
+    Track(Track),
+    Layers(Layers),
+    Artwork(Artwork),
+    Mechanical(Mechanical),
+    Testpins(TestPins),
+    Powerpins(TestPins),
+    Pseudo(Pseudo),
+    Change(Change),
+    Unknown(Unknown),
+    /// An attribute of a whole section that is split into records.
+    Attribute {
+        /// The name of the section the attribute belongs to.
+        section: String,
+        /// The attribute.
+        attribute: Attribute,
+    },
+}
+
+/// Returns the keywords that start a new record in a section that is read record by record.
+fn record_keywords(section: &str) -> &'static [&'static str] {
+    match section {
+        "PADS" => &["PAD"],
+        "PADSTACKS" => &["PADSTACK"],
+        "SHAPES" => &["SHAPE"],
+        "COMPONENTS" => &["COMPONENT"],
+        "DEVICES" => &["DEVICE"],
+        "SIGNALS" => &["SIGNAL"],
+        "ROUTES" => &["ROUTE"],
+        "TRACKS" => &["TRACK"],
+        "ARTWORKS" => &["ARTWORK"],
+        "MECH" => &["MECHANICAL"],
+        "PSEUDOS" => &["PSEUDO"],
+        "CHANGES" => &["CHANGE"],
+        _ => &[],
+    }
+}
+
+/// Reads a GenCAD file one record at a time, so that only a single component, signal, etc. is
+/// held in memory.
+///
+/// After an error is returned, the reader yields nothing more.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// use gencad::parser::{Record, RecordReader};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let reader = BufReader::new(File::open("example.cad")?);
+///
+///     for record in RecordReader::new(reader) {
+///         if let Record::Component(component) = record? {
+///             println!("{} at {:?}", component.name, component.place);
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct RecordReader<R> {
+    lexer: Lexer<R>,
+    /// The name of the section being read.
+    section: String,
+    /// The statements of the record being read.
+    record: Vec<Statement>,
+    /// Statements that apply to every record of the section, i.e., the default `INSERT` of the
+    /// `SHAPES` section.
+    context: Vec<Statement>,
+    /// Records that have been parsed but not yet returned.
+    queue: VecDeque<Record>,
+    done: bool,
+}
+
+impl<R: BufRead> RecordReader<R> {
+    /// Creates a reader over the file data.
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, &ParseOptions::default())
+    }
+
+    /// Creates a reader over the file data using the given options.
+    pub fn with_options(reader: R, options: &ParseOptions) -> Self {
+        Self {
+            lexer: Lexer::new(reader, options),
+            section: String::new(),
+            record: Vec::new(),
+            context: Vec::new(),
+            queue: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Removes and returns the diagnostics found so far.
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.lexer.diagnostics)
+    }
+
+    fn next_record(&mut self) -> Result<Option<Record>, Error> {
+        loop {
+            if let Some(record) = self.queue.pop_front() {
+                return Ok(Some(record));
+            }
+            match self.lexer.next_token()? {
+                Some(Token::Start(name)) => {
+                    self.section = name;
+                    self.context.clear();
+                }
+                Some(Token::Statement(st)) => {
+                    if record_keywords(&self.section).contains(&st.keyword.as_str())
+                        && !self.record.is_empty()
+                    {
+                        self.flush(st.line)?;
+                    }
+                    self.record.push(st);
+                }
+                Some(Token::End(line)) => self.flush(line)?,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Parses the statements read so far and queues the resulting records.
+    fn flush(&mut self, end_line: usize) -> Result<(), Error> {
+        let record = std::mem::take(&mut self.record);
+        let keywords = record_keywords(&self.section);
+        let starts_record = record
+            .first()
+            .is_some_and(|st| keywords.contains(&st.keyword.as_str()));
+
+        let statements: Vec<_> = if starts_record {
+            self.context.iter().chain(&record).collect()
+        } else {
+            record.iter().collect()
+        };
+        let parsed = self.lexer.parse(&self.section, &statements, end_line)?;
+
+        if !starts_record && self.section == "SHAPES" {
+            self.context = record
+                .into_iter()
+                .filter(|st| st.keyword == "INSERT")
+                .collect();
+        }
+
+        if let Some(parsed) = parsed {
+            self.push(parsed);
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, parsed: ParsedSection) {
+        let queue = &mut self.queue;
+        let attributes = match parsed {
+            ParsedSection::Header(header) => {
+                queue.push_back(Record::Header(header));
+                Vec::new()
+            }
+            ParsedSection::Board(board) => {
+                queue.push_back(Record::Board(board));
+                Vec::new()
+            }
+            ParsedSection::Pads(pads) => {
+                queue.extend(pads.into_iter().map(Record::Pad));
+                Vec::new()
+            }
+            ParsedSection::Padstacks(padstacks) => {
+                queue.extend(padstacks.padstacks.into_iter().map(Record::Padstack));
+                padstacks.attributes
+            }
+            ParsedSection::Shapes(shapes) => {
+                queue.extend(shapes.into_iter().map(Record::Shape));
+                Vec::new()
+            }
+            ParsedSection::Components(components) => {
+                queue.extend(components.into_iter().map(Record::Component));
+                Vec::new()
+            }
+            ParsedSection::Devices(devices) => {
+                queue.extend(devices.into_iter().map(Record::Device));
+                Vec::new()
+            }
+            ParsedSection::Signals(signals) => {
+                queue.extend(signals.signals.into_iter().map(Record::Signal));
+                signals.attributes
+            }
+            ParsedSection::Routes(routes) => {
+                queue.extend(routes.into_iter().map(Record::Route));
+                Vec::new()
+            }
+            ParsedSection::Tracks(tracks) => {
+                queue.extend(tracks.tracks.into_iter().map(Record::Track));
+                tracks.attributes
+            }
+            ParsedSection::Layers(layers) => {
+                queue.push_back(Record::Layers(layers));
+                Vec::new()
+            }
+            ParsedSection::Artworks(artworks) => {
+                queue.extend(artworks.into_iter().map(Record::Artwork));
+                Vec::new()
+            }
+            ParsedSection::Mech(mechanicals) => {
+                queue.extend(mechanicals.into_iter().map(Record::Mechanical));
+                Vec::new()
+            }
+            ParsedSection::Testpins(testpins) => {
+                queue.push_back(Record::Testpins(testpins));
+                Vec::new()
+            }
+            ParsedSection::Powerpins(powerpins) => {
+                queue.push_back(Record::Powerpins(powerpins));
+                Vec::new()
+            }
+            ParsedSection::Pseudos(pseudos) => {
+                queue.extend(pseudos.into_iter().map(Record::Pseudo));
+                Vec::new()
+            }
+            ParsedSection::Changes(changes) => {
+                queue.extend(changes.changes.into_iter().map(Record::Change));
+                changes.attributes
+            }
+            ParsedSection::Unknown(unknown) => {
+                queue.push_back(Record::Unknown(unknown));
+                Vec::new()
+            }
+        };
+        queue.extend(attributes.into_iter().map(|attribute| Record::Attribute {
+            section: self.section.clone(),
+            attribute,
+        }));
+    }
+}
+
+impl<R: BufRead> Iterator for RecordReader<R> {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.next_record().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
@@ -18,6 +18,8 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::borrow::Cow;
+
 use nom::IResult;
 
 use super::{number, string};
@@ -27,7 +29,7 @@ use crate::types::Number;
 macro_rules! string_alias {
     ($name:ident) => {
         /// Alias of [string].
-        pub fn $name(s: &str) -> IResult<&str, String> {
+        pub fn $name<'a, S: From<Cow<'a, str>>>(s: &'a str) -> IResult<&'a str, S> {
             string(s)
         }
     };
@@ -68,7 +70,7 @@ string_alias!(sig_name);
 
 string_alias!(tan);
 
-//string_alias!(testpad_name);
+string_alias!(testpad_name);
 
 string_alias!(tin);
 
@@ -76,6 +78,6 @@ string_alias!(tp_name);
 
 string_alias!(track_name);
 
-//number_alias!(track_width);
+number_alias!(track_width);
 
-//string_alias!(via_name);
+string_alias!(via_name);
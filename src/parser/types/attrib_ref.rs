@@ -18,6 +18,8 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::borrow::Cow;
+
 use nom::combinator::map;
 use nom::sequence::preceded;
 use nom::{IResult, Parser};
@@ -27,8 +29,8 @@ use super::util::spaces;
 
 use crate::types::Attribute;
 
-impl Attribute {
-    fn new(v: (String, String, String)) -> Self {
+impl<S> Attribute<S> {
+    fn new(v: (S, S, S)) -> Self {
         let (category, name, data) = v;
         Self {
             category,
@@ -38,7 +40,7 @@ impl Attribute {
     }
 }
 
-pub fn attrib_ref(s: &str) -> IResult<&str, Attribute> {
+pub fn attrib_ref<'a, S: From<Cow<'a, str>>>(s: &'a str) -> IResult<&'a str, Attribute<S>> {
     map(
         (string, preceded(spaces, string), preceded(spaces, string)),
         Attribute::new,
@@ -18,6 +18,8 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::borrow::Cow;
+
 use nom::branch::alt;
 use nom::bytes::complete::{take_while, take_while1};
 use nom::character::complete::char;
@@ -65,17 +67,24 @@ fn quoted_string_inner_fragment<'a>(s: &'a str) -> IResult<&'a str, QuotedString
     .parse(s)
 }
 
-fn quoted_string(s: &str) -> IResult<&str, String> {
+fn quoted_string(s: &str) -> IResult<&str, Cow<'_, str>> {
     let build_string = many0(quoted_string_inner_fragment).map(|fragments| {
-        fragments
-            .into_iter()
-            .fold(String::new(), |mut string, fragment| {
-                match fragment {
-                    QuotedStringFragment::Literal(s) => string.push_str(s),
-                    QuotedStringFragment::Char(c) => string.push(c),
-                }
-                string
-            })
+        // Only strings with backslashes need to be rebuilt, the rest can be borrowed as-is.
+        match fragments.as_slice() {
+            [] => Cow::Borrowed(""),
+            [QuotedStringFragment::Literal(s)] => Cow::Borrowed(*s),
+            _ => Cow::Owned(
+                fragments
+                    .into_iter()
+                    .fold(String::new(), |mut string, fragment| {
+                        match fragment {
+                            QuotedStringFragment::Literal(s) => string.push_str(s),
+                            QuotedStringFragment::Char(c) => string.push(c),
+                        }
+                        string
+                    }),
+            ),
+        }
     });
     delimited(char('"'), build_string, char('"')).parse(s)
 }
@@ -88,15 +97,20 @@ fn unquoted_string(s: &str) -> IResult<&str, &str> {
     .parse(s)
 }
 
-pub fn string(s: &str) -> IResult<&str, String> {
-    alt((map(unquoted_string, |x| x.to_string()), quoted_string)).parse(s)
+/// Parses a string, borrowing it from the input unless it contains backslashes.
+pub fn string_cow(s: &str) -> IResult<&str, Cow<'_, str>> {
+    alt((map(unquoted_string, Cow::Borrowed), quoted_string)).parse(s)
+}
+
+pub fn string<'a, S: From<Cow<'a, str>>>(s: &'a str) -> IResult<&'a str, S> {
+    map(string_cow, S::from).parse(s)
 }
 
-pub fn part_name(s: &str) -> IResult<&str, String> {
+pub fn part_name<'a, S: From<Cow<'a, str>>>(s: &'a str) -> IResult<&'a str, S> {
     let (remaining, part) = string(s)?;
     if !remaining.is_empty() {
         // Sometimes the part name is an unquoted string containing spaces, so it doesn't get parsed completely.
-        return Ok(("", s.to_string()));
+        return Ok(("", S::from(Cow::Borrowed(s))));
     }
 
     // A properly parsed part name.
@@ -144,17 +158,24 @@ mod tests {
 
     #[test]
     fn test_quoted_string() {
-        assert_eq!(quoted_string(r#""""#), Ok(("", "".to_string())));
-        assert_eq!(quoted_string(r#""A""#), Ok(("", "A".to_string())));
+        assert_eq!(quoted_string(r#""""#), Ok(("", "".into())));
+        assert_eq!(quoted_string(r#""A""#), Ok(("", "A".into())));
         assert_eq!(
             quoted_string(r#""ABCD EFGH""#),
-            Ok(("", "ABCD EFGH".to_string()))
+            Ok(("", "ABCD EFGH".into()))
         );
-        assert_eq!(quoted_string(r#""A\"""#), Ok(("", "A\"".to_string())));
+        assert_eq!(quoted_string(r#""A\"""#), Ok(("", "A\"".into())));
         assert_eq!(
             quoted_string(r#""ABCD EFGH \IJKL\ \"MNOP\" QRST WXYZ""#),
-            Ok(("", "ABCD EFGH \\IJKL\\ \"MNOP\" QRST WXYZ".to_string()))
+            Ok(("", "ABCD EFGH \\IJKL\\ \"MNOP\" QRST WXYZ".into()))
         );
+
+        // Only strings with backslashes are copied out of the input.
+        assert!(matches!(
+            quoted_string(r#""ABCD EFGH""#),
+            Ok((_, Cow::Borrowed("ABCD EFGH")))
+        ));
+        assert!(matches!(quoted_string(r#""A\"""#), Ok((_, Cow::Owned(_)))));
     }
 
     #[test]
@@ -61,3 +61,12 @@ fn test_errors() {
     assert!(number("infinity").is_err());
     assert!(number("inf").is_err());
 }
+
+#[test]
+fn test_precision() {
+    // Every digit that fits in a double is kept.
+    assert_eq!(number("9.52527"), Ok(("", 9.52527)));
+    assert_eq!(number("123456789.123"), Ok(("", 123456789.123)));
+    assert_eq!(number("0.1234567890123456"), Ok(("", 0.1234567890123456)));
+    assert_ne!(number("16777217").unwrap().1, number("16777216").unwrap().1);
+}
@@ -18,6 +18,8 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::borrow::Cow;
+
 use nom::combinator::map;
 use nom::sequence::preceded;
 use nom::{IResult, Parser};
@@ -27,8 +29,8 @@ use super::{layer, mirror, number, rectangle_ref, rot, string};
 
 use crate::types::{Layer, Mirror, Number, RectangleRef, TextPar};
 
-impl TextPar {
-    fn new(v: (Number, Number, Mirror, Layer, String, RectangleRef)) -> Self {
+impl<S> TextPar<S> {
+    fn new(v: (Number, Number, Mirror, Layer, S, RectangleRef)) -> Self {
         let (text_size, rotation, mirror, layer, text, area) = v;
         Self {
             text_size,
@@ -41,7 +43,7 @@ impl TextPar {
     }
 }
 
-pub fn text_par(s: &str) -> IResult<&str, TextPar> {
+pub fn text_par<'a, S: From<Cow<'a, str>>>(s: &'a str) -> IResult<&'a str, TextPar<S>> {
     map(
         (
             number,
@@ -0,0 +1,504 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Tessellation of GenCAD geometry into polylines.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+/*!
+ * # `tessellate` Module
+ *
+ * This module approximates lines, arcs, circles and rectangles with lists of
+ * points, for backends that can only draw straight lines and for polygon math.
+ *
+ * GenCAD arcs run counterclockwise from their start point to their end point,
+ * and an arc whose start and end points are the same is a full circle. The
+ * major axis of an elliptical arc lies along the x-axis; [elliptical_arc]
+ * tessellates ellipses that are rotated from that. Each segment of a curve
+ * strays from it by no more than the tolerance given, which is in the units of
+ * the file.
+ *
+ * Points are in the coordinates of the feature they belong to. Mapping them
+ * with a [Transform] places them on the board,
+ * and a mirrored transform turns the arcs clockwise as it should.
+ *
+ * ## Usage Example
+ *
+ * ```no_run
+ * use std::fs::File;
+ * use std::io::BufReader;
+ *
+ * use gencad::interpreter::InterpretedGencadFile;
+ * use gencad::parser::ParsedGencadFile;
+ * use gencad::tessellate::Tessellate;
+ *
+ * fn main() -> Result<(), Box<dyn std::error::Error>> {
+ *     let parsed = ParsedGencadFile::new(BufReader::new(File::open("example.cad")?))?;
+ *     let interpreted = InterpretedGencadFile::new(parsed)?;
+ *
+ *     if let Some(board) = &interpreted.board {
+ *         for shape in &board.outline_shapes {
+ *             println!("{:?}", shape.tessellate(0.5));
+ *         }
+ *     }
+ *
+ *     Ok(())
+ * }
+ * ```
+ */
+
+use std::f64::consts::{PI, TAU};
+
+use crate::parser::sections::board::{ArtworkComponent, BoardShape};
+use crate::parser::sections::pads::PadShape;
+use crate::parser::sections::routes::RouteElement;
+use crate::parser::sections::shapes::ShapeElement;
+use crate::transform::Transform;
+use crate::types::{
+    ArcRef, CircleRef, CircularArcRef, EllipticalArcRef, LineRef, Mirror, Number, RectangleRef,
+    XYRef,
+};
+
+/// Geometry that can be approximated by a list of points.
+pub trait Tessellate {
+    /// Returns points that, joined by straight lines in order, are within `tolerance` of the
+    /// geometry.
+    ///
+    /// Closed shapes end with their first point again. Items that aren't geometry, like a
+    /// `LAYER` statement, have no points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` isn't greater than zero and the geometry is curved.
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef>;
+}
+
+impl Tessellate for XYRef {
+    fn tessellate(&self, _tolerance: Number) -> Vec<XYRef> {
+        vec![*self]
+    }
+}
+
+impl Tessellate for LineRef {
+    fn tessellate(&self, _tolerance: Number) -> Vec<XYRef> {
+        vec![self.start, self.end]
+    }
+}
+
+impl Tessellate for CircularArcRef {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        let (start_radius, start_angle) = polar(self.start, self.center);
+        let (end_radius, end_angle) = polar(self.end, self.center);
+        let Some(sweep) = sweep(self.start, self.end, start_angle, end_angle) else {
+            return vec![self.start, self.end];
+        };
+
+        // The radius is blended from the start to the end, so that the points join up with both
+        // even if they aren't quite the same distance from the center.
+        let steps = steps(sweep, start_radius.max(end_radius), tolerance);
+        let mut points = Vec::with_capacity(steps + 1);
+        points.push(self.start);
+        for i in 1..steps {
+            let t = i as Number / steps as Number;
+            let radius = start_radius + (end_radius - start_radius) * t;
+            let angle = start_angle + sweep * t;
+            points.push(XYRef {
+                x: self.center.x + radius * angle.cos(),
+                y: self.center.y + radius * angle.sin(),
+            });
+        }
+        points.push(self.end);
+        points
+    }
+}
+
+impl Tessellate for EllipticalArcRef {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        elliptical_arc(self, 0.0, tolerance)
+    }
+}
+
+impl Tessellate for ArcRef {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        match self {
+            Self::Circular(arc) => arc.tessellate(tolerance),
+            Self::Elliptical(arc) => arc.tessellate(tolerance),
+        }
+    }
+}
+
+impl Tessellate for CircleRef {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        let start = XYRef {
+            x: self.center.x + self.radius,
+            y: self.center.y,
+        };
+        CircularArcRef {
+            start,
+            end: start,
+            center: self.center,
+        }
+        .tessellate(tolerance)
+    }
+}
+
+impl Tessellate for RectangleRef {
+    fn tessellate(&self, _tolerance: Number) -> Vec<XYRef> {
+        let XYRef { x, y } = self.origin;
+        vec![
+            self.origin,
+            XYRef { x: x + self.x, y },
+            XYRef {
+                x: x + self.x,
+                y: y + self.y,
+            },
+            XYRef { x, y: y + self.y },
+            self.origin,
+        ]
+    }
+}
+
+impl Tessellate for BoardShape {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        match self {
+            Self::Line(line) => line.tessellate(tolerance),
+            Self::Arc(arc) => arc.tessellate(tolerance),
+            Self::Circle(circle) => circle.tessellate(tolerance),
+            Self::Rectangle(rectangle) => rectangle.tessellate(tolerance),
+        }
+    }
+}
+
+impl Tessellate for PadShape {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        match self {
+            Self::Line(line) => line.tessellate(tolerance),
+            Self::Arc(arc) => arc.tessellate(tolerance),
+            Self::Circle(circle) => circle.tessellate(tolerance),
+            Self::Rectangle(rectangle) => rectangle.tessellate(tolerance),
+        }
+    }
+}
+
+impl Tessellate for ShapeElement {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        match self {
+            Self::Line(line) => line.tessellate(tolerance),
+            Self::Arc(arc) => arc.tessellate(tolerance),
+            Self::Circle(circle) => circle.tessellate(tolerance),
+            Self::Rectangle(rectangle) => rectangle.tessellate(tolerance),
+            Self::Fiducial(xy) => xy.tessellate(tolerance),
+        }
+    }
+}
+
+impl<S> Tessellate for ArtworkComponent<S> {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        match self {
+            Self::Line(line) => line.tessellate(tolerance),
+            Self::Arc(arc) => arc.tessellate(tolerance),
+            Self::Circle(circle) => circle.tessellate(tolerance),
+            Self::Rectangle(rectangle) => rectangle.tessellate(tolerance),
+            Self::Track(_) | Self::Layer(_) | Self::Filled(_) | Self::Text(_) => Vec::new(),
+        }
+    }
+}
+
+impl<S> Tessellate for RouteElement<S> {
+    fn tessellate(&self, tolerance: Number) -> Vec<XYRef> {
+        match self {
+            Self::Line(line) => line.tessellate(tolerance),
+            Self::Arc(arc) => arc.tessellate(tolerance),
+            Self::Circle(circle) => circle.tessellate(tolerance),
+            Self::Rectangle(rectangle) => rectangle.tessellate(tolerance),
+            Self::Via(via) => via.xy.tessellate(tolerance),
+            Self::TestPad(testpad) => testpad.xy.tessellate(tolerance),
+            Self::Track(_) | Self::Layer(_) | Self::Filled(_) | Self::Text(_) => Vec::new(),
+        }
+    }
+}
+
+/// Tessellates an elliptical arc whose major axis is rotated counterclockwise from the x-axis by
+/// `rotation` degrees.
+///
+/// The start and end points are projected onto the ellipse to find where the arc starts and ends
+/// on it, and are kept as the first and last points.
+///
+/// # Panics
+///
+/// Panics if `tolerance` isn't greater than zero.
+pub fn elliptical_arc(arc: &EllipticalArcRef, rotation: Number, tolerance: Number) -> Vec<XYRef> {
+    let (a, b) = (arc.major_radius, arc.minor_radius);
+    let to_arc = Transform::new(arc.center, rotation, Mirror::Not);
+    let to_ellipse = Transform::new(XYRef { x: 0.0, y: 0.0 }, -rotation, Mirror::Not);
+
+    // The angles are of the points on the circle that the ellipse is squashed from.
+    let parameter = |xy: XYRef| {
+        let offset = to_ellipse.apply(XYRef {
+            x: xy.x - arc.center.x,
+            y: xy.y - arc.center.y,
+        });
+        (offset.y / b).atan2(offset.x / a)
+    };
+    let start_angle = parameter(arc.start);
+    let end_angle = parameter(arc.end);
+    let Some(sweep) = sweep(arc.start, arc.end, start_angle, end_angle) else {
+        return vec![arc.start, arc.end];
+    };
+
+    // Within a step of the angle, the ellipse strays from its chord by no more than a circle of
+    // the major radius would.
+    let steps = steps(sweep, a.abs().max(b.abs()), tolerance);
+    let mut points = Vec::with_capacity(steps + 1);
+    points.push(arc.start);
+    for i in 1..steps {
+        let angle = start_angle + sweep * (i as Number / steps as Number);
+        points.push(to_arc.apply(XYRef {
+            x: a * angle.cos(),
+            y: b * angle.sin(),
+        }));
+    }
+    points.push(arc.end);
+    points
+}
+
+/// Returns the distance and angle in radians of a point from a center.
+fn polar(xy: XYRef, center: XYRef) -> (Number, Number) {
+    let (dx, dy) = (xy.x - center.x, xy.y - center.y);
+    (dx.hypot(dy), dy.atan2(dx))
+}
+
+/// Returns the counterclockwise angle in radians from the start of an arc to its end, or `None`
+/// if the arc is just a straight line between two points in the same direction from its center.
+fn sweep(start: XYRef, end: XYRef, start_angle: Number, end_angle: Number) -> Option<Number> {
+    if start == end {
+        return Some(TAU);
+    }
+    let sweep = (end_angle - start_angle).rem_euclid(TAU);
+    (sweep > 0.0).then_some(sweep)
+}
+
+/// Returns how many straight segments an arc of a circle needs for none of them to stray from
+/// it by more than `tolerance`.
+///
+/// Every full turn has at least three segments, so that circles stay polygons however large the
+/// tolerance is.
+fn steps(sweep: Number, radius: Number, tolerance: Number) -> usize {
+    assert!(tolerance > 0.0, "tolerance must be greater than zero");
+    // A chord spanning an angle strays from the arc by `radius * (1 - cos(angle / 2))`.
+    let max_step = (2.0 * (1.0 - (tolerance / radius).min(1.0)).acos()).min(2.0 * PI / 3.0);
+    (sweep / max_step).ceil().max(1.0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xy(x: Number, y: Number) -> XYRef {
+        XYRef { x, y }
+    }
+
+    fn assert_near(actual: XYRef, expected: XYRef) {
+        assert!(
+            (actual.x - expected.x).abs() < 1e-9 && (actual.y - expected.y).abs() < 1e-9,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    /// Returns the largest distance from the midpoint of a segment to the circle.
+    fn max_error(points: &[XYRef], center: XYRef, radius: Number) -> Number {
+        points
+            .windows(2)
+            .map(|segment| {
+                let mid = xy(
+                    (segment[0].x + segment[1].x) / 2.0,
+                    (segment[0].y + segment[1].y) / 2.0,
+                );
+                radius - polar(mid, center).0
+            })
+            .fold(0.0, Number::max)
+    }
+
+    #[test]
+    fn test_circular_arc() {
+        // A quarter turn counterclockwise.
+        let arc = CircularArcRef {
+            start: xy(10.0, 0.0),
+            end: xy(0.0, 10.0),
+            center: xy(0.0, 0.0),
+        };
+        let points = arc.tessellate(0.01);
+        assert_eq!(points.first(), Some(&arc.start));
+        assert_eq!(points.last(), Some(&arc.end));
+        assert!(points.iter().all(|p| p.x >= 0.0 && p.y >= 0.0));
+        assert!(max_error(&points, arc.center, 10.0) <= 0.01);
+
+        // Swapping the ends goes the long way around, still counterclockwise.
+        let reversed = CircularArcRef {
+            start: arc.end,
+            end: arc.start,
+            center: arc.center,
+        };
+        let points = reversed.tessellate(0.01);
+        assert!(points.iter().any(|p| p.x < -9.0));
+        assert!(points.iter().any(|p| p.y < -9.0));
+        assert!(max_error(&points, arc.center, 10.0) <= 0.01);
+
+        // A tighter tolerance takes more points.
+        assert!(arc.tessellate(0.001).len() > arc.tessellate(0.01).len());
+        // And a loose one still takes at least one segment per third of a turn.
+        assert_eq!(arc.tessellate(100.0).len(), 2);
+        assert_eq!(reversed.tessellate(100.0).len(), 4);
+    }
+
+    #[test]
+    fn test_full_circle() {
+        let arc = CircularArcRef {
+            start: xy(5.0, 2.0),
+            end: xy(5.0, 2.0),
+            center: xy(2.0, 2.0),
+        };
+        let points = arc.tessellate(0.1);
+        assert_eq!(points.first(), points.last());
+        assert!(points.iter().any(|p| p.x < -0.9));
+        assert!(max_error(&points, arc.center, 3.0) <= 0.1);
+
+        let circle = CircleRef {
+            center: xy(2.0, 2.0),
+            radius: 3.0,
+        };
+        assert_eq!(circle.tessellate(0.1), points);
+        assert_eq!(circle.tessellate(100.0).len(), 4);
+    }
+
+    #[test]
+    fn test_elliptical_arc() {
+        // Half of an ellipse, from the end of its major axis to the other.
+        let arc = EllipticalArcRef {
+            start: xy(4.0, 0.0),
+            end: xy(-4.0, 0.0),
+            center: xy(0.0, 0.0),
+            major_radius: 4.0,
+            minor_radius: 2.0,
+        };
+        let points = arc.tessellate(0.01);
+        assert_eq!(points.first(), Some(&arc.start));
+        assert_eq!(points.last(), Some(&arc.end));
+        for p in &points {
+            assert!(p.y >= 0.0);
+            assert!(((p.x / 4.0).powi(2) + (p.y / 2.0).powi(2) - 1.0).abs() < 1e-9);
+        }
+        assert!(points.iter().any(|p| (p.y - 2.0).abs() < 0.01));
+
+        // Rotating the ellipse rotates its points, once the ends are rotated with it.
+        let rotate = Transform::new(xy(0.0, 0.0), 90.0, Mirror::Not);
+        let rotated = EllipticalArcRef {
+            start: rotate.apply(arc.start),
+            end: rotate.apply(arc.end),
+            ..arc
+        };
+        let rotated_points = elliptical_arc(&rotated, 90.0, 0.01);
+        assert_eq!(rotated_points.len(), points.len());
+        for (actual, expected) in rotated_points.iter().zip(&points) {
+            assert_near(*actual, rotate.apply(*expected));
+        }
+
+        // A full ellipse.
+        let full = EllipticalArcRef {
+            end: arc.start,
+            ..arc
+        };
+        let points = full.tessellate(0.01);
+        assert_eq!(points.first(), points.last());
+        assert!(points.iter().any(|p| (p.y + 2.0).abs() < 0.01));
+    }
+
+    #[test]
+    fn test_mirrored_arc() {
+        // Mirroring the points of a counterclockwise arc makes them run clockwise.
+        let arc = CircularArcRef {
+            start: xy(1.0, 0.0),
+            end: xy(0.0, 1.0),
+            center: xy(0.0, 0.0),
+        };
+        let mirror = Transform::new(xy(0.0, 0.0), 0.0, Mirror::MirrorX);
+        let points: Vec<_> = arc
+            .tessellate(0.01)
+            .into_iter()
+            .map(|p| mirror.apply(p))
+            .collect();
+        assert_eq!(points.first(), Some(&xy(1.0, 0.0)));
+        assert_eq!(points.last(), Some(&xy(0.0, -1.0)));
+        assert!(points.iter().all(|p| p.y <= 0.0));
+    }
+
+    #[test]
+    fn test_shapes() {
+        let rectangle = RectangleRef {
+            origin: xy(1.0, 2.0),
+            x: 3.0,
+            y: 4.0,
+        };
+        assert_eq!(
+            PadShape::Rectangle(rectangle).tessellate(0.1),
+            [
+                xy(1.0, 2.0),
+                xy(4.0, 2.0),
+                xy(4.0, 6.0),
+                xy(1.0, 6.0),
+                xy(1.0, 2.0)
+            ]
+        );
+        let line = LineRef {
+            start: xy(0.0, 0.0),
+            end: xy(1.0, 1.0),
+        };
+        assert_eq!(
+            BoardShape::Line(line).tessellate(0.1),
+            [line.start, line.end]
+        );
+        assert_eq!(
+            ShapeElement::Fiducial(xy(3.0, 3.0)).tessellate(0.1),
+            [xy(3.0, 3.0)]
+        );
+        assert!(
+            ArtworkComponent::<String>::Filled(true)
+                .tessellate(0.1)
+                .is_empty()
+        );
+
+        // A straight "arc" between two points in the same direction from the center.
+        let arc = ArcRef::Circular(CircularArcRef {
+            start: xy(1.0, 0.0),
+            end: xy(2.0, 0.0),
+            center: xy(0.0, 0.0),
+        });
+        assert_eq!(
+            ArtworkComponent::<String>::Arc(arc).tessellate(0.1),
+            [xy(1.0, 0.0), xy(2.0, 0.0)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "tolerance must be greater than zero")]
+    fn test_bad_tolerance() {
+        CircleRef {
+            center: xy(0.0, 0.0),
+            radius: 1.0,
+        }
+        .tessellate(0.0);
+    }
+}
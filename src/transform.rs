@@ -0,0 +1,541 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Placement transforms for GenCAD components.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+/*!
+ * # `transform` Module
+ *
+ * This module computes where the pins, fiducials and artworks of a placed
+ * component end up on the board.
+ *
+ * GenCAD places features in layers of coordinate systems. A pin is positioned
+ * relative to its shape's origin, and the shape is positioned on the board by
+ * the component that uses it. At each level, a feature is first mirrored
+ * ([Mirror::MirrorX] negates y, [Mirror::MirrorY] negates x), then rotated
+ * counterclockwise by its rotation in degrees, then moved to its position.
+ *
+ * Flipping a shape puts it on the other side of the board: the layers of its
+ * pins, fiducials and artworks are swapped between top and bottom with
+ * [Layer::flipped]. Flipping doesn't mirror the geometry, so files generally
+ * mirror flipped shapes too. A component's `LAYER` only records which side it
+ * is on, and doesn't change the layers of its features.
+ *
+ * [placed_pins], [placed_fids] and [placed_artworks] take a component and its
+ * shape, and
+ * [ComponentRef::placed_pins](crate::interpreter::ComponentRef::placed_pins)
+ * looks the shape up.
+ *
+ * ## Usage Example
+ *
+ * ```no_run
+ * use std::fs::File;
+ * use std::io::BufReader;
+ *
+ * use gencad::interpreter::InterpretedGencadFile;
+ * use gencad::parser::ParsedGencadFile;
+ *
+ * fn main() -> Result<(), Box<dyn std::error::Error>> {
+ *     let parsed = ParsedGencadFile::new(BufReader::new(File::open("example.cad")?))?;
+ *     let interpreted = InterpretedGencadFile::new(parsed)?;
+ *
+ *     for component in interpreted.component_refs() {
+ *         for pin in component.placed_pins()? {
+ *             let xy = pin.placement.xy();
+ *             println!("{} {}: {} {} on {:?}", component.name, pin.pin.name, xy.x, xy.y, pin.layer);
+ *         }
+ *     }
+ *
+ *     Ok(())
+ * }
+ * ```
+ */
+
+use crate::parser::sections::components::{self, Component};
+use crate::parser::sections::shapes::{self, Pin, Shape, SubShape};
+use crate::types::{Layer, Mirror, Number, XYRef};
+
+/// A mapping from the coordinates of a feature to the coordinates it's placed in.
+///
+/// Points are mirrored about the x-axis if `mirrored` is set, then rotated counterclockwise by
+/// `rotation` degrees, then moved by `offset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// Where the feature's origin ends up.
+    pub offset: XYRef,
+    /// The counterclockwise rotation in degrees, from 0 up to but not including 360.
+    pub rotation: Number,
+    /// Whether the feature is mirrored about the x-axis before it's rotated.
+    pub mirrored: bool,
+}
+
+impl Transform {
+    /// The transform that leaves every point where it is.
+    pub const IDENTITY: Self = Self {
+        offset: XYRef { x: 0.0, y: 0.0 },
+        rotation: 0.0,
+        mirrored: false,
+    };
+
+    /// Creates the transform for a feature with the given position, rotation and mirror state,
+    /// as they appear in the file.
+    ///
+    /// Mirroring about the y-axis is the same as mirroring about the x-axis and then rotating by
+    /// 180 degrees, so it's stored that way.
+    pub fn new(offset: XYRef, rotation: Number, mirror: Mirror) -> Self {
+        let (rotation, mirrored) = match mirror {
+            Mirror::Not => (rotation, false),
+            Mirror::MirrorX => (rotation, true),
+            Mirror::MirrorY => (rotation + 180.0, true),
+        };
+        Self {
+            offset,
+            rotation: normalize(rotation),
+            mirrored,
+        }
+    }
+
+    /// Returns the transform that places a component's shape on the board.
+    pub fn of_component(component: &Component) -> Self {
+        Self::new(component.place, component.rotation, component.shape.mirror)
+    }
+
+    /// Maps a point to the coordinates the feature is placed in.
+    pub fn apply(&self, xy: XYRef) -> XYRef {
+        let y = if self.mirrored { -xy.y } else { xy.y };
+        let (sin, cos) = sin_cos(self.rotation);
+        XYRef {
+            x: self.offset.x + xy.x * cos - y * sin,
+            y: self.offset.y + xy.x * sin + y * cos,
+        }
+    }
+
+    /// Returns the transform that applies `inner` first and then this one.
+    ///
+    /// This places a feature positioned by `inner` inside something positioned by this transform,
+    /// e.g., a pin inside a component.
+    pub fn then_inner(&self, inner: &Self) -> Self {
+        // Mirroring reverses the direction of the rotations that come before it.
+        let inner_rotation = if self.mirrored {
+            -inner.rotation
+        } else {
+            inner.rotation
+        };
+        Self {
+            offset: self.apply(inner.offset),
+            rotation: normalize(self.rotation + inner_rotation),
+            mirrored: self.mirrored != inner.mirrored,
+        }
+    }
+
+    /// Returns the mirror state of the transform, which is never [Mirror::MirrorY].
+    pub fn mirror(&self) -> Mirror {
+        if self.mirrored {
+            Mirror::MirrorX
+        } else {
+            Mirror::Not
+        }
+    }
+}
+
+/// Where a feature of a component ends up on the board.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    /// Maps coordinates relative to the feature's origin to board coordinates.
+    pub transform: Transform,
+    /// Whether the feature's layers are swapped to the other side of the board.
+    pub flipped: bool,
+}
+
+impl Placement {
+    /// Returns the position of the feature's origin on the board.
+    pub fn xy(&self) -> XYRef {
+        self.transform.offset
+    }
+
+    /// Returns the counterclockwise rotation of the feature on the board, in degrees.
+    pub fn rotation(&self) -> Number {
+        self.transform.rotation
+    }
+
+    /// Returns the layer of the board that one of the feature's layers ends up on.
+    pub fn layer(&self, layer: Layer) -> Layer {
+        if self.flipped { layer.flipped() } else { layer }
+    }
+}
+
+/// A pin of a shape, placed on the board by a component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacedPin<'f> {
+    /// The pin, as defined in the shape.
+    pub pin: &'f Pin,
+    /// Where the pin and its pad end up.
+    pub placement: Placement,
+    /// The layer of the board the pin is on.
+    pub layer: Layer,
+}
+
+/// A fiducial of a shape or component, placed on the board.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacedFid<'f> {
+    /// The name of the fiducial.
+    pub name: &'f str,
+    /// The name of the fiducial's pad or padstack.
+    pub pad_name: &'f str,
+    /// Where the fiducial and its pad end up.
+    pub placement: Placement,
+    /// The layer of the board the fiducial is on.
+    pub layer: Layer,
+}
+
+/// An artwork of a shape or component, placed on the board.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacedArtwork<'f> {
+    /// The name of the artwork, as defined in the `ARTWORKS` section.
+    pub name: &'f str,
+    /// Where the artwork ends up. The layers of the artwork's components are mapped with
+    /// [Placement::layer].
+    pub placement: Placement,
+}
+
+/// Returns the pins of the component's shape, placed on the board, in file order.
+pub fn placed_pins<'f>(component: &Component, shape: &'f Shape) -> Vec<PlacedPin<'f>> {
+    let component_placement = shape_placement(component);
+    shape
+        .subshapes
+        .iter()
+        .filter_map(|subshape| match subshape {
+            SubShape::Pin(pin) => {
+                let placement = place(
+                    &component_placement,
+                    Transform::new(pin.xy, pin.rotation, pin.mirror),
+                    false,
+                );
+                Some(PlacedPin {
+                    pin,
+                    placement,
+                    layer: placement.layer(pin.layer),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the fiducials of the component's shape and then those of the component itself,
+/// placed on the board, in file order.
+///
+/// The component's own fiducials are positioned relative to the component's origin and rotated
+/// with it, but have their own mirror and flip states instead of the shape's.
+pub fn placed_fids<'f>(component: &'f Component, shape: &'f Shape) -> Vec<PlacedFid<'f>> {
+    let component_placement = shape_placement(component);
+    let shape_fids = shape
+        .subshapes
+        .iter()
+        .filter_map(|subshape| match subshape {
+            SubShape::Fid(shapes::Fid {
+                name,
+                pad_name,
+                xy,
+                layer,
+                rotation,
+                mirror,
+                ..
+            }) => {
+                let transform = Transform::new(*xy, *rotation, *mirror);
+                let placement = place(&component_placement, transform, false);
+                Some((name, pad_name, placement, *layer))
+            }
+            _ => None,
+        });
+
+    let origin_placement = origin_placement(component);
+    let component_fids =
+        component
+            .subcomponents
+            .iter()
+            .filter_map(|subcomponent| match subcomponent {
+                components::SubComponent::Fid(fid) => {
+                    let transform = Transform::new(fid.xy, fid.rotation, fid.mirror);
+                    let placement = place(&origin_placement, transform, fid.flip);
+                    Some((&fid.name, &fid.pad_name, placement, fid.layer))
+                }
+                _ => None,
+            });
+
+    shape_fids
+        .chain(component_fids)
+        .map(|(name, pad_name, placement, layer)| PlacedFid {
+            name,
+            pad_name,
+            placement,
+            layer: placement.layer(layer),
+        })
+        .collect()
+}
+
+/// Returns the artworks of the component's shape and then those of the component itself,
+/// placed on the board, in file order.
+///
+/// The component's own artworks are positioned relative to the component's origin and rotated
+/// with it, but have their own mirror and flip states instead of the shape's.
+pub fn placed_artworks<'f>(component: &'f Component, shape: &'f Shape) -> Vec<PlacedArtwork<'f>> {
+    let component_placement = shape_placement(component);
+    let shape_artworks = shape
+        .subshapes
+        .iter()
+        .filter_map(|subshape| match subshape {
+            SubShape::Artwork(artwork) => {
+                let transform = Transform::new(artwork.xy, artwork.rotation, artwork.mirror);
+                Some(PlacedArtwork {
+                    name: &artwork.name,
+                    placement: place(&component_placement, transform, false),
+                })
+            }
+            _ => None,
+        });
+
+    let origin_placement = origin_placement(component);
+    let component_artworks =
+        component
+            .subcomponents
+            .iter()
+            .filter_map(|subcomponent| match subcomponent {
+                components::SubComponent::Artwork(artwork) => {
+                    let transform = Transform::new(artwork.xy, artwork.rotation, artwork.mirror);
+                    Some(PlacedArtwork {
+                        name: &artwork.name,
+                        placement: place(&origin_placement, transform, artwork.flip),
+                    })
+                }
+                _ => None,
+            });
+
+    shape_artworks.chain(component_artworks).collect()
+}
+
+/// The placement of the component's shape, which its pins, fiducials and artworks are relative to.
+fn shape_placement(component: &Component) -> Placement {
+    Placement {
+        transform: Transform::of_component(component),
+        flipped: component.shape.flip,
+    }
+}
+
+/// The placement of the component's origin, without the shape's mirror and flip states.
+fn origin_placement(component: &Component) -> Placement {
+    Placement {
+        transform: Transform::new(component.place, component.rotation, Mirror::Not),
+        flipped: false,
+    }
+}
+
+fn place(outer: &Placement, inner: Transform, flip: bool) -> Placement {
+    Placement {
+        transform: outer.transform.then_inner(&inner),
+        flipped: outer.flipped != flip,
+    }
+}
+
+fn normalize(degrees: Number) -> Number {
+    degrees.rem_euclid(360.0)
+}
+
+/// Returns the sine and cosine of an angle in degrees, exactly for multiples of 90 degrees.
+fn sin_cos(degrees: Number) -> (Number, Number) {
+    match degrees {
+        0.0 => (0.0, 1.0),
+        90.0 => (1.0, 0.0),
+        180.0 => (0.0, -1.0),
+        270.0 => (-1.0, 0.0),
+        _ => degrees.to_radians().sin_cos(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xy(x: Number, y: Number) -> XYRef {
+        XYRef { x, y }
+    }
+
+    #[test]
+    fn test_transform() {
+        let t = Transform::new(xy(10.0, 20.0), 90.0, Mirror::Not);
+        assert_eq!(t.apply(xy(1.0, 2.0)), xy(8.0, 21.0));
+
+        // Mirroring comes before rotation.
+        let t = Transform::new(xy(0.0, 0.0), 90.0, Mirror::MirrorX);
+        assert_eq!(t.apply(xy(1.0, 2.0)), xy(2.0, 1.0));
+        let t = Transform::new(xy(0.0, 0.0), 90.0, Mirror::MirrorY);
+        assert_eq!(t.apply(xy(1.0, 2.0)), xy(-2.0, -1.0));
+        assert_eq!(t.rotation, 270.0);
+        assert_eq!(t.mirror(), Mirror::MirrorX);
+
+        assert_eq!(
+            Transform::new(xy(0.0, 0.0), -450.0, Mirror::Not).rotation,
+            270.0
+        );
+        assert_eq!(Transform::IDENTITY.apply(xy(3.0, 4.0)), xy(3.0, 4.0));
+    }
+
+    #[test]
+    fn test_then_inner() {
+        let outer = Transform::new(xy(100.0, 0.0), 90.0, Mirror::MirrorX);
+        let inner = Transform::new(xy(1.0, 2.0), 30.0, Mirror::Not);
+        let composed = outer.then_inner(&inner);
+
+        // The composed transform maps points the same as applying both in turn.
+        for point in [xy(0.0, 0.0), xy(1.0, 0.0), xy(0.0, 1.0), xy(2.0, -3.0)] {
+            let expected = outer.apply(inner.apply(point));
+            let actual = composed.apply(point);
+            assert!((expected.x - actual.x).abs() < 1e-4, "{point:?}");
+            assert!((expected.y - actual.y).abs() < 1e-4, "{point:?}");
+        }
+        assert_eq!(composed.rotation, 60.0);
+        assert!(composed.mirrored);
+
+        // Two mirrors cancel out.
+        let inner = Transform::new(xy(0.0, 0.0), 0.0, Mirror::MirrorX);
+        assert!(!outer.then_inner(&inner).mirrored);
+    }
+
+    fn component(place: XYRef, rotation: Number, mirror: Mirror, flip: bool) -> Component {
+        Component {
+            name: "U1".to_string(),
+            device: "D".to_string(),
+            place,
+            layer: if flip { Layer::Bottom } else { Layer::Top },
+            rotation,
+            shape: components::Shape {
+                name: "S".to_string(),
+                mirror,
+                flip,
+            },
+            subcomponents: vec![components::SubComponent::Fid(components::Fid {
+                name: "F2".to_string(),
+                pad_name: "fid".to_string(),
+                xy: xy(0.0, 5.0),
+                layer: Layer::Top,
+                rotation: 0.0,
+                mirror: Mirror::Not,
+                flip: false,
+                attributes: Vec::new(),
+            })],
+            texts: Vec::new(),
+            sheet: None,
+            attributes: Vec::new(),
+            span: None,
+        }
+    }
+
+    fn shape() -> Shape {
+        let pin = |name: &str, xy, layer, rotation| {
+            SubShape::Pin(Pin {
+                name: name.to_string(),
+                pad_name: "p".to_string(),
+                xy,
+                layer,
+                rotation,
+                mirror: Mirror::Not,
+                attributes: Vec::new(),
+                span: None,
+            })
+        };
+        Shape {
+            name: "S".to_string(),
+            elements: Vec::new(),
+            insert: None,
+            height: None,
+            subshapes: vec![
+                pin("1", xy(-1.0, 0.0), Layer::Top, 0.0),
+                pin("2", xy(1.0, 0.5), Layer::Bottom, 45.0),
+                SubShape::Artwork(shapes::Artwork {
+                    name: "A".to_string(),
+                    xy: xy(0.0, 1.0),
+                    rotation: 0.0,
+                    mirror: Mirror::Not,
+                    attributes: Vec::new(),
+                }),
+                SubShape::Fid(shapes::Fid {
+                    name: "F1".to_string(),
+                    pad_name: "fid".to_string(),
+                    xy: xy(2.0, 2.0),
+                    layer: Layer::Top,
+                    rotation: 0.0,
+                    mirror: Mirror::Not,
+                    attributes: Vec::new(),
+                }),
+            ],
+            attributes: Vec::new(),
+            span: None,
+        }
+    }
+
+    #[test]
+    fn test_placed_pins() {
+        let shape = shape();
+
+        let top = component(xy(10.0, 10.0), 90.0, Mirror::Not, false);
+        let pins = placed_pins(&top, &shape);
+        assert_eq!(pins.len(), 2);
+        assert_eq!(pins[0].placement.xy(), xy(10.0, 9.0));
+        assert_eq!(pins[0].layer, Layer::Top);
+        assert_eq!(pins[1].placement.xy(), xy(9.5, 11.0));
+        assert_eq!(pins[1].placement.rotation(), 135.0);
+        assert_eq!(pins[1].layer, Layer::Bottom);
+
+        // A component on the bottom is mirrored and flipped, which swaps the pin layers and
+        // reverses the pin rotations.
+        let bottom = component(xy(10.0, 10.0), 0.0, Mirror::MirrorY, true);
+        let pins = placed_pins(&bottom, &shape);
+        assert_eq!(pins[0].placement.xy(), xy(11.0, 10.0));
+        assert_eq!(pins[0].layer, Layer::Bottom);
+        assert_eq!(pins[1].placement.xy(), xy(9.0, 10.5));
+        assert_eq!(pins[1].placement.rotation(), 135.0);
+        assert_eq!(pins[1].placement.transform.mirror(), Mirror::MirrorX);
+        assert_eq!(pins[1].layer, Layer::Top);
+    }
+
+    #[test]
+    fn test_placed_fids_and_artworks() {
+        let shape = shape();
+        let bottom = component(xy(10.0, 10.0), 90.0, Mirror::MirrorX, true);
+
+        let fids = placed_fids(&bottom, &shape);
+        assert_eq!(fids.len(), 2);
+        assert_eq!(fids[0].name, "F1");
+        assert_eq!(fids[0].placement.xy(), xy(12.0, 12.0));
+        assert_eq!(fids[0].layer, Layer::Bottom);
+
+        // The component's own fiducial isn't mirrored or flipped with the shape.
+        assert_eq!(fids[1].name, "F2");
+        assert_eq!(fids[1].placement.xy(), xy(5.0, 10.0));
+        assert_eq!(fids[1].layer, Layer::Top);
+
+        let artworks = placed_artworks(&bottom, &shape);
+        assert_eq!(artworks.len(), 1);
+        assert_eq!(artworks[0].placement.xy(), xy(11.0, 10.0));
+        assert!(artworks[0].placement.flipped);
+        assert_eq!(
+            artworks[0].placement.layer(Layer::SilkscreenTop),
+            Layer::SilkscreenBottom
+        );
+    }
+}
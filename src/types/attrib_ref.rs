@@ -20,11 +20,11 @@
 
 /// Additional data in a section.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Attribute {
+pub struct Attribute<S = String> {
     /// The category of the attribute. Can be used to group a set of attributes.
-    pub category: String,
+    pub category: S,
     /// The name of the attribute.
-    pub name: String,
+    pub name: S,
     /// The attribute data.
-    pub data: String,
+    pub data: S,
 }
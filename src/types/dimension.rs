@@ -18,6 +18,8 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use super::Number;
+
 /// The dimension of the units used in the GenCAD file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Dimension {
@@ -36,3 +38,38 @@ pub enum Dimension {
     /// Number of units per millimeter.
     UserMm(u16),
 }
+
+impl Dimension {
+    /// Returns the length of one unit in tenths of a micrometer, as a numerator and denominator.
+    ///
+    /// Every unit is a whole number of tenths of a micrometer, or one divided by a user-defined
+    /// count, so conversions between them only round once.
+    fn size(self) -> (u64, u64) {
+        match self {
+            Self::Inch => (254000, 1),
+            Self::Thou => (254, 1),
+            Self::Mm => (10000, 1),
+            Self::Mm100 => (100, 1),
+            Self::User(per_inch) => (254000, per_inch.into()),
+            Self::UserM(per_cm) => (100000, per_cm.into()),
+            Self::UserMm(per_mm) => (10000, per_mm.into()),
+        }
+    }
+
+    /// Converts a length in these units to `units`.
+    pub fn convert(self, value: Number, units: Dimension) -> Number {
+        let (from_num, from_den) = self.size();
+        let (to_num, to_den) = units.size();
+        (value * (from_num * to_den) as Number) / (from_den * to_num) as Number
+    }
+
+    /// Converts a length in these units to millimeters.
+    pub fn to_mm(self, value: Number) -> Number {
+        self.convert(value, Self::Mm)
+    }
+
+    /// Converts a length in millimeters to these units.
+    pub fn from_mm(self, value: Number) -> Number {
+        Self::Mm.convert(value, self)
+    }
+}
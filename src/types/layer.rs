@@ -19,7 +19,7 @@
  */
 
 /// Layer information.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Layer {
     /// The top of the board.
     Top,
@@ -52,3 +52,24 @@ pub enum Layer {
     /// Sets of layers.
     LayersetX(u16),
 }
+
+impl Layer {
+    /// Returns the layer on the other side of the board, as when a shape is flipped.
+    ///
+    /// Only the top and bottom layers are swapped. Inner, power and ground layers are numbered
+    /// from the top, so finding the layer they swap with would need the number of layers in the
+    /// board, and they are returned unchanged.
+    pub fn flipped(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::SoldermaskTop => Self::SoldermaskBottom,
+            Self::SoldermaskBottom => Self::SoldermaskTop,
+            Self::SilkscreenTop => Self::SilkscreenBottom,
+            Self::SilkscreenBottom => Self::SilkscreenTop,
+            Self::SolderpasteTop => Self::SolderpasteBottom,
+            Self::SolderpasteBottom => Self::SolderpasteTop,
+            other => other,
+        }
+    }
+}
@@ -18,4 +18,8 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-pub type Number = f32;
+/// A GenCAD number.
+///
+/// This is double precision, since single precision can't hold every coordinate of a large board
+/// in fine user units.
+pub type Number = f64;
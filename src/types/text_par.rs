@@ -22,7 +22,7 @@ use super::{Layer, Mirror, Number, RectangleRef};
 
 /// Specifications for a text object.
 #[derive(Debug, Clone, PartialEq)]
-pub struct TextPar {
+pub struct TextPar<S = String> {
     /// The text size in [super::Dimension] units.
     pub text_size: Number,
     /// The rotation of the text in degrees.
@@ -32,7 +32,7 @@ pub struct TextPar {
     /// The layer this text belongs to.
     pub layer: Layer,
     /// The text itself.
-    pub text: String,
+    pub text: S,
     /// The rectangular area the text must fit within.
     pub area: RectangleRef,
 }
@@ -0,0 +1,409 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Conversion of GenCAD files between units.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::interpreter::InterpretedGencadFile;
+use crate::parser::sections::artworks::Artwork;
+use crate::parser::sections::board::{self, ArtworkComponent, Board, BoardShape, Subsection};
+use crate::parser::sections::components::{self, Component, SubComponent};
+use crate::parser::sections::header::Header;
+use crate::parser::sections::mech::Mechanical;
+use crate::parser::sections::pads::{Pad, PadShape};
+use crate::parser::sections::padstacks::Padstack;
+use crate::parser::sections::routes::{Route, RouteElement};
+use crate::parser::sections::shapes::{Shape, ShapeElement, SubShape};
+use crate::parser::sections::signals::Signal;
+use crate::parser::sections::testpins::TestPin;
+use crate::parser::{ParsedGencadFile, ParsedSection};
+use crate::types::{ArcRef, CircleRef, Dimension, LineRef, Number, RectangleRef, TextPar, XYRef};
+
+impl<S> ParsedGencadFile<S> {
+    /// Converts every length in the file to `units`, and sets the header's `UNITS` to them.
+    ///
+    /// This covers coordinates, radii, drill sizes, heights, text sizes, track widths, the board
+    /// thickness and the header's origin and intertrack spacing. Negative drill sizes mean the
+    /// size is undefined, so they are left as they are. Unknown sections are left as they are
+    /// too, as are files without a `HEADER` section, since their units aren't known.
+    pub fn to_units(&mut self, units: Dimension) {
+        let Some(from) = self.sections.iter().find_map(|section| match section {
+            ParsedSection::Header(header) => Some(header.units),
+            _ => None,
+        }) else {
+            return;
+        };
+        let factor = from.convert(1.0, units);
+
+        for section in &mut self.sections {
+            match section {
+                ParsedSection::Header(header) => {
+                    header.rescale(factor);
+                    header.units = units;
+                }
+                ParsedSection::Board(board) => board.rescale(factor),
+                ParsedSection::Pads(pads) => pads.rescale(factor),
+                ParsedSection::Padstacks(padstacks) => padstacks.padstacks.rescale(factor),
+                ParsedSection::Shapes(shapes) => shapes.rescale(factor),
+                ParsedSection::Components(components) => components.rescale(factor),
+                ParsedSection::Signals(signals) => signals.signals.rescale(factor),
+                ParsedSection::Routes(routes) => routes.rescale(factor),
+                ParsedSection::Tracks(tracks) => {
+                    for track in &mut tracks.tracks {
+                        track.width.rescale(factor);
+                    }
+                }
+                ParsedSection::Artworks(artworks) => artworks.rescale(factor),
+                ParsedSection::Mech(mech) => mech.rescale(factor),
+                ParsedSection::Testpins(pins) | ParsedSection::Powerpins(pins) => {
+                    for signal in &mut pins.signals {
+                        signal.pins.rescale(factor);
+                    }
+                }
+                ParsedSection::Devices(_)
+                | ParsedSection::Layers(_)
+                | ParsedSection::Pseudos(_)
+                | ParsedSection::Changes(_)
+                | ParsedSection::Unknown(_) => (),
+            }
+        }
+    }
+}
+
+impl InterpretedGencadFile {
+    /// Converts every length in the file to `units`, and sets the header's `UNITS` to them.
+    ///
+    /// This rescales the same lengths as [ParsedGencadFile::to_units].
+    pub fn to_units(&mut self, units: Dimension) {
+        let factor = self.header.units.convert(1.0, units);
+        self.header.rescale(factor);
+        self.header.units = units;
+
+        if let Some(board) = &mut self.board {
+            board.rescale(factor);
+        }
+        self.pads.values_mut().for_each(|pad| pad.rescale(factor));
+        self.padstacks
+            .values_mut()
+            .for_each(|ps| ps.rescale(factor));
+        self.shapes
+            .values_mut()
+            .for_each(|shape| shape.rescale(factor));
+        self.components.values_mut().for_each(|c| c.rescale(factor));
+        self.signals
+            .values_mut()
+            .for_each(|signal| signal.rescale(factor));
+        self.routes
+            .values_mut()
+            .for_each(|route| route.rescale(factor));
+        self.tracks
+            .values_mut()
+            .for_each(|width| width.rescale(factor));
+        self.artworks.values_mut().for_each(|a| a.rescale(factor));
+        self.mechanicals
+            .values_mut()
+            .for_each(|m| m.rescale(factor));
+        self.testpins
+            .values_mut()
+            .for_each(|pins| pins.rescale(factor));
+        self.powerpins
+            .values_mut()
+            .for_each(|pins| pins.rescale(factor));
+    }
+}
+
+/// A value with lengths that can be converted to other units.
+trait Rescale {
+    /// Multiplies every length in the value by `factor`.
+    fn rescale(&mut self, factor: Number);
+}
+
+impl Rescale for Number {
+    fn rescale(&mut self, factor: Number) {
+        *self *= factor;
+    }
+}
+
+impl<T: Rescale> Rescale for Option<T> {
+    fn rescale(&mut self, factor: Number) {
+        if let Some(value) = self {
+            value.rescale(factor);
+        }
+    }
+}
+
+impl<T: Rescale> Rescale for Vec<T> {
+    fn rescale(&mut self, factor: Number) {
+        for value in self {
+            value.rescale(factor);
+        }
+    }
+}
+
+/// Rescales a drill size, unless it's negative to mean that it's undefined.
+fn rescale_drill(drill_size: &mut Number, factor: Number) {
+    if *drill_size > 0.0 {
+        drill_size.rescale(factor);
+    }
+}
+
+impl Rescale for XYRef {
+    fn rescale(&mut self, factor: Number) {
+        self.x.rescale(factor);
+        self.y.rescale(factor);
+    }
+}
+
+impl Rescale for LineRef {
+    fn rescale(&mut self, factor: Number) {
+        self.start.rescale(factor);
+        self.end.rescale(factor);
+    }
+}
+
+impl Rescale for ArcRef {
+    fn rescale(&mut self, factor: Number) {
+        match self {
+            Self::Circular(arc) => {
+                arc.start.rescale(factor);
+                arc.end.rescale(factor);
+                arc.center.rescale(factor);
+            }
+            Self::Elliptical(arc) => {
+                arc.start.rescale(factor);
+                arc.end.rescale(factor);
+                arc.center.rescale(factor);
+                arc.major_radius.rescale(factor);
+                arc.minor_radius.rescale(factor);
+            }
+        }
+    }
+}
+
+impl Rescale for CircleRef {
+    fn rescale(&mut self, factor: Number) {
+        self.center.rescale(factor);
+        self.radius.rescale(factor);
+    }
+}
+
+impl Rescale for RectangleRef {
+    fn rescale(&mut self, factor: Number) {
+        self.origin.rescale(factor);
+        self.x.rescale(factor);
+        self.y.rescale(factor);
+    }
+}
+
+impl<S> Rescale for TextPar<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.text_size.rescale(factor);
+        self.area.rescale(factor);
+    }
+}
+
+impl<S> Rescale for board::Text<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.origin.rescale(factor);
+        self.text.rescale(factor);
+    }
+}
+
+impl<S> Rescale for components::Text<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.origin.rescale(factor);
+        self.text.rescale(factor);
+    }
+}
+
+impl<S> Rescale for Header<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.origin.rescale(factor);
+        self.intertrack.rescale(factor);
+    }
+}
+
+impl Rescale for BoardShape {
+    fn rescale(&mut self, factor: Number) {
+        match self {
+            Self::Line(line) => line.rescale(factor),
+            Self::Arc(arc) => arc.rescale(factor),
+            Self::Circle(circle) => circle.rescale(factor),
+            Self::Rectangle(rectangle) => rectangle.rescale(factor),
+        }
+    }
+}
+
+impl<S> Rescale for ArtworkComponent<S> {
+    fn rescale(&mut self, factor: Number) {
+        match self {
+            Self::Line(line) => line.rescale(factor),
+            Self::Arc(arc) => arc.rescale(factor),
+            Self::Circle(circle) => circle.rescale(factor),
+            Self::Rectangle(rectangle) => rectangle.rescale(factor),
+            Self::Text(text) => text.rescale(factor),
+            Self::Track(_) | Self::Layer(_) | Self::Filled(_) => (),
+        }
+    }
+}
+
+impl<S> Rescale for Board<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.thickness.rescale(factor);
+        self.outline_shapes.rescale(factor);
+        for subsection in &mut self.subsections {
+            match subsection {
+                Subsection::Cutout(cutout) => cutout.shapes.rescale(factor),
+                Subsection::Mask(mask) => mask.shapes.rescale(factor),
+                Subsection::Artwork(artwork) => artwork.components.rescale(factor),
+            }
+        }
+    }
+}
+
+impl Rescale for PadShape {
+    fn rescale(&mut self, factor: Number) {
+        match self {
+            Self::Line(line) => line.rescale(factor),
+            Self::Arc(arc) => arc.rescale(factor),
+            Self::Circle(circle) => circle.rescale(factor),
+            Self::Rectangle(rectangle) => rectangle.rescale(factor),
+        }
+    }
+}
+
+impl<S> Rescale for Pad<S> {
+    fn rescale(&mut self, factor: Number) {
+        rescale_drill(&mut self.drill_size, factor);
+        self.shapes.rescale(factor);
+    }
+}
+
+impl<S> Rescale for Padstack<S> {
+    fn rescale(&mut self, factor: Number) {
+        rescale_drill(&mut self.drill_size, factor);
+    }
+}
+
+impl Rescale for ShapeElement {
+    fn rescale(&mut self, factor: Number) {
+        match self {
+            Self::Line(line) => line.rescale(factor),
+            Self::Arc(arc) => arc.rescale(factor),
+            Self::Circle(circle) => circle.rescale(factor),
+            Self::Rectangle(rectangle) => rectangle.rescale(factor),
+            Self::Fiducial(xy) => xy.rescale(factor),
+        }
+    }
+}
+
+impl<S> Rescale for Shape<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.elements.rescale(factor);
+        self.height.rescale(factor);
+        for subshape in &mut self.subshapes {
+            match subshape {
+                SubShape::Artwork(artwork) => artwork.xy.rescale(factor),
+                SubShape::Fid(fid) => fid.xy.rescale(factor),
+                SubShape::Pin(pin) => pin.xy.rescale(factor),
+            }
+        }
+    }
+}
+
+impl<S> Rescale for Component<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.place.rescale(factor);
+        self.texts.rescale(factor);
+        for subcomponent in &mut self.subcomponents {
+            match subcomponent {
+                SubComponent::Artwork(artwork) => artwork.xy.rescale(factor),
+                SubComponent::Fid(fid) => fid.xy.rescale(factor),
+            }
+        }
+    }
+}
+
+impl<S> Rescale for Signal<S> {
+    fn rescale(&mut self, factor: Number) {
+        for nail in &mut self.nail_locations {
+            nail.xy.rescale(factor);
+        }
+    }
+}
+
+impl<S> Rescale for RouteElement<S> {
+    fn rescale(&mut self, factor: Number) {
+        match self {
+            Self::Line(line) => line.rescale(factor),
+            Self::Arc(arc) => arc.rescale(factor),
+            Self::Circle(circle) => circle.rescale(factor),
+            Self::Rectangle(rectangle) => rectangle.rescale(factor),
+            Self::Via(via) => {
+                via.xy.rescale(factor);
+                rescale_drill(&mut via.drill_size, factor);
+            }
+            Self::TestPad(testpad) => testpad.xy.rescale(factor),
+            Self::Text(text) => text.rescale(factor),
+            Self::Track(_) | Self::Layer(_) | Self::Filled(_) => (),
+        }
+    }
+}
+
+impl<S> Rescale for Route<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.elements.rescale(factor);
+    }
+}
+
+impl<S> Rescale for Artwork<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.components.rescale(factor);
+    }
+}
+
+impl<S> Rescale for Mechanical<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.place.rescale(factor);
+        for hole in &mut self.holes {
+            hole.xy.rescale(factor);
+            rescale_drill(&mut hole.drill_size, factor);
+        }
+    }
+}
+
+impl<S> Rescale for TestPin<S> {
+    fn rescale(&mut self, factor: Number) {
+        self.xy.rescale(factor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(Dimension::Inch.convert(1.0, Dimension::Thou), 1000.0);
+        assert_eq!(Dimension::Thou.to_mm(1000.0), 25.4);
+        assert_eq!(Dimension::User(1200).to_mm(1200.0), 25.4);
+        assert_eq!(Dimension::UserM(100).to_mm(5.0), 0.5);
+        assert_eq!(Dimension::UserMm(25).from_mm(2.0), 50.0);
+        assert_eq!(Dimension::Mm100.from_mm(1.5), 150.0);
+        assert_eq!(Dimension::Mm.convert(25.4, Dimension::User(1000)), 1000.0);
+    }
+}
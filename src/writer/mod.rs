@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Writer for GenCAD files.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+/*!
+ * # `writer` Module
+ *
+ * This module provides functionality to write parsed GenCAD files back out as
+ * GenCAD 1.4 text.
+ *
+ * Every section is written, including unknown sections, whose statements are
+ * copied as-is. Strings are only quoted when they have to be, and numbers are
+ * written in their shortest form that reads back as the same value, so parsing
+ * the written file gives back the same sections. Files parsed with
+ * [ParseOptions::keep_number_text](crate::parser::ParseOptions::keep_number_text)
+ * have their numbers written back exactly as they were read instead.
+ *
+ * ## Usage Example
+ *
+ * ```no_run
+ * use std::fs::File;
+ * use std::io::{BufReader, BufWriter};
+ *
+ * use gencad::parser::ParsedGencadFile;
+ *
+ * fn main() -> Result<(), Box<dyn std::error::Error>> {
+ *     // Parse the file
+ *     let parsed = ParsedGencadFile::new(BufReader::new(File::open("example.cad")?))?;
+ *
+ *     // Write it back out
+ *     parsed.write(BufWriter::new(File::create("copy.cad")?))?;
+ *
+ *     Ok(())
+ * }
+ * ```
+ */
+
+mod sections;
+mod types;
+
+use std::io::Write;
+
+use crate::error::Error;
+use crate::parser::{ParsedGencadFile, ParsedSection};
+use types::Line;
+
+impl<S: AsRef<str>> ParsedGencadFile<S> {
+    /// Writes the file as GenCAD text, with CRLF line endings and a blank line between sections.
+    ///
+    /// Numbers are written with their text in [ParsedGencadFile::number_text] if it was kept and
+    /// they still have the value it was read as. The diagnostics aren't written.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Where to write the file to.
+    ///
+    /// # Returns
+    ///
+    /// An [Error::Unwritable] if a value has no GenCAD representation, or an [Error::Io] if the
+    /// writer fails.
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        let mut line = Line::new(Some(&self.number_text));
+        for (i, section) in self.sections.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b"\r\n")?;
+            }
+            sections::write_section(&mut writer, section, &mut line)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<S: AsRef<str>> ParsedSection<S> {
+    /// Writes the section as GenCAD text, from its `$` line to its `$END` line.
+    ///
+    /// Numbers are always written in their shortest form, since the text they were read from
+    /// is kept by the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Where to write the section to.
+    ///
+    /// # Returns
+    ///
+    /// An [Error::Unwritable] if a value has no GenCAD representation, or an [Error::Io] if the
+    /// writer fails.
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        sections::write_section(&mut writer, self, &mut Line::new(None))
+    }
+}
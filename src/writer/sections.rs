@@ -0,0 +1,652 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Writers for the GenCAD sections.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::io::Write;
+
+use super::types::{Field, Filled, Flip, Line};
+use crate::error::Error;
+use crate::parser::ParsedSection;
+use crate::parser::sections::artworks::Artwork;
+use crate::parser::sections::board::{ArtworkComponent, Board, BoardShape, Subsection};
+use crate::parser::sections::changes::Changes;
+use crate::parser::sections::components::{self, Component, SubComponent};
+use crate::parser::sections::devices::Device;
+use crate::parser::sections::header::Header;
+use crate::parser::sections::layers::Layers;
+use crate::parser::sections::mech::Mechanical;
+use crate::parser::sections::pads::{Pad, PadShape};
+use crate::parser::sections::padstacks::Padstacks;
+use crate::parser::sections::pseudos::Pseudo;
+use crate::parser::sections::routes::{Route, RouteElement};
+use crate::parser::sections::shapes::{Shape, ShapeElement, SubShape};
+use crate::parser::sections::signals::Signals;
+use crate::parser::sections::testpins::TestPins;
+use crate::parser::sections::tracks::Tracks;
+use crate::parser::sections::unknown::Unknown;
+use crate::types::Attribute;
+
+/// Writes the statements of a single section.
+struct SectionWriter<'w, 'n, W> {
+    out: &'w mut W,
+    /// The name of the section, without the `$`.
+    section: &'w str,
+    /// The line being built, shared between sections to reuse its allocation and keep track of
+    /// which sections and statements the text of the numbers is for.
+    line: &'w mut Line<'n>,
+}
+
+impl<'w, 'n, W: Write> SectionWriter<'w, 'n, W> {
+    /// Writes the line that starts the section.
+    fn start(out: &'w mut W, section: &'w str, line: &'w mut Line<'n>) -> Result<Self, Error> {
+        write!(out, "${}\r\n", section)?;
+        line.start_section(section);
+        Ok(Self { out, section, line })
+    }
+
+    /// Writes the line that ends the section.
+    fn end(self) -> Result<(), Error> {
+        write!(self.out, "$END{}\r\n", self.section)?;
+        Ok(())
+    }
+
+    /// Writes a statement whose parameter is made up of the fields, separated by spaces.
+    fn statement(&mut self, keyword: &str, fields: &[&dyn Field]) -> Result<(), Error> {
+        self.line.start_statement(keyword);
+        super::types::write_fields(self.line, fields).map_err(|_| Error::Unwritable {
+            section: self.section.to_string(),
+            keyword: keyword.to_string(),
+        })?;
+        self.line.push_str("\r\n");
+        self.out.write_all(self.line.as_bytes())?;
+        Ok(())
+    }
+
+    /// Writes a statement whose parameter is copied as-is.
+    fn raw(&mut self, keyword: &str, parameter: &str) -> Result<(), Error> {
+        write!(self.out, "{} {}\r\n", keyword, parameter)?;
+        Ok(())
+    }
+
+    fn attributes<S: AsRef<str>>(&mut self, attributes: &[Attribute<S>]) -> Result<(), Error> {
+        for attribute in attributes {
+            self.statement("ATTRIBUTE", &[attribute])?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a section, from its `$` line to its `$END` line.
+pub(super) fn write_section<W: Write, S: AsRef<str>>(
+    out: &mut W,
+    section: &ParsedSection<S>,
+    line: &mut Line,
+) -> Result<(), Error> {
+    let name = match section {
+        ParsedSection::Header(_) => "HEADER",
+        ParsedSection::Board(_) => "BOARD",
+        ParsedSection::Pads(_) => "PADS",
+        ParsedSection::Padstacks(_) => "PADSTACKS",
+        ParsedSection::Shapes(_) => "SHAPES",
+        ParsedSection::Components(_) => "COMPONENTS",
+        ParsedSection::Devices(_) => "DEVICES",
+        ParsedSection::Signals(_) => "SIGNALS",
+        ParsedSection::Routes(_) => "ROUTES",
+        ParsedSection::Tracks(_) => "TRACKS",
+        ParsedSection::Layers(_) => "LAYERS",
+        ParsedSection::Artworks(_) => "ARTWORKS",
+        ParsedSection::Mech(_) => "MECH",
+        ParsedSection::Testpins(_) => "TESTPINS",
+        ParsedSection::Powerpins(_) => "POWERPINS",
+        ParsedSection::Pseudos(_) => "PSEUDOS",
+        ParsedSection::Changes(_) => "CHANGES",
+        ParsedSection::Unknown(unknown) => unknown.name.as_ref(),
+    };
+
+    let mut w = SectionWriter::start(out, name, line)?;
+    match section {
+        ParsedSection::Header(header) => write_header(&mut w, header)?,
+        ParsedSection::Board(board) => write_board(&mut w, board)?,
+        ParsedSection::Pads(pads) => write_pads(&mut w, pads)?,
+        ParsedSection::Padstacks(padstacks) => write_padstacks(&mut w, padstacks)?,
+        ParsedSection::Shapes(shapes) => write_shapes(&mut w, shapes)?,
+        ParsedSection::Components(components) => write_components(&mut w, components)?,
+        ParsedSection::Devices(devices) => write_devices(&mut w, devices)?,
+        ParsedSection::Signals(signals) => write_signals(&mut w, signals)?,
+        ParsedSection::Routes(routes) => write_routes(&mut w, routes)?,
+        ParsedSection::Tracks(tracks) => write_tracks(&mut w, tracks)?,
+        ParsedSection::Layers(layers) => write_layers(&mut w, layers)?,
+        ParsedSection::Artworks(artworks) => write_artworks(&mut w, artworks)?,
+        ParsedSection::Mech(mech) => write_mech(&mut w, mech)?,
+        ParsedSection::Testpins(testpins) => write_testpins(&mut w, testpins, "TESTPIN")?,
+        ParsedSection::Powerpins(powerpins) => write_testpins(&mut w, powerpins, "POWERPIN")?,
+        ParsedSection::Pseudos(pseudos) => write_pseudos(&mut w, pseudos)?,
+        ParsedSection::Changes(changes) => write_changes(&mut w, changes)?,
+        ParsedSection::Unknown(unknown) => write_unknown(&mut w, unknown)?,
+    }
+    w.end()
+}
+
+fn write_header<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    header: &Header<S>,
+) -> Result<(), Error> {
+    w.statement("GENCAD", &[&header.gencad_version])?;
+    w.statement("USER", &[&header.user.as_ref()])?;
+    w.statement("DRAWING", &[&header.drawing.as_ref()])?;
+    w.statement("REVISION", &[&header.revision.as_ref()])?;
+    w.statement("UNITS", &[&header.units])?;
+    w.statement("ORIGIN", &[&header.origin])?;
+    w.statement("INTERTRACK", &[&header.intertrack])?;
+    w.attributes(&header.attributes)
+}
+
+fn write_board_shape<W: Write>(w: &mut SectionWriter<W>, shape: &BoardShape) -> Result<(), Error> {
+    match shape {
+        BoardShape::Line(line) => w.statement("LINE", &[line]),
+        BoardShape::Arc(arc) => w.statement("ARC", &[arc]),
+        BoardShape::Circle(circle) => w.statement("CIRCLE", &[circle]),
+        BoardShape::Rectangle(rectangle) => w.statement("RECTANGLE", &[rectangle]),
+    }
+}
+
+fn write_artwork_component<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    component: &ArtworkComponent<S>,
+) -> Result<(), Error> {
+    match component {
+        ArtworkComponent::Line(line) => w.statement("LINE", &[line]),
+        ArtworkComponent::Arc(arc) => w.statement("ARC", &[arc]),
+        ArtworkComponent::Circle(circle) => w.statement("CIRCLE", &[circle]),
+        ArtworkComponent::Rectangle(rectangle) => w.statement("RECTANGLE", &[rectangle]),
+        ArtworkComponent::Track(track) => w.statement("TRACK", &[&track.as_ref()]),
+        ArtworkComponent::Layer(layer) => w.statement("LAYER", &[layer]),
+        ArtworkComponent::Filled(filled) => w.statement("FILLED", &[&Filled(*filled)]),
+        ArtworkComponent::Text(text) => w.statement("TEXT", &[&text.origin, &text.text]),
+    }
+}
+
+fn write_board<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    board: &Board<S>,
+) -> Result<(), Error> {
+    if let Some(thickness) = &board.thickness {
+        w.statement("THICKNESS", &[thickness])?;
+    }
+    for shape in &board.outline_shapes {
+        write_board_shape(w, shape)?;
+    }
+    w.attributes(&board.attributes)?;
+
+    for subsection in &board.subsections {
+        match subsection {
+            Subsection::Cutout(cutout) => {
+                w.statement("CUTOUT", &[&cutout.name.as_ref()])?;
+                for shape in &cutout.shapes {
+                    write_board_shape(w, shape)?;
+                }
+                w.attributes(&cutout.attributes)?;
+            }
+            Subsection::Mask(mask) => {
+                w.statement("MASK", &[&mask.name.as_ref(), &mask.layer])?;
+                for shape in &mask.shapes {
+                    write_board_shape(w, shape)?;
+                }
+                w.attributes(&mask.attributes)?;
+            }
+            Subsection::Artwork(artwork) => {
+                w.statement("ARTWORK", &[&artwork.name.as_ref(), &artwork.layer])?;
+                for component in &artwork.components {
+                    write_artwork_component(w, component)?;
+                }
+                w.attributes(&artwork.attributes)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_pads<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    pads: &[Pad<S>],
+) -> Result<(), Error> {
+    for pad in pads {
+        w.statement("PAD", &[&pad.name.as_ref(), &pad.ptype, &pad.drill_size])?;
+        for shape in &pad.shapes {
+            match shape {
+                PadShape::Line(line) => w.statement("LINE", &[line])?,
+                PadShape::Arc(arc) => w.statement("ARC", &[arc])?,
+                PadShape::Circle(circle) => w.statement("CIRCLE", &[circle])?,
+                PadShape::Rectangle(rectangle) => w.statement("RECTANGLE", &[rectangle])?,
+            }
+        }
+        w.attributes(&pad.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_padstacks<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    padstacks: &Padstacks<S>,
+) -> Result<(), Error> {
+    w.attributes(&padstacks.attributes)?;
+    for padstack in &padstacks.padstacks {
+        w.statement("PADSTACK", &[&padstack.name.as_ref(), &padstack.drill_size])?;
+        for pad in &padstack.pads {
+            w.statement(
+                "PAD",
+                &[&pad.name.as_ref(), &pad.layer, &pad.rotation, &pad.mirror],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn write_shapes<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    shapes: &[Shape<S>],
+) -> Result<(), Error> {
+    for shape in shapes {
+        w.statement("SHAPE", &[&shape.name.as_ref()])?;
+        for element in &shape.elements {
+            match element {
+                ShapeElement::Line(line) => w.statement("LINE", &[line])?,
+                ShapeElement::Arc(arc) => w.statement("ARC", &[arc])?,
+                ShapeElement::Circle(circle) => w.statement("CIRCLE", &[circle])?,
+                ShapeElement::Rectangle(rectangle) => w.statement("RECTANGLE", &[rectangle])?,
+                ShapeElement::Fiducial(xy) => w.statement("FIDUCIAL", &[xy])?,
+            }
+        }
+        // Each shape gets its own INSERT, rather than relying on a default for the section.
+        if let Some(insert) = &shape.insert {
+            w.statement("INSERT", &[insert])?;
+        }
+        if let Some(height) = &shape.height {
+            w.statement("HEIGHT", &[height])?;
+        }
+        w.attributes(&shape.attributes)?;
+
+        for subshape in &shape.subshapes {
+            match subshape {
+                SubShape::Artwork(artwork) => {
+                    w.statement(
+                        "ARTWORK",
+                        &[
+                            &artwork.name.as_ref(),
+                            &artwork.xy,
+                            &artwork.rotation,
+                            &artwork.mirror,
+                        ],
+                    )?;
+                    w.attributes(&artwork.attributes)?;
+                }
+                SubShape::Fid(fid) => {
+                    w.statement(
+                        "FID",
+                        &[
+                            &fid.name.as_ref(),
+                            &fid.pad_name.as_ref(),
+                            &fid.xy,
+                            &fid.layer,
+                            &fid.rotation,
+                            &fid.mirror,
+                        ],
+                    )?;
+                    w.attributes(&fid.attributes)?;
+                }
+                SubShape::Pin(pin) => {
+                    w.statement(
+                        "PIN",
+                        &[
+                            &pin.name.as_ref(),
+                            &pin.pad_name.as_ref(),
+                            &pin.xy,
+                            &pin.layer,
+                            &pin.rotation,
+                            &pin.mirror,
+                        ],
+                    )?;
+                    w.attributes(&pin.attributes)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_component_shape<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    shape: &components::Shape<S>,
+) -> Result<(), Error> {
+    w.statement(
+        "SHAPE",
+        &[&shape.name.as_ref(), &shape.mirror, &Flip(shape.flip)],
+    )
+}
+
+fn write_components<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    components: &[Component<S>],
+) -> Result<(), Error> {
+    for component in components {
+        w.statement("COMPONENT", &[&component.name.as_ref()])?;
+        w.statement("DEVICE", &[&component.device.as_ref()])?;
+        w.statement("PLACE", &[&component.place])?;
+        w.statement("LAYER", &[&component.layer])?;
+        w.statement("ROTATION", &[&component.rotation])?;
+        write_component_shape(w, &component.shape)?;
+        for text in &component.texts {
+            w.statement("TEXT", &[&text.origin, &text.text])?;
+        }
+        if let Some(sheet) = &component.sheet {
+            w.statement("SHEET", &[&sheet.as_ref()])?;
+        }
+        w.attributes(&component.attributes)?;
+
+        for subcomponent in &component.subcomponents {
+            match subcomponent {
+                SubComponent::Artwork(artwork) => {
+                    w.statement(
+                        "ARTWORK",
+                        &[
+                            &artwork.name.as_ref(),
+                            &artwork.xy,
+                            &artwork.rotation,
+                            &artwork.mirror,
+                            &Flip(artwork.flip),
+                        ],
+                    )?;
+                    w.attributes(&artwork.attributes)?;
+                }
+                SubComponent::Fid(fid) => {
+                    w.statement(
+                        "FID",
+                        &[
+                            &fid.name.as_ref(),
+                            &fid.pad_name.as_ref(),
+                            &fid.xy,
+                            &fid.layer,
+                            &fid.rotation,
+                            &fid.mirror,
+                            &Flip(fid.flip),
+                        ],
+                    )?;
+                    w.attributes(&fid.attributes)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_devices<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    devices: &[Device<S>],
+) -> Result<(), Error> {
+    for device in devices {
+        w.statement("DEVICE", &[&device.name.as_ref()])?;
+        let optional_strings = [
+            ("PART", &device.part),
+            ("TYPE", &device.dtype),
+            ("STYLE", &device.style),
+            ("PACKAGE", &device.package),
+        ];
+        for (keyword, value) in optional_strings {
+            if let Some(value) = value {
+                w.statement(keyword, &[&value.as_ref()])?;
+            }
+        }
+        for pin in &device.pin_descriptions {
+            w.statement("PINDESC", &[&pin.pin_name.as_ref(), &pin.text.as_ref()])?;
+        }
+        for pin in &device.pin_functions {
+            w.statement("PINFUNCT", &[&pin.pin_name.as_ref(), &pin.text.as_ref()])?;
+        }
+        if let Some(pincount) = &device.pincount {
+            w.statement("PINCOUNT", &[pincount])?;
+        }
+        let optional_strings = [
+            ("VALUE", &device.value),
+            ("TOL", &device.tol),
+            ("NTOL", &device.ntol),
+            ("PTOL", &device.ptol),
+            ("VOLTS", &device.volts),
+            ("DESC", &device.desc),
+        ];
+        for (keyword, value) in optional_strings {
+            if let Some(value) = value {
+                w.statement(keyword, &[&value.as_ref()])?;
+            }
+        }
+        w.attributes(&device.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_signals<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    signals: &Signals<S>,
+) -> Result<(), Error> {
+    w.attributes(&signals.attributes)?;
+    for signal in &signals.signals {
+        w.statement("SIGNAL", &[&signal.name.as_ref()])?;
+        for node in &signal.nodes {
+            w.statement(
+                "NODE",
+                &[&node.component_name.as_ref(), &node.pin_name.as_ref()],
+            )?;
+        }
+        for nail in &signal.nail_locations {
+            w.statement(
+                "NAILLOC",
+                &[
+                    &nail.component_name.as_ref(),
+                    &nail.pin_name.as_ref(),
+                    &nail.tp_name.as_ref(),
+                    &nail.xy,
+                    &nail.tan.as_ref(),
+                    &nail.tin.as_ref(),
+                    &nail.probe.as_ref(),
+                    &nail.layer,
+                ],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn write_routes<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    routes: &[Route<S>],
+) -> Result<(), Error> {
+    for route in routes {
+        w.statement("ROUTE", &[&route.name.as_ref()])?;
+        for element in &route.elements {
+            match element {
+                RouteElement::Track(track) => w.statement("TRACK", &[&track.as_ref()])?,
+                RouteElement::Layer(layer) => w.statement("LAYER", &[layer])?,
+                RouteElement::Filled(filled) => w.statement("FILLED", &[&Filled(*filled)])?,
+                RouteElement::Line(line) => w.statement("LINE", &[line])?,
+                RouteElement::Arc(arc) => w.statement("ARC", &[arc])?,
+                RouteElement::Circle(circle) => w.statement("CIRCLE", &[circle])?,
+                RouteElement::Rectangle(rectangle) => w.statement("RECTANGLE", &[rectangle])?,
+                RouteElement::Via(via) => w.statement(
+                    "VIA",
+                    &[
+                        &via.pad_name.as_ref(),
+                        &via.xy,
+                        &via.layer,
+                        &via.drill_size,
+                        &via.name.as_ref(),
+                    ],
+                )?,
+                RouteElement::TestPad(testpad) => w.statement(
+                    "TESTPAD",
+                    &[
+                        &testpad.name.as_ref(),
+                        &testpad.pad_name.as_ref(),
+                        &testpad.xy,
+                        &testpad.layer,
+                        &testpad.rotation,
+                        &testpad.mirror,
+                    ],
+                )?,
+                RouteElement::Text(text) => w.statement("TEXT", &[&text.origin, &text.text])?,
+            }
+        }
+        w.attributes(&route.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_tracks<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    tracks: &Tracks<S>,
+) -> Result<(), Error> {
+    w.attributes(&tracks.attributes)?;
+    for track in &tracks.tracks {
+        w.statement("TRACK", &[&track.name.as_ref(), &track.width])?;
+    }
+    Ok(())
+}
+
+fn write_layers<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    layers: &Layers<S>,
+) -> Result<(), Error> {
+    // Attributes after a LAYER belong to it, so the section's own attributes must come first.
+    w.attributes(&layers.attributes)?;
+    for layer in &layers.layers {
+        w.statement("LAYER", &[&layer.layer, &layer.description.as_ref()])?;
+        w.attributes(&layer.attributes)?;
+    }
+    for layerset in &layers.layersets {
+        w.statement("LAYERSET", &[&layerset.layerset])?;
+        for layer in &layerset.layers {
+            w.statement("LAYER", &[layer])?;
+        }
+        w.attributes(&layerset.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_artworks<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    artworks: &[Artwork<S>],
+) -> Result<(), Error> {
+    for artwork in artworks {
+        w.statement("ARTWORK", &[&artwork.name.as_ref()])?;
+        for component in &artwork.components {
+            write_artwork_component(w, component)?;
+        }
+        w.attributes(&artwork.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_mech<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    mech: &[Mechanical<S>],
+) -> Result<(), Error> {
+    for mechanical in mech {
+        w.statement("MECHANICAL", &[&mechanical.name.as_ref()])?;
+        w.statement("PLACE", &[&mechanical.place])?;
+        w.statement("LAYER", &[&mechanical.layer])?;
+        w.statement("ROTATION", &[&mechanical.rotation])?;
+        if let Some(shape) = &mechanical.shape {
+            write_component_shape(w, shape)?;
+        }
+        for hole in &mechanical.holes {
+            w.statement("HOLE", &[&hole.xy, &hole.drill_size])?;
+        }
+        w.attributes(&mechanical.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_testpins<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    testpins: &TestPins<S>,
+    pin_keyword: &str,
+) -> Result<(), Error> {
+    w.attributes(&testpins.attributes)?;
+    for signal in &testpins.signals {
+        w.statement("SIGNAL", &[&signal.name.as_ref()])?;
+        for pin in &signal.pins {
+            w.statement(
+                pin_keyword,
+                &[
+                    &pin.tp_name.as_ref(),
+                    &pin.xy,
+                    &pin.tan.as_ref(),
+                    &pin.tin.as_ref(),
+                    &pin.probe.as_ref(),
+                    &pin.layer,
+                ],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn write_pseudos<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    pseudos: &[Pseudo<S>],
+) -> Result<(), Error> {
+    for pseudo in pseudos {
+        w.statement("PSEUDO", &[&pseudo.name.as_ref()])?;
+        if let Some(device) = &pseudo.device {
+            w.statement("DEVICE", &[&device.as_ref()])?;
+        }
+        for member in &pseudo.members {
+            w.statement("COMPONENT", &[&member.component_name.as_ref()])?;
+            for pin in &member.pins {
+                w.statement("PIN", &[&pin.pseudo_pin.as_ref(), &pin.pin_name.as_ref()])?;
+            }
+        }
+        w.attributes(&pseudo.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_changes<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    changes: &Changes<S>,
+) -> Result<(), Error> {
+    // Attributes after a CHANGE belong to it, so the section's own attributes must come first.
+    w.attributes(&changes.attributes)?;
+    for change in &changes.changes {
+        match change.description.as_ref() {
+            "" => w.statement("CHANGE", &[&change.id.as_ref()])?,
+            description => w.statement("CHANGE", &[&change.id.as_ref(), &description])?,
+        }
+        w.attributes(&change.attributes)?;
+    }
+    Ok(())
+}
+
+fn write_unknown<W: Write, S: AsRef<str>>(
+    w: &mut SectionWriter<W>,
+    unknown: &Unknown<S>,
+) -> Result<(), Error> {
+    for statement in &unknown.statements {
+        w.raw(statement.keyword.as_ref(), statement.parameter.as_ref())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/*
+ *  Writers for the GenCAD data types.
+ *  Copyright (C) 2026  Forest Crossman <cyrozap@gmail.com>
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::ops::{Deref, DerefMut};
+
+use crate::parser::NumberText;
+use crate::parser::number_text::{SectionNumberText, words};
+use crate::parser::sections::shapes::Insert;
+use crate::types::{
+    ArcRef, Attribute, CircleRef, Dimension, Layer, LineRef, Mirror, Number, PadType, RectangleRef,
+    TextPar, XYRef,
+};
+
+/// The value of a field can't be represented in GenCAD syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unwritable;
+
+/// The line of a statement being written, along with the text of the numbers to write in it.
+///
+/// Dereferences to the text of the line.
+pub struct Line<'n> {
+    text: String,
+    /// The text of the numbers of the file being written, if it was kept.
+    number_text: Option<&'n NumberText>,
+    /// How many sections with each name have been started.
+    sections: HashMap<String, usize>,
+    /// The text of the numbers of the section being written, if any was kept.
+    section: Option<&'n SectionNumberText>,
+    /// How many statements with each keyword have been started in the section.
+    statements: HashMap<String, usize>,
+    /// The keyword of the statement being written, and its index among the statements of the
+    /// section with that keyword.
+    statement: (String, usize),
+    /// Where the parameter of the statement starts in the line.
+    parameter: usize,
+}
+
+impl<'n> Line<'n> {
+    pub fn new(number_text: Option<&'n NumberText>) -> Self {
+        Self {
+            text: String::new(),
+            number_text,
+            sections: HashMap::new(),
+            section: None,
+            statements: HashMap::new(),
+            statement: (String::new(), 0),
+            parameter: 0,
+        }
+    }
+
+    /// Moves on to the next section with the name.
+    pub fn start_section(&mut self, name: &str) {
+        let count = self.sections.entry(name.to_string()).or_default();
+        self.section = self
+            .number_text
+            .and_then(|number_text| number_text.section(name, *count));
+        *count += 1;
+        self.statements.clear();
+    }
+
+    /// Clears the line and starts it with the keyword of the next statement in the section.
+    pub fn start_statement(&mut self, keyword: &str) {
+        self.text.clear();
+        self.text.push_str(keyword);
+        self.text.push(' ');
+        self.parameter = self.text.len();
+
+        if self.section.is_some() {
+            let count = self.statements.entry(keyword.to_string()).or_default();
+            self.statement.0.clear();
+            self.statement.0.push_str(keyword);
+            self.statement.1 = *count;
+            *count += 1;
+        }
+    }
+
+    /// Returns the text of the number about to be written, if it was kept and still has the
+    /// value it was read as.
+    fn number_text(&self, value: Number) -> Option<&'n str> {
+        let section = self.section?;
+        let word = words(&self.text[self.parameter..]).count();
+        let (keyword, index) = &self.statement;
+        section.get(keyword, *index, word, value)
+    }
+}
+
+impl Deref for Line<'_> {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.text
+    }
+}
+
+impl DerefMut for Line<'_> {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.text
+    }
+}
+
+/// A value that can be written as part of a statement's parameter.
+pub trait Field {
+    /// Appends the value to `line` in the form the parser reads it back from.
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable>;
+}
+
+impl Field for &str {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        if !self.chars().all(|c| matches!(c, ' '..='~')) {
+            return Err(Unwritable);
+        }
+
+        // Strings only need to be quoted if they would otherwise be read as something else.
+        if !self.is_empty() && !self.contains(' ') && !self.starts_with('"') {
+            line.push_str(self);
+            return Ok(());
+        }
+
+        // Quote marks can be escaped, but backslashes can't, so a backslash right before the
+        // closing quote would escape it.
+        if self.ends_with('\\') {
+            return Err(Unwritable);
+        }
+        line.push('"');
+        for c in self.chars() {
+            if c == '"' {
+                line.push('\\');
+            }
+            line.push(c);
+        }
+        line.push('"');
+        Ok(())
+    }
+}
+
+impl Field for Number {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        if !self.is_finite() {
+            return Err(Unwritable);
+        }
+        // The text the number was read from, if it was kept, or else the shortest representation
+        // that reads back as the same number.
+        match line.number_text(*self) {
+            Some(text) => {
+                line.push_str(text);
+                Ok(())
+            }
+            None => write!(line, "{}", self).map_err(|_| Unwritable),
+        }
+    }
+}
+
+impl Field for u16 {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        write!(line, "{}", self).map_err(|_| Unwritable)
+    }
+}
+
+/// A `FILLED` flag, written as `YES` or `0`.
+pub struct Filled(pub bool);
+
+impl Field for Filled {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        line.push_str(if self.0 { "YES" } else { "0" });
+        Ok(())
+    }
+}
+
+/// A flip flag, written as `FLIP` or `0`.
+pub struct Flip(pub bool);
+
+impl Field for Flip {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        line.push_str(if self.0 { "FLIP" } else { "0" });
+        Ok(())
+    }
+}
+
+impl Field for Dimension {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        match self {
+            Self::Inch => line.push_str("INCH"),
+            Self::Thou => line.push_str("THOU"),
+            Self::Mm => line.push_str("MM"),
+            Self::Mm100 => line.push_str("MM100"),
+            Self::User(n) => write!(line, "USER {}", n).map_err(|_| Unwritable)?,
+            Self::UserM(n) => write!(line, "USERM {}", n).map_err(|_| Unwritable)?,
+            Self::UserMm(n) => write!(line, "USERMM {}", n).map_err(|_| Unwritable)?,
+        }
+        Ok(())
+    }
+}
+
+impl Field for Layer {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        let result = match self {
+            Self::Top => write!(line, "TOP"),
+            Self::Bottom => write!(line, "BOTTOM"),
+            Self::SoldermaskTop => write!(line, "SOLDERMASK_TOP"),
+            Self::SoldermaskBottom => write!(line, "SOLDERMASK_BOTTOM"),
+            Self::SilkscreenTop => write!(line, "SILKSCREEN_TOP"),
+            Self::SilkscreenBottom => write!(line, "SILKSCREEN_BOTTOM"),
+            Self::SolderpasteTop => write!(line, "SOLDERPASTE_TOP"),
+            Self::SolderpasteBottom => write!(line, "SOLDERPASTE_BOTTOM"),
+            Self::PowerX(n) => write!(line, "POWER{}", n),
+            Self::GroundX(n) => write!(line, "GROUND{}", n),
+            Self::Inner => write!(line, "INNER"),
+            Self::InnerX(n) => write!(line, "INNER{}", n),
+            Self::All => write!(line, "ALL"),
+            Self::LayerX(n) => write!(line, "LAYER{}", n),
+            Self::LayersetX(n) => write!(line, "LAYERSET{}", n),
+        };
+        result.map_err(|_| Unwritable)
+    }
+}
+
+impl Field for Mirror {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        line.push_str(match self {
+            Self::Not => "0",
+            Self::MirrorX => "MIRRORX",
+            Self::MirrorY => "MIRRORY",
+        });
+        Ok(())
+    }
+}
+
+impl Field for PadType {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        line.push_str(match self {
+            Self::Finger => "FINGER",
+            Self::Round => "ROUND",
+            Self::Annular => "ANNULAR",
+            Self::Bullet => "BULLET",
+            Self::Rectangular => "RECTANGULAR",
+            Self::Hexagon => "HEXAGON",
+            Self::Octagon => "OCTAGON",
+            Self::Polygon => "POLYGON",
+            Self::Unknown => "UNKNOWN",
+        });
+        Ok(())
+    }
+}
+
+impl Field for Insert {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        line.push_str(match self {
+            Self::Th => "TH",
+            Self::Axial => "AXIAL",
+            Self::Radial => "RADIAL",
+            Self::Dip => "DIP",
+            Self::Sip => "SIP",
+            Self::Zip => "ZIP",
+            Self::Conn => "CONN",
+            Self::Smd => "SMD",
+            Self::Other => "OTHER",
+        });
+        Ok(())
+    }
+}
+
+/// Writes each of the fields, separated by spaces.
+pub fn write_fields(line: &mut Line, fields: &[&dyn Field]) -> Result<(), Unwritable> {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            line.push(' ');
+        }
+        field.write(line)?;
+    }
+    Ok(())
+}
+
+impl Field for XYRef {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        write_fields(line, &[&self.x, &self.y])
+    }
+}
+
+impl Field for LineRef {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        write_fields(line, &[&self.start, &self.end])
+    }
+}
+
+impl Field for ArcRef {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        match self {
+            Self::Circular(arc) => write_fields(line, &[&arc.start, &arc.end, &arc.center]),
+            Self::Elliptical(arc) => write_fields(
+                line,
+                &[
+                    &arc.start,
+                    &arc.end,
+                    &arc.center,
+                    &arc.major_radius,
+                    &arc.minor_radius,
+                ],
+            ),
+        }
+    }
+}
+
+impl Field for CircleRef {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        write_fields(line, &[&self.center, &self.radius])
+    }
+}
+
+impl Field for RectangleRef {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        write_fields(line, &[&self.origin, &self.x, &self.y])
+    }
+}
+
+impl<S: AsRef<str>> Field for TextPar<S> {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        write_fields(
+            line,
+            &[
+                &self.text_size,
+                &self.rotation,
+                &self.mirror,
+                &self.layer,
+                &self.text.as_ref(),
+                &self.area,
+            ],
+        )
+    }
+}
+
+impl<S: AsRef<str>> Field for Attribute<S> {
+    fn write(&self, line: &mut Line) -> Result<(), Unwritable> {
+        write_fields(
+            line,
+            &[
+                &self.category.as_ref(),
+                &self.name.as_ref(),
+                &self.data.as_ref(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::string;
+
+    fn written(field: &dyn Field) -> Result<String, Unwritable> {
+        let mut line = Line::new(None);
+        field.write(&mut line)?;
+        Ok(line.text)
+    }
+
+    #[test]
+    fn test_string() {
+        assert_eq!(written(&"ABC"), Ok("ABC".to_string()));
+        assert_eq!(written(&r#"A"B\C"#), Ok(r#"A"B\C"#.to_string()));
+        assert_eq!(written(&""), Ok(r#""""#.to_string()));
+        assert_eq!(written(&"A B"), Ok(r#""A B""#.to_string()));
+        assert_eq!(written(&r#""A"#), Ok(r#""\"A""#.to_string()));
+        assert_eq!(written(&r#"A \"B\""#), Ok(r#""A \\"B\\"""#.to_string()));
+
+        assert_eq!(written(&"A\tB"), Err(Unwritable));
+        assert_eq!(written(&"Ä"), Err(Unwritable));
+        assert_eq!(written(&r#"A B\"#), Err(Unwritable));
+
+        // Everything that can be written reads back as the same string.
+        for s in [
+            "ABC",
+            r#"A"B\C"#,
+            "",
+            "A B",
+            r#""A"#,
+            r#"A \"B\""#,
+            r#"\ A"#,
+        ] {
+            let line = written(&s).unwrap();
+            assert_eq!(string::<String>(&line), Ok(("", s.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_string_round_trip() {
+        // Every string of up to four of the characters that affect quoting.
+        let mut strings = vec![String::new()];
+        for len in 1..=4 {
+            let shorter: Vec<_> = strings
+                .iter()
+                .filter(|s| s.len() == len - 1)
+                .cloned()
+                .collect();
+            for s in shorter {
+                for c in [' ', '"', '\\', 'A'] {
+                    strings.push(format!("{s}{c}"));
+                }
+            }
+        }
+
+        for s in &strings {
+            match written(&s.as_str()) {
+                Ok(line) => assert_eq!(string::<String>(&line), Ok(("", s.clone()))),
+                // Only strings that have to be quoted but end with a backslash can't be written.
+                Err(Unwritable) => {
+                    assert!(s.ends_with('\\') && (s.contains(' ') || s.starts_with('"')))
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(written(&1200.0), Ok("1200".to_string()));
+        assert_eq!(written(&-0.003), Ok("-0.003".to_string()));
+        assert_eq!(written(&9.52527), Ok("9.52527".to_string()));
+        assert_eq!(written(&Number::NAN), Err(Unwritable));
+        assert_eq!(written(&Number::INFINITY), Err(Unwritable));
+    }
+
+    #[test]
+    fn test_layer_and_dimension() {
+        assert_eq!(written(&Layer::InnerX(3)), Ok("INNER3".to_string()));
+        assert_eq!(written(&Layer::LayersetX(12)), Ok("LAYERSET12".to_string()));
+        assert_eq!(written(&Dimension::UserMm(25)), Ok("USERMM 25".to_string()));
+        assert_eq!(written(&Dimension::Mm100), Ok("MM100".to_string()));
+    }
+}
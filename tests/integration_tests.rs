@@ -18,6 +18,10 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::borrow::Cow;
+
+use gencad::builder::GencadBuilder;
+use gencad::interpreter::{InterpretedGencadFile, PadOrPadstack};
 use gencad::parser::sections::board::{
     self, ArtworkComponent, Board, BoardShape, Cutout, Mask, Subsection,
 };
@@ -26,14 +30,20 @@ use gencad::parser::sections::devices::{Device, PinDesc};
 use gencad::parser::sections::header::Header;
 use gencad::parser::sections::pads::{self, PadShape};
 use gencad::parser::sections::padstacks::{self, Padstack, Padstacks};
-use gencad::parser::sections::shapes::{self, Pin, Shape, ShapeElement, SubShape};
+use gencad::parser::sections::routes::RouteElement;
+use gencad::parser::sections::shapes::{self, Insert, Pin, Shape, ShapeElement, SubShape};
 use gencad::parser::sections::signals::{NailLoc, Node, Signal, Signals};
 use gencad::parser::sections::unknown::{Statement, Unknown};
-use gencad::parser::{ParsedGencadFile, ParsedSection};
+use gencad::parser::{
+    BorrowedGencadFile, NumberText, ParseOptions, ParsedGencadFile, ParsedSection, Record,
+    RecordReader, SectionReader, Span,
+};
+use gencad::tessellate::Tessellate;
 use gencad::types::{
     ArcRef, Attribute, CircleRef, CircularArcRef, Dimension, Layer, LineRef, Mirror, PadType,
     RectangleRef, TextPar, XYRef,
 };
+use gencad::{Diagnostic, Error, Severity};
 
 const EXAMPLE: &[u8; 2610] = include_bytes!("fixtures/example.cad");
 
@@ -232,7 +242,12 @@ fn test_example() {
                                 center: XYRef { x: 100.0, y: 0.0 }
                             }))
                         ],
-                        attributes: vec![]
+                        attributes: vec![],
+                        span: Some(Span {
+                            offset: 719,
+                            len: 19,
+                            line: 34
+                        })
                     },
                     pads::Pad {
                         name: "p1053".to_string(),
@@ -242,7 +257,12 @@ fn test_example() {
                             center: XYRef { x: 0.0, y: 0.0 },
                             radius: 30.0
                         })],
-                        attributes: vec![]
+                        attributes: vec![],
+                        span: Some(Span {
+                            offset: 839,
+                            len: 18,
+                            line: 39
+                        })
                     },
                     pads::Pad {
                         name: "p2034".to_string(),
@@ -273,7 +293,12 @@ fn test_example() {
                                 end: XYRef { x: 0.0, y: -50.0 }
                             })
                         ],
-                        attributes: vec![]
+                        attributes: vec![],
+                        span: Some(Span {
+                            offset: 874,
+                            len: 19,
+                            line: 41
+                        })
                     },
                     pads::Pad {
                         name: "d_hole_50".to_string(),
@@ -283,7 +308,12 @@ fn test_example() {
                             center: XYRef { x: 0.0, y: 0.0 },
                             radius: 25.0
                         })],
-                        attributes: vec![]
+                        attributes: vec![],
+                        span: Some(Span {
+                            offset: 978,
+                            len: 22,
+                            line: 46
+                        })
                     },
                     pads::Pad {
                         name: "3".to_string(),
@@ -294,7 +324,12 @@ fn test_example() {
                             x: 10.4,
                             y: 10.4
                         })],
-                        attributes: vec![]
+                        attributes: vec![],
+                        span: Some(Span {
+                            offset: 1017,
+                            len: 19,
+                            line: 48
+                        })
                     }
                 ]),
                 ParsedSection::Padstacks(Padstacks {
@@ -315,7 +350,12 @@ fn test_example() {
                                     rotation: 0.0,
                                     mirror: Mirror::Not
                                 }
-                            ]
+                            ],
+                            span: Some(Span {
+                                offset: 1093,
+                                len: 20,
+                                line: 53
+                            })
                         },
                         Padstack {
                             name: "p_stack2".to_string(),
@@ -345,7 +385,12 @@ fn test_example() {
                                     rotation: 180.0,
                                     mirror: Mirror::MirrorY
                                 }
-                            ]
+                            ],
+                            span: Some(Span {
+                                offset: 1160,
+                                len: 20,
+                                line: 56
+                            })
                         }
                     ],
                     attributes: vec![]
@@ -408,7 +453,12 @@ fn test_example() {
                             layer: Layer::Top,
                             rotation: 315.0,
                             mirror: Mirror::Not,
-                            attributes: vec![]
+                            attributes: vec![],
+                            span: Some(Span {
+                                offset: 1463,
+                                len: 31,
+                                line: 69
+                            })
                         }),
                         SubShape::Pin(Pin {
                             name: "1".to_string(),
@@ -420,7 +470,12 @@ fn test_example() {
                             layer: Layer::Bottom,
                             rotation: 315.0,
                             mirror: Mirror::MirrorX,
-                            attributes: vec![]
+                            attributes: vec![],
+                            span: Some(Span {
+                                offset: 1496,
+                                len: 40,
+                                line: 70
+                            })
                         }),
                         SubShape::Pin(Pin {
                             name: "2".to_string(),
@@ -432,7 +487,12 @@ fn test_example() {
                             layer: Layer::Top,
                             rotation: 135.0,
                             mirror: Mirror::Not,
-                            attributes: vec![]
+                            attributes: vec![],
+                            span: Some(Span {
+                                offset: 1538,
+                                len: 31,
+                                line: 71
+                            })
                         }),
                         SubShape::Pin(Pin {
                             name: "2".to_string(),
@@ -444,7 +504,12 @@ fn test_example() {
                             layer: Layer::Bottom,
                             rotation: 135.0,
                             mirror: Mirror::MirrorX,
-                            attributes: vec![]
+                            attributes: vec![],
+                            span: Some(Span {
+                                offset: 1571,
+                                len: 40,
+                                line: 72
+                            })
                         }),
                         SubShape::Artwork(shapes::Artwork {
                             name: "PIN1_MARKER".to_string(),
@@ -463,7 +528,12 @@ fn test_example() {
                             attributes: vec![]
                         })
                     ],
-                    attributes: vec![]
+                    attributes: vec![],
+                    span: Some(Span {
+                        offset: 1321,
+                        len: 30,
+                        line: 64
+                    })
                 }]),
                 ParsedSection::Components(vec![
                     Component {
@@ -504,7 +574,12 @@ fn test_example() {
                             }
                         }],
                         sheet: Some("12_B3".to_string()),
-                        attributes: vec![]
+                        attributes: vec![],
+                        span: Some(Span {
+                            offset: 1705,
+                            len: 14,
+                            line: 78
+                        })
                     },
                     Component {
                         name: "U7".to_string(),
@@ -533,7 +608,12 @@ fn test_example() {
                         })],
                         texts: vec![],
                         sheet: None,
-                        attributes: vec![]
+                        attributes: vec![],
+                        span: Some(Span {
+                            offset: 1899,
+                            len: 12,
+                            line: 87
+                        })
                     }
                 ]),
                 ParsedSection::Devices(vec![Device {
@@ -560,7 +640,12 @@ fn test_example() {
                     ptol: None,
                     volts: None,
                     desc: Some("Diode 1N4148 bandoleer reverse voltage 100V".to_string()),
-                    attributes: vec![]
+                    attributes: vec![],
+                    span: Some(Span {
+                        offset: 2073,
+                        len: 16,
+                        line: 97
+                    })
                 }]),
                 ParsedSection::Signals(Signals {
                     signals: vec![
@@ -569,19 +654,39 @@ fn test_example() {
                             nodes: vec![
                                 Node {
                                     component_name: "IC3".to_string(),
-                                    pin_name: "2".to_string()
+                                    pin_name: "2".to_string(),
+                                    span: Some(Span {
+                                        offset: 2248,
+                                        len: 10,
+                                        line: 107
+                                    })
                                 },
                                 Node {
                                     component_name: "R2".to_string(),
-                                    pin_name: "2".to_string()
+                                    pin_name: "2".to_string(),
+                                    span: Some(Span {
+                                        offset: 2260,
+                                        len: 9,
+                                        line: 108
+                                    })
                                 },
                                 Node {
                                     component_name: "IC4".to_string(),
-                                    pin_name: "2".to_string()
+                                    pin_name: "2".to_string(),
+                                    span: Some(Span {
+                                        offset: 2315,
+                                        len: 10,
+                                        line: 110
+                                    })
                                 },
                                 Node {
                                     component_name: "6Ic2".to_string(),
-                                    pin_name: "p34A".to_string()
+                                    pin_name: "p34A".to_string(),
+                                    span: Some(Span {
+                                        offset: 2327,
+                                        len: 14,
+                                        line: 111
+                                    })
                                 }
                             ],
                             nail_locations: vec![
@@ -611,18 +716,33 @@ fn test_example() {
                                     probe: "75T".to_string(),
                                     layer: Layer::Bottom
                                 }
-                            ]
+                            ],
+                            span: Some(Span {
+                                offset: 2229,
+                                len: 17,
+                                line: 106
+                            })
                         },
                         Signal {
                             name: "ADDRESS_BUS_4".to_string(),
                             nodes: vec![
                                 Node {
                                     component_name: "U1".to_string(),
-                                    pin_name: "2".to_string()
+                                    pin_name: "2".to_string(),
+                                    span: Some(Span {
+                                        offset: 2413,
+                                        len: 9,
+                                        line: 114
+                                    })
                                 },
                                 Node {
                                     component_name: "PL12".to_string(),
-                                    pin_name: "132".to_string()
+                                    pin_name: "132".to_string(),
+                                    span: Some(Span {
+                                        offset: 2424,
+                                        len: 13,
+                                        line: 115
+                                    })
                                 }
                             ],
                             nail_locations: vec![NailLoc {
@@ -634,7 +754,12 @@ fn test_example() {
                                 tin: "-1".to_string(),
                                 probe: "100T".to_string(),
                                 layer: Layer::Bottom
-                            }]
+                            }],
+                            span: Some(Span {
+                                offset: 2391,
+                                len: 20,
+                                line: 113
+                            })
                         }
                     ],
                     attributes: vec![]
@@ -652,7 +777,1072 @@ fn test_example() {
                         },
                     ]
                 }),
-            ]
+            ],
+            diagnostics: vec![],
+            number_text: NumberText::default(),
+        }
+    );
+}
+
+#[test]
+fn test_interpret_example() {
+    let parsed = ParsedGencadFile::new(EXAMPLE.as_slice()).unwrap();
+    let interpreted = InterpretedGencadFile::new(parsed).unwrap();
+
+    let board = interpreted.board.as_ref().unwrap();
+    assert_eq!(board.outline_shapes.len(), 4);
+    assert_eq!(board.subsections.len(), 3);
+
+    assert_eq!(interpreted.signals.len(), 2);
+    assert_eq!(interpreted.signals["data_bus_7"].nodes.len(), 4);
+    assert!(interpreted.signal_attributes.is_empty());
+    assert_eq!(
+        interpreted.pin_signal("6Ic2", "p34A").unwrap().name,
+        "data_bus_7"
+    );
+    assert_eq!(
+        interpreted.pin_signal("PL12", "132").unwrap().name,
+        "ADDRESS_BUS_4"
+    );
+    assert!(interpreted.pin_signal("PL12", "133").is_none());
+
+    assert_eq!(interpreted.unknown_sections.len(), 1);
+    assert_eq!(interpreted.unknown_sections[0].name, "UNKNOWN");
+    assert_eq!(interpreted.unknown_sections[0].statements.len(), 2);
+}
+
+#[test]
+fn test_number_text() {
+    let file = "$HEADER\r
+GENCAD 1.40\r
+USER test\r
+DRAWING test\r
+REVISION test\r
+UNITS MM100\r
+ORIGIN 0 0\r
+INTERTRACK 0.0\r
+$ENDHEADER\r
+\r
+$BOARD\r
+THICKNESS 160.0\r
+LINE 0 0 1200.0 .5\r
+LINE 1200.0 0.50 1200 1.2E3\r
+ARC 0 0 1200 00 600 0 600 600.\r
+$ENDBOARD\r
+\r
+$COMPONENTS\r
+COMPONENT U1\r
+DEVICE D\r
+PLACE +1.50 -0.0\r
+LAYER TOP\r
+ROTATION 90.0\r
+SHAPE S 0 0\r
+TEXT 50.0 -50 100 90 0 TOP \"U 1\" 42.0 -50 500 200\r
+$ENDCOMPONENTS\r
+";
+    let options = ParseOptions {
+        keep_number_text: true,
+        ..Default::default()
+    };
+    let parse = |text: &str| ParsedGencadFile::with_options(text.as_bytes(), &options).unwrap();
+    let parsed = parse(file);
+    assert!(!parsed.number_text.is_empty());
+
+    // An unmodified file is written back exactly as it was.
+    assert_eq!(String::from_utf8(written(&parsed)).unwrap(), file);
+
+    // Without the texts, the shortest form of each number is written.
+    let plain = ParsedGencadFile::new(file.as_bytes()).unwrap();
+    assert!(plain.number_text.is_empty());
+    assert_eq!(plain.sections, parsed.sections);
+    let text = String::from_utf8(written(&plain)).unwrap();
+    assert!(text.contains("INTERTRACK 0\r\n"));
+    assert!(text.contains("LINE 1200 0.5 1200 1200\r\n"));
+    assert!(text.contains("PLACE 1.5 -0\r\n"));
+
+    // Changed values are written in their shortest form, and the other numbers are unaffected.
+    let mut changed = parsed.clone();
+    let ParsedSection::Board(board) = &mut changed.sections[1] else {
+        panic!("expected BOARD, got {:?}", changed.sections[1]);
+    };
+    board.thickness = Some(1.5);
+    let BoardShape::Line(line) = &mut board.outline_shapes[0] else {
+        panic!("expected LINE, got {:?}", board.outline_shapes[0]);
+    };
+    line.end.x = 0.25;
+    let ParsedSection::Components(components) = &mut changed.sections[2] else {
+        panic!("expected COMPONENTS, got {:?}", changed.sections[2]);
+    };
+    components[0].place.y = 0.0;
+    let expected = file
+        .replace("THICKNESS 160.0", "THICKNESS 1.5")
+        .replace("LINE 0 0 1200.0 .5", "LINE 0 0 0.25 .5")
+        .replace("PLACE +1.50 -0.0", "PLACE +1.50 0");
+    assert_eq!(String::from_utf8(written(&changed)).unwrap(), expected);
+
+    // The text of a number stays with its statement when the writer puts statements in a
+    // different order than the file.
+    let reordered = file.replace(
+        "ORIGIN 0 0\r\nINTERTRACK 0.0\r\n",
+        "INTERTRACK 0.0\r\nORIGIN 0 0\r\n",
+    );
+    assert_eq!(
+        String::from_utf8(written(&parse(&reordered))).unwrap(),
+        file
+    );
+}
+
+#[test]
+fn test_to_units() {
+    let mut parsed = ParsedGencadFile::new(EXAMPLE.as_slice()).unwrap();
+    let mut interpreted = InterpretedGencadFile::new(parsed.clone()).unwrap();
+
+    // The example is in 1/1200 inch, so this halves every length.
+    parsed.to_units(Dimension::User(600));
+    interpreted.to_units(Dimension::User(600));
+    assert_eq!(InterpretedGencadFile::new(parsed).unwrap(), interpreted);
+
+    assert_eq!(interpreted.header.units, Dimension::User(600));
+    let component = &interpreted.components["D102"];
+    assert_eq!(component.place, XYRef { x: 600.0, y: 900.0 });
+    assert_eq!(component.rotation, 90.0);
+    assert_eq!(component.texts[0].text.text_size, 50.0);
+    let pad = &interpreted.pads["p1053"];
+    assert_eq!(pad.drill_size, 10.0);
+    let PadShape::Circle(circle) = &pad.shapes[0] else {
+        panic!("expected CIRCLE, got {:?}", pad.shapes[0]);
+    };
+    assert_eq!(circle.radius, 15.0);
+
+    // Converting back gives the original lengths.
+    interpreted.to_units(Dimension::User(1200));
+    assert_eq!(
+        interpreted.components["D102"].place,
+        XYRef {
+            x: 1200.0,
+            y: 1800.0
+        }
+    );
+    assert_eq!(interpreted.pads["p1053"].drill_size, 20.0);
+}
+
+#[test]
+fn test_tessellate() {
+    let parsed = ParsedGencadFile::new(EXAMPLE.as_slice()).unwrap();
+    let interpreted = InterpretedGencadFile::new(parsed).unwrap();
+
+    // The board outline is a closed loop, with the arc bulging out counterclockwise from
+    // (1200, 2000) to (1200, 3000).
+    let board = interpreted.board.as_ref().unwrap();
+    let outline: Vec<_> = board
+        .outline_shapes
+        .iter()
+        .map(|shape| shape.tessellate(0.5))
+        .collect();
+    for (shape, next) in outline.iter().zip(outline.iter().cycle().skip(1)) {
+        assert_eq!(shape.last(), next.first());
+    }
+    assert!(outline[1].len() > 2);
+    assert!(outline[1].iter().all(|xy| xy.x >= 1199.0));
+    assert!(outline[1].iter().any(|xy| xy.x > 1679.0));
+
+    // Every shape element has points, and circles are closed.
+    for shape in interpreted.shapes.values() {
+        for element in &shape.elements {
+            let points = element.tessellate(0.5);
+            assert!(!points.is_empty());
+            if let ShapeElement::Circle(_) = element {
+                assert_eq!(points.first(), points.last());
+            }
         }
+    }
+    let PadShape::Circle(_) = &interpreted.pads["p1053"].shapes[0] else {
+        panic!("expected CIRCLE");
+    };
+    let points = interpreted.pads["p1053"].shapes[0].tessellate(0.5);
+    assert_eq!(points.first(), Some(&XYRef { x: 30.0, y: 0.0 }));
+    assert_eq!(points.first(), points.last());
+}
+
+#[test]
+fn test_expand_layerset() {
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER test\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+$LAYERS\r
+LAYER LAYER5 \"Top assembly\"\r
+LAYERSET LAYERSET1\r
+LAYER TOP\r
+LAYER LAYERSET2\r
+LAYERSET LAYERSET2\r
+LAYER INNER1\r
+LAYER BOTTOM\r
+$ENDLAYERS\r
+";
+
+    let parsed = ParsedGencadFile::new(file.as_slice()).unwrap();
+    let interpreted = InterpretedGencadFile::new(parsed).unwrap();
+
+    assert_eq!(
+        interpreted.layers[&Layer::LayerX(5)].description,
+        "Top assembly"
+    );
+    assert_eq!(
+        interpreted.expand_layer(Layer::LayersetX(1)),
+        vec![Layer::Top, Layer::InnerX(1), Layer::Bottom]
+    );
+    assert_eq!(interpreted.expand_layer(Layer::Top), vec![Layer::Top]);
+    assert_eq!(
+        interpreted.expand_layer(Layer::LayersetX(3)),
+        vec![Layer::LayersetX(3)]
+    );
+}
+
+#[test]
+fn test_interpret_routes() {
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER test\r
+DRAWING test\r
+REVISION 1\r
+UNITS USER 1200\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+$ROUTES\r
+ROUTE data_bus_7\r
+TRACK 2\r
+LAYER TOP\r
+LINE 1000 1000 1000 1500\r
+ROUTE ADDRESS_BUS_4\r
+TRACK 1\r
+$ENDROUTES\r
+";
+
+    let parsed = ParsedGencadFile::new(file.as_slice()).unwrap();
+    let mut interpreted = InterpretedGencadFile::new(parsed).unwrap();
+
+    assert_eq!(interpreted.routes.len(), 2);
+    assert_eq!(interpreted.routes["data_bus_7"].elements.len(), 3);
+    assert_eq!(
+        interpreted.routes["ADDRESS_BUS_4"].elements,
+        vec![RouteElement::Track("1".to_string())]
+    );
+
+    interpreted.to_units(Dimension::User(600));
+    let RouteElement::Line(line) = &interpreted.routes["data_bus_7"].elements[2] else {
+        panic!("expected LINE");
+    };
+    assert_eq!(line.end, XYRef { x: 500.0, y: 750.0 });
+}
+
+#[test]
+fn test_interpret_duplicate_signals() {
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER test\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+$SIGNALS\r
+SIGNAL GND\r
+NODE U1 1\r
+NAILLOC U1 1 -1 0 0 -1 -1 100T BOTTOM\r
+SIGNAL VCC\r
+NODE U1 2\r
+SIGNAL GND\r
+NODE U2 1\r
+$ENDSIGNALS\r
+$ROUTES\r
+ROUTE GND\r
+TRACK 1\r
+ROUTE GND\r
+LAYER TOP\r
+$ENDROUTES\r
+";
+
+    let parsed = ParsedGencadFile::new(file.as_slice()).unwrap();
+    let interpreted = InterpretedGencadFile::new(parsed).unwrap();
+
+    // A net split over several records keeps the nodes and nail locations of all of them.
+    assert_eq!(interpreted.signals.len(), 2);
+    let gnd = &interpreted.signals["GND"];
+    assert_eq!(
+        gnd.nodes
+            .iter()
+            .map(|node| node.component_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["U1", "U2"]
+    );
+    assert_eq!(gnd.nail_locations.len(), 1);
+    assert_eq!(interpreted.pin_signal("U2", "1").unwrap().name, "GND");
+
+    assert_eq!(interpreted.routes.len(), 1);
+    assert_eq!(
+        interpreted.routes["GND"].elements,
+        vec![
+            RouteElement::Track("1".to_string()),
+            RouteElement::Layer(Layer::Top)
+        ]
+    );
+}
+
+#[test]
+fn test_pseudo_components() {
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER test\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+$COMPONENTS\r
+COMPONENT U1\r
+DEVICE dev\r
+PLACE 0 0\r
+LAYER TOP\r
+ROTATION 0\r
+SHAPE s 0 0\r
+COMPONENT U2\r
+DEVICE dev\r
+PLACE 0 0\r
+LAYER TOP\r
+ROTATION 0\r
+SHAPE s 0 0\r
+$ENDCOMPONENTS\r
+$PSEUDOS\r
+PSEUDO P1\r
+COMPONENT U2\r
+COMPONENT U1\r
+PSEUDO P2\r
+COMPONENT U1\r
+COMPONENT U3\r
+$ENDPSEUDOS\r
+";
+
+    let parsed = ParsedGencadFile::new(file.as_slice()).unwrap();
+    let interpreted = InterpretedGencadFile::new(parsed).unwrap();
+
+    let members = interpreted.pseudo_components("P1").unwrap();
+    assert_eq!(
+        members.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        vec!["U2", "U1"]
+    );
+    assert!(matches!(
+        interpreted.pseudo_components("P2"),
+        Err(Error::UndefinedReference { section, keyword, name })
+            if section == "PSEUDOS" && keyword == "COMPONENT" && name == "U3"
+    ));
+    assert_eq!(
+        interpreted.pseudo_components("P3").unwrap_err().to_string(),
+        "undefined PSEUDO P3 referenced in $PSEUDOS"
+    );
+}
+
+#[test]
+fn test_references() {
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER test\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+$PADS\r
+PAD round ROUND 0\r
+CIRCLE 0 0 5\r
+$ENDPADS\r
+$PADSTACKS\r
+PADSTACK via 10\r
+PAD round TOP 0 0\r
+$ENDPADSTACKS\r
+$SHAPES\r
+SHAPE two\r
+PIN 1 round 0 0 TOP 0 0\r
+PIN 2 via 100 0 TOP 0 0\r
+PIN 3 missing 200 0 TOP 0 0\r
+$ENDSHAPES\r
+$COMPONENTS\r
+COMPONENT U1\r
+DEVICE dev\r
+PLACE 0 0\r
+LAYER TOP\r
+ROTATION 0\r
+SHAPE two 0 0\r
+COMPONENT U2\r
+DEVICE nodev\r
+PLACE 0 0\r
+LAYER TOP\r
+ROTATION 0\r
+SHAPE noshape 0 0\r
+$ENDCOMPONENTS\r
+$DEVICES\r
+DEVICE dev\r
+PART 1234\r
+$ENDDEVICES\r
+";
+
+    let parsed = ParsedGencadFile::new(file.as_slice()).unwrap();
+    let interpreted = InterpretedGencadFile::new(parsed).unwrap();
+
+    let u1 = interpreted.component("U1").unwrap();
+    assert_eq!(u1.device().unwrap().part.as_deref(), Some("1234"));
+    let shape = u1.shape().unwrap();
+    assert_eq!(shape.name, "two");
+
+    let pins: Vec<_> = shape.pins().collect();
+    assert_eq!(pins.len(), 3);
+    assert!(matches!(
+        pins[0].pad_or_padstack(),
+        Ok(PadOrPadstack::Pad(pad)) if pad.name == "round"
+    ));
+    assert!(matches!(
+        pins[1].pad_or_padstack(),
+        Ok(PadOrPadstack::Padstack(padstack)) if padstack.drill_size == 10.0
+    ));
+    assert_eq!(
+        pins[2].pad_or_padstack().unwrap_err().to_string(),
+        "undefined PAD missing referenced in $SHAPES"
+    );
+    assert_eq!(shape.pin("2").unwrap().xy, XYRef { x: 100.0, y: 0.0 });
+
+    let u2 = interpreted.component("U2").unwrap();
+    assert!(matches!(
+        u2.shape(),
+        Err(Error::UndefinedReference { section, keyword, name })
+            if section == "COMPONENTS" && keyword == "SHAPE" && name == "noshape"
+    ));
+    assert_eq!(
+        u2.device().unwrap_err().to_string(),
+        "undefined DEVICE nodev referenced in $COMPONENTS"
+    );
+
+    assert!(interpreted.component("U3").is_none());
+    assert_eq!(interpreted.component_refs().count(), 2);
+}
+
+#[test]
+fn test_netlist() {
+    let mut builder = GencadBuilder::new(Dimension::Mm);
+    builder.pad("p", PadType::Round, 0.0, []);
+    let pins = [
+        ("1", "p", XYRef { x: 0.0, y: 0.0 }, Layer::Top),
+        ("2", "p", XYRef { x: 1.0, y: 0.0 }, Layer::Top),
+        ("3", "p", XYRef { x: 2.0, y: 0.0 }, Layer::Top),
+    ];
+    builder.shape("s", [], pins);
+    builder.device("d");
+    for name in ["U1", "U2", "U3"] {
+        builder.component(name, "d", "s", XYRef { x: 0.0, y: 0.0 }, Layer::Top);
+    }
+    builder.signal("GND", [("U1", "1"), ("U2", "1"), ("U2", "3"), ("U3", "1")]);
+    builder.signal("VCC", [("U1", "2"), ("U3", "2")]);
+    builder.signal("ALT", [("U1", "2"), ("U2", "1")]);
+    let interpreted = InterpretedGencadFile::new(builder.build().unwrap()).unwrap();
+    assert_eq!(interpreted.signal_names, vec!["GND", "VCC", "ALT"]);
+
+    let netlist = interpreted.netlist();
+    assert_eq!(netlist.nets(), vec!["ALT", "GND", "VCC"]);
+    assert_eq!(netlist.net_of("U2", "3"), Some("GND"));
+
+    // A pin listed in more than one signal is on the first one in the file.
+    assert_eq!(netlist.net_of("U1", "2"), Some("VCC"));
+    assert_eq!(netlist.net_of("U2", "1"), Some("GND"));
+    assert_eq!(interpreted.pin_signal("U1", "2").unwrap().name, "VCC");
+    assert_eq!(interpreted.pin_signal("U2", "1").unwrap().name, "GND");
+    assert_eq!(netlist.net_of("U2", "2"), None);
+    assert_eq!(netlist.nodes("VCC").unwrap().len(), 2);
+    assert!(netlist.nodes("NC").is_none());
+    assert_eq!(
+        netlist
+            .connected("U3", "2")
+            .iter()
+            .map(|node| (node.component_name.as_str(), node.pin_name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("U1", "2"), ("U3", "2")]
+    );
+    assert!(netlist.connected("U2", "2").is_empty());
+    assert_eq!(netlist.components("GND"), vec!["U1", "U2", "U3"]);
+    assert_eq!(
+        netlist.unconnected_pins(&interpreted).unwrap(),
+        vec![("U1", "3"), ("U2", "2"), ("U3", "3")]
+    );
+}
+
+#[test]
+fn test_error_locations() {
+    let header = "$HEADER\r
+GENCAD 1.4\r
+USER test\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+";
+    let parse = |rest: &str| ParsedGencadFile::new(format!("{}{}", header, rest).as_bytes());
+
+    let err = parse("$TRACKS\r\nTRACK 1 10\r\nTRACK 2 wide\r\n$ENDTRACKS\r\n").unwrap_err();
+    assert!(matches!(
+        err,
+        Error::BadField { ref section, ref keyword, line: 12, column: 9 }
+            if section == "TRACKS" && keyword == "TRACK"
+    ));
+
+    let err = parse("$TRACKS\r\nTRACK 1 10\r\nNODE U1 1\r\n$ENDTRACKS\r\n").unwrap_err();
+    assert!(matches!(
+        err,
+        Error::UnexpectedKeyword { ref keyword, line: 12, column: 1, .. } if keyword == "NODE"
+    ));
+
+    let err = parse("$MECH\r\nMECHANICAL MH1\r\nLAYER TOP\r\n$ENDMECH\r\n").unwrap_err();
+    assert!(matches!(
+        err,
+        Error::MissingKeyword { ref keyword, line: 11, .. } if keyword == "PLACE"
+    ));
+
+    let err = parse("$TRACKS\r\nTRACK 1 10\r\n$ENDSHAPES\r\n").unwrap_err();
+    assert!(matches!(
+        err,
+        Error::MismatchedEnd { ref section, ref end, line: 12 }
+            if section == "TRACKS" && end == "SHAPES"
+    ));
+
+    let err = parse("$TRACKS\r\nTRACK 1 10\r\n").unwrap_err();
+    assert!(matches!(
+        err,
+        Error::UnterminatedSection { ref section, line: 10 } if section == "TRACKS"
+    ));
+
+    let err =
+        ParsedGencadFile::new(b"$HEADER\r\nGENCAD 1.4\r\n$ENDHEADER\r\n".as_slice()).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::MissingKeyword { ref keyword, line: 3, .. } if keyword == "USER"
+    ));
+
+    let parsed = ParsedGencadFile::new(b"$TRACKS\r\nTRACK 1 10\r\n$ENDTRACKS\r\n".as_slice());
+    let err = InterpretedGencadFile::new(parsed.unwrap()).unwrap_err();
+    assert!(matches!(err, Error::MissingSection { ref section } if section == "HEADER"));
+    assert_eq!(err.to_string(), "missing $HEADER section");
+}
+
+#[test]
+fn test_recover() {
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER test\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+garbage\r
+$TRACKS\r
+TRACK 1 10\r
+TRACK 2 wide\r
+TRACK 3 20\r
+$ENDTRACKS\r
+$MECH\r
+MECHANICAL MH1\r
+HOLE 0 0 10\r
+MECHANICAL MH2\r
+PLACE 100 100\r
+LAYER TOP\r
+ROTATION 0\r
+$ENDMECH\r
+$SIGNALS\r
+SIGNAL GND\r
+NODE U1 1\r
+";
+
+    assert!(ParsedGencadFile::new(file.as_slice()).is_err());
+
+    let options = ParseOptions {
+        recover: true,
+        ..Default::default()
+    };
+    let parsed = ParsedGencadFile::with_options(file.as_slice(), &options).unwrap();
+
+    assert_eq!(parsed.sections.len(), 4);
+    let ParsedSection::Tracks(tracks) = &parsed.sections[1] else {
+        panic!("expected TRACKS, got {:?}", parsed.sections[1]);
+    };
+    let names: Vec<_> = tracks.tracks.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["1", "3"]);
+    let ParsedSection::Mech(mechanicals) = &parsed.sections[2] else {
+        panic!("expected MECH, got {:?}", parsed.sections[2]);
+    };
+    assert_eq!(mechanicals.len(), 1);
+    assert_eq!(mechanicals[0].name, "MH2");
+    assert!(matches!(&parsed.sections[3], ParsedSection::Signals(s) if s.signals.len() == 1));
+
+    assert_eq!(
+        parsed.diagnostics,
+        vec![
+            Diagnostic {
+                severity: Severity::Error,
+                line: 10,
+                column: Some(1),
+                message: "syntax error".to_string()
+            },
+            Diagnostic {
+                severity: Severity::Error,
+                line: 13,
+                column: Some(9),
+                message: "invalid TRACK parameter in $TRACKS".to_string()
+            },
+            Diagnostic {
+                severity: Severity::Error,
+                line: 17,
+                column: None,
+                message: "missing PLACE in $MECH".to_string()
+            },
+            Diagnostic {
+                severity: Severity::Error,
+                line: 24,
+                column: None,
+                message: "section $SIGNALS is never closed".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_warnings() {
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER test\r
+USER other\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+$BOARD\r
+THICKNESS 62\r
+OUTLINE main\r
+LINE 0 0 100 0\r
+$ENDBOARD\r
+$COMPONENTS\r
+COMPONENT U1\r
+DEVICE 74LS04\r
+PLACE 100 100\r
+LAYER TOP\r
+ROTATION 0\r
+ROTATION 90\r
+SHAPE DIP14 0 0\r
+$ENDCOMPONENTS\r
+";
+
+    let parsed = ParsedGencadFile::new(file.as_slice()).unwrap();
+
+    let ParsedSection::Components(components) = &parsed.sections[2] else {
+        panic!("expected COMPONENTS, got {:?}", parsed.sections[2]);
+    };
+    assert_eq!(components[0].rotation, 0.0);
+    assert_eq!(
+        parsed.diagnostics,
+        vec![
+            Diagnostic {
+                severity: Severity::Warning,
+                line: 4,
+                column: Some(1),
+                message: "ignored duplicate USER in $HEADER".to_string()
+            },
+            Diagnostic {
+                severity: Severity::Warning,
+                line: 13,
+                column: Some(1),
+                message: "ignored OUTLINE in $BOARD: unknown keyword".to_string()
+            },
+            Diagnostic {
+                severity: Severity::Warning,
+                line: 22,
+                column: Some(1),
+                message: "ignored duplicate ROTATION in $COMPONENTS".to_string()
+            },
+        ]
     );
 }
+
+#[test]
+fn test_section_reader() {
+    let parsed = ParsedGencadFile::new(EXAMPLE.as_slice()).unwrap();
+    let sections: Vec<_> = SectionReader::new(EXAMPLE.as_slice())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(sections, parsed.sections);
+
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+$ENDBOARD\r
+garbage\r
+$TRACKS\r
+TRACK 1 10\r
+TRACK 2 wide\r
+$SIGNALS\r
+SIGNAL GND\r
+NODE U1 1\r
+";
+
+    let mut reader = SectionReader::new(file.as_slice());
+    assert!(matches!(
+        reader.next(),
+        Some(Err(Error::MismatchedEnd { line: 3, .. }))
+    ));
+    assert!(reader.next().is_none());
+
+    let options = ParseOptions {
+        recover: true,
+        ..Default::default()
+    };
+    let parsed = ParsedGencadFile::with_options(file.as_slice(), &options).unwrap();
+    let mut reader = SectionReader::with_options(file.as_slice(), &options);
+    let sections: Vec<_> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+    let mut diagnostics = reader.take_diagnostics();
+    diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+    assert_eq!(sections, parsed.sections);
+    assert_eq!(diagnostics, parsed.diagnostics);
+}
+
+#[test]
+fn test_record_reader() {
+    let parsed = ParsedGencadFile::new(EXAMPLE.as_slice()).unwrap();
+    let records: Vec<_> = RecordReader::new(EXAMPLE.as_slice())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    let components: Vec<_> = records
+        .iter()
+        .filter_map(|record| match record {
+            Record::Component(component) => Some(component.clone()),
+            _ => None,
+        })
+        .collect();
+    let expected = parsed.sections.iter().find_map(|section| match section {
+        ParsedSection::Components(components) => Some(components.clone()),
+        _ => None,
+    });
+    assert_eq!(Some(components), expected);
+    assert!(matches!(records[0], Record::Header(_)));
+
+    let file = b"$SHAPES\r
+INSERT SMD\r
+SHAPE SOIC8\r
+HEIGHT 60\r
+SHAPE DIP8\r
+INSERT DIP\r
+$ENDSHAPES\r
+$SIGNALS\r
+ATTRIBUTE net class \"power\"\r
+SIGNAL GND\r
+NODE U1 1\r
+SIGNAL VCC\r
+NODE U1 8\r
+$ENDSIGNALS\r
+";
+
+    let records: Vec<_> = RecordReader::new(file.as_slice())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let summary: Vec<_> = records
+        .iter()
+        .map(|record| match record {
+            Record::Shape(shape) => format!("shape {} {:?}", shape.name, shape.insert),
+            Record::Signal(signal) => format!("signal {}", signal.name),
+            Record::Attribute { section, attribute } => {
+                format!("attribute {} {}", section, attribute.name)
+            }
+            other => panic!("unexpected record {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            format!("shape SOIC8 {:?}", Some(Insert::Smd)),
+            format!("shape DIP8 {:?}", Some(Insert::Dip)),
+            "attribute SIGNALS class".to_string(),
+            "signal GND".to_string(),
+            "signal VCC".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_borrowed() {
+    let parsed = ParsedGencadFile::new(EXAMPLE.as_slice()).unwrap();
+    let borrowed = BorrowedGencadFile::from_slice(EXAMPLE, &ParseOptions::default()).unwrap();
+
+    // `Cow` and `String` are formatted the same way, so this compares every field.
+    assert_eq!(format!("{:?}", borrowed), format!("{:?}", parsed));
+
+    let ParsedSection::Header(header) = &borrowed.sections[0] else {
+        panic!("expected HEADER, got {:?}", borrowed.sections[0]);
+    };
+    assert!(matches!(
+        header.user,
+        Cow::Borrowed("Mitron Europe Ltd. Serial Number 00001")
+    ));
+    assert!(matches!(
+        header.attributes[0].data,
+        Cow::Borrowed("BIS 9600")
+    ));
+
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER \"say \\\"hi\\\"\"\r
+DRAWING test\r
+REVISION 1\r
+UNITS INCH\r
+ORIGIN 0 0\r
+INTERTRACK 0\r
+$ENDHEADER\r
+";
+    let borrowed = BorrowedGencadFile::from_slice(file, &ParseOptions::default()).unwrap();
+    let ParsedSection::Header(header) = &borrowed.sections[0] else {
+        panic!("expected HEADER, got {:?}", borrowed.sections[0]);
+    };
+    assert!(matches!(&header.user, Cow::Owned(user) if user == "say \"hi\""));
+}
+
+fn written<S: AsRef<str>>(parsed: &ParsedGencadFile<S>) -> Vec<u8> {
+    let mut out = Vec::new();
+    parsed.write(&mut out).unwrap();
+    out
+}
+
+/// Formats the parsed file without the spans of its records, which depend on the layout of the
+/// text it was parsed from.
+fn without_spans(parsed: &ParsedGencadFile) -> String {
+    let mut debug = format!("{:?}", parsed);
+    while let Some(start) = debug.find("span: Some(Span {") {
+        let end = start + debug[start..].find("})").unwrap() + "})".len();
+        debug.replace_range(start..end, "");
+    }
+    debug
+}
+
+#[test]
+fn test_round_trip() {
+    let parsed = ParsedGencadFile::new(EXAMPLE.as_slice()).unwrap();
+    let text = written(&parsed);
+    let reparsed = ParsedGencadFile::new(text.as_slice()).unwrap();
+    assert!(reparsed.diagnostics.is_empty());
+
+    // Only the spans change, so writing the reparsed file gives the same text again.
+    assert_eq!(without_spans(&reparsed), without_spans(&parsed));
+    assert_eq!(written(&reparsed), text);
+    assert_eq!(
+        ParsedGencadFile::new(written(&reparsed).as_slice()).unwrap(),
+        reparsed
+    );
+
+    // Strings are only quoted when they have to be.
+    let file = b"$HEADER\r
+GENCAD 1.4\r
+USER \"say \\\"hi\\\"\"\r
+DRAWING \"\"\r
+REVISION \\\"1\\\r
+UNITS USERMM 25\r
+ORIGIN -0.5 1e3\r
+INTERTRACK 0.001\r
+ATTRIBUTE a \"b c\" \"\\\"d\"\r
+$ENDHEADER\r
+\r
+$FOO\r
+BAR  baz \"qux\"\r
+$ENDFOO\r
+";
+    let parsed = ParsedGencadFile::new(file.as_slice()).unwrap();
+    let text = String::from_utf8(written(&parsed)).unwrap();
+    assert_eq!(
+        text,
+        "$HEADER\r
+GENCAD 1.4\r
+USER \"say \\\"hi\\\"\"\r
+DRAWING \"\"\r
+REVISION \\\"1\\\r
+UNITS USERMM 25\r
+ORIGIN -0.5 1000\r
+INTERTRACK 0.001\r
+ATTRIBUTE a \"b c\" \"\\\"d\"\r
+$ENDHEADER\r
+\r
+$FOO\r
+BAR  baz \"qux\"\r
+$ENDFOO\r
+"
+    );
+    assert_eq!(ParsedGencadFile::new(text.as_bytes()).unwrap(), parsed);
+
+    // Values that can't be read back aren't written.
+    let mut parsed = parsed;
+    let ParsedSection::Header(header) = &mut parsed.sections[0] else {
+        panic!("expected HEADER, got {:?}", parsed.sections[0]);
+    };
+    header.drawing = "a b\\".to_string();
+    let err = parsed.write(Vec::new()).unwrap_err();
+    assert!(matches!(
+        &err,
+        Error::Unwritable { section, keyword } if section == "HEADER" && keyword == "DRAWING"
+    ));
+    assert_eq!(
+        err.to_string(),
+        "DRAWING in $HEADER can't be written as GenCAD text"
+    );
+}
+
+/// Generates a file with every section the parser knows and an unknown one, with `count` records
+/// in each section that has records.
+fn generated_file(count: usize) -> String {
+    let mut file = format!(
+        "$HEADER
+GENCAD 1.4
+USER \"Generated {count}\"
+DRAWING gen_{count}
+REVISION \"Rev {count}\"
+UNITS USERMM {count}
+ORIGIN {} -{count}
+INTERTRACK 0.{count}
+ATTRIBUTE gen count {count}
+$ENDHEADER
+",
+        count as f64 * 2.5
+    );
+
+    let board = (0..count)
+        .map(|i| format!("LINE {i} 0 {} 0\n", i + 1))
+        .collect::<String>();
+    file.push_str(&format!(
+        "$BOARD
+{board}CUTOUT HOLE_{count}
+CIRCLE 50 50 {count}
+MASK MASK_{count} TOP
+RECTANGLE 0 0 {count} {count}
+ARTWORK MARKER_{count} BOTTOM
+TRACK 1
+FILLED YES
+{board}$ENDBOARD
+"
+    ));
+
+    let mut sections = [
+        ("PADS", String::new()),
+        ("PADSTACKS", String::new()),
+        ("SHAPES", String::new()),
+        ("COMPONENTS", String::new()),
+        ("DEVICES", String::new()),
+        ("SIGNALS", String::new()),
+        ("ROUTES", String::new()),
+        ("TRACKS", String::new()),
+        ("LAYERS", String::new()),
+        ("ARTWORKS", String::new()),
+        ("MECH", String::new()),
+        ("TESTPINS", String::new()),
+        ("POWERPINS", String::new()),
+        ("PSEUDOS", String::new()),
+        ("CHANGES", String::new()),
+    ];
+    for i in 0..count {
+        let x = i as f64 * 12.5;
+        let records = [
+            format!("PAD p{i} ROUND {i}\nCIRCLE 0 0 {x}\n"),
+            format!("PADSTACK s{i} {x}\nPAD p{i} TOP {x} MIRRORX\nPAD p{i} BOTTOM 0 0\n"),
+            format!(
+                "SHAPE SH{i}\nLINE 0 0 {x} 0\nINSERT SMD\nHEIGHT {x}\n\
+                 PIN {i} s{i} {x} -{x} TOP 90 0\nFID F{i} p{i} 0 {x} BOTTOM 0 MIRRORY\n"
+            ),
+            format!(
+                "COMPONENT U{i}\nDEVICE D{i}\nPLACE {x} -{x}\nLAYER TOP\nROTATION {x}\n\
+                 SHAPE SH{i} 0 0\nTEXT 0 0 10 0 0 TOP \"U {i}\" 0 0 {x} 10\nSHEET \"sheet {i}\"\n"
+            ),
+            format!("DEVICE D{i}\nPART P{i}\nTYPE IC\nPINDESC {i} \"pin {i}\"\nVALUE {x}\n"),
+            format!("SIGNAL N{i}\nNODE U{i} {i}\nNAILLOC U{i} {i} -1 {x} {x} -1 -1 100T BOTTOM\n"),
+            format!(
+                "ROUTE N{i}\nTRACK 1\nLAYER TOP\nLINE 0 0 {x} 0\n\
+                 VIA s{i} {x} 0 ALL -1 v{i}\nATTRIBUTE route index {i}\n"
+            ),
+            format!("TRACK t{i} {x}\n"),
+            format!(
+                "LAYER LAYER{} \"Layer {i}\"\nLAYERSET LAYERSET{}\nLAYER TOP\n",
+                i + 1,
+                i + 1
+            ),
+            format!("ARTWORK A{i}\nLAYER TOP\nTRACK 1\nCIRCLE 0 0 {x}\n"),
+            format!("MECHANICAL M{i}\nPLACE {x} 0\nLAYER ALL\nROTATION 0\nHOLE 0 0 {x}\n"),
+            format!("SIGNAL N{i}\nTESTPIN tp{i} {x} {x} {i} -1 100T BOTTOM\n"),
+            format!("SIGNAL N{i}\nPOWERPIN pp{i} {x} {x} {i} -1 75T TOP\n"),
+            format!("PSEUDO P{i}\nDEVICE D{i}\nCOMPONENT U{i}\nPIN {i} 1\n"),
+            format!("CHANGE C{i} \"change {i}\"\nATTRIBUTE eco index {i}\n"),
+        ];
+        for ((_, body), record) in sections.iter_mut().zip(records) {
+            body.push_str(&record);
+        }
+    }
+    for (name, body) in sections {
+        file.push_str(&format!("${name}\n{body}$END{name}\n"));
+    }
+
+    let statements = (0..count)
+        .map(|i| format!("KEY{} \"value {i}\" {i}\n", char::from(b'A' + i as u8)))
+        .collect::<String>();
+    file.push_str(&format!("$GENERATED\n{statements}$ENDGENERATED\n"));
+    file.replace('\n', "\r\n")
+}
+
+#[test]
+fn test_round_trip_generated() {
+    for count in 1..=3 {
+        let file = generated_file(count);
+        let parsed = ParsedGencadFile::new(file.as_bytes()).unwrap();
+        assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+
+        // Every kind of section is covered.
+        let kinds: Vec<_> = parsed
+            .sections
+            .iter()
+            .map(|section| match section {
+                ParsedSection::Header(_) => "HEADER",
+                ParsedSection::Board(_) => "BOARD",
+                ParsedSection::Pads(_) => "PADS",
+                ParsedSection::Padstacks(_) => "PADSTACKS",
+                ParsedSection::Shapes(_) => "SHAPES",
+                ParsedSection::Components(_) => "COMPONENTS",
+                ParsedSection::Devices(_) => "DEVICES",
+                ParsedSection::Signals(_) => "SIGNALS",
+                ParsedSection::Routes(_) => "ROUTES",
+                ParsedSection::Tracks(_) => "TRACKS",
+                ParsedSection::Layers(_) => "LAYERS",
+                ParsedSection::Artworks(_) => "ARTWORKS",
+                ParsedSection::Mech(_) => "MECH",
+                ParsedSection::Testpins(_) => "TESTPINS",
+                ParsedSection::Powerpins(_) => "POWERPINS",
+                ParsedSection::Pseudos(_) => "PSEUDOS",
+                ParsedSection::Changes(_) => "CHANGES",
+                ParsedSection::Unknown(_) => "UNKNOWN",
+            })
+            .collect();
+        assert_eq!(kinds.len(), 18);
+
+        let reparsed = ParsedGencadFile::new(written(&parsed).as_slice()).unwrap();
+        assert!(reparsed.diagnostics.is_empty());
+        assert_eq!(without_spans(&reparsed), without_spans(&parsed));
+    }
+}